@@ -0,0 +1,225 @@
+use std::error::Error;
+
+use extend::ext;
+use itertools::Itertools;
+use ssloc::{Audio, PcmFormat, F};
+
+use crate::config::PublishFormat;
+
+#[ext(pub, name = AudioExt)]
+impl Audio {
+    /// Copy of the audio, which is not `Clone`.
+    fn duplicate(&self) -> Audio {
+        Audio::from_interleaved(self.sample_rate(), self.channels(), self.to_interleaved::<F>())
+    }
+
+    /// Samples of a single `channel`.
+    fn channel(&self, channel: usize) -> Vec<F> {
+        self.to_interleaved::<F>()
+            .skip(channel)
+            .step_by(self.channels())
+            .collect()
+    }
+
+    /// Average of the `channels` selected by the filter, sample by sample.
+    fn mix(&self, mut channels: impl FnMut(usize) -> bool) -> Vec<F> {
+        let selected: Vec<_> = (0..self.channels()).filter(|&c| channels(c)).collect();
+        let mut mix = vec![0.; self.samples()];
+        for channel in &selected {
+            for (mix, sample) in mix.iter_mut().zip(self.channel(*channel)) {
+                *mix += sample / selected.len() as F;
+            }
+        }
+        mix
+    }
+
+    /// Root mean square of all samples of all channels.
+    fn rms(&self) -> F {
+        let len = self.samples() * self.channels();
+        if len == 0 {
+            return 0.;
+        }
+        (self.to_interleaved::<F>().map(|s| s * s).sum::<F>() / len as F).sqrt()
+    }
+
+    /// The channels at the indices of `map`, in its order.
+    fn select_channels(&self, map: &[u16]) -> Audio {
+        let samples = self.to_interleaved::<F>().collect_vec();
+        Audio::from_interleaved(
+            self.sample_rate(),
+            map.len(),
+            samples
+                .chunks(self.channels())
+                .flat_map(|frame| map.iter().map(|&channel| frame[usize::from(channel)])),
+        )
+    }
+
+    /// Each channel multiplied by its gain in `gains`, channels without one
+    /// are kept.
+    fn amplify(self, gains: &[F]) -> Audio {
+        if gains.iter().take(self.channels()).all(|&gain| gain == 1.) {
+            return self;
+        }
+        let channels = self.channels();
+        Audio::from_interleaved(
+            self.sample_rate(),
+            channels,
+            self.to_interleaved::<F>()
+                .enumerate()
+                .map(|(i, sample)| sample * gains.get(i % channels).copied().unwrap_or(1.)),
+        )
+    }
+
+    /// Linearly interpolated to `rate`, all channels preserved.
+    ///
+    /// Each recording is resampled on its own, so the sample at the boundary
+    /// to the next recording is held instead of interpolated, and downsampling
+    /// does not low-pass filter, aliasing frequencies above the new nyquist.
+    fn resample(&self, rate: F) -> Audio {
+        let ratio = self.sample_rate() / rate;
+        let len = self.samples();
+        let channels = (0..self.channels()).map(|c| self.channel(c)).collect_vec();
+        let samples = if len == 0 { 0 } else { (len as F / ratio).round() as usize };
+        Audio::from_interleaved(
+            rate,
+            self.channels(),
+            (0..samples).flat_map(|i| {
+                let position = i as F * ratio;
+                let lower = (position.floor() as usize).min(len - 1);
+                let upper = (lower + 1).min(len - 1);
+                let lerp = position - lower as F;
+                channels
+                    .iter()
+                    .map(move |channel| channel[lower] + (channel[upper] - channel[lower]) * lerp)
+            }),
+        )
+    }
+
+    /// Interleaved little endian samples in `format`.
+    fn encode(&self, format: PublishFormat) -> Vec<u8> {
+        let samples = self.to_interleaved::<F>();
+        match format {
+            PublishFormat::S16 => samples
+                .flat_map(|s| ((s.clamp(-1., 1.) * F::from(i16::MAX)) as i16).to_le_bytes())
+                .collect(),
+            PublishFormat::S24 => samples
+                .flat_map(|s| {
+                    let [low, mid, high, _] = ((s.clamp(-1., 1.) * S24_MAX) as i32).to_le_bytes();
+                    [low, mid, high]
+                })
+                .collect(),
+            PublishFormat::S32 => samples
+                .flat_map(|s| ((s.clamp(-1., 1.) * F::from(i32::MAX)) as i32).to_le_bytes())
+                .collect(),
+            PublishFormat::F32 => samples.flat_map(|s| (s as f32).to_le_bytes()).collect(),
+        }
+    }
+}
+
+/// Prepends the end of the previous recording to each recording, so events
+/// at the boundary between two recordings are analyzed within one window.
+#[derive(Debug, Default)]
+pub struct Overlap {
+    /// Sequence number, sample rate, channels and interleaved tail of the
+    /// previous recording.
+    previous: Option<(u32, F, usize, Vec<F>)>,
+}
+
+impl Overlap {
+    /// `audio` with the last `fraction` of the previous recording prepended,
+    /// as long as that was recording `seq - 1` in the same format. With
+    /// multiple analysis threads each only sees some of the recordings, the
+    /// others are analyzed without overlap.
+    pub fn apply(&mut self, seq: u32, audio: Audio, fraction: F) -> Audio {
+        if fraction <= 0. {
+            self.previous = None;
+            return audio;
+        }
+        let (rate, channels) = (audio.sample_rate(), audio.channels());
+        let samples = audio.to_interleaved::<F>().collect_vec();
+        let tail =
+            ((audio.samples() as F * fraction).round() as usize * channels).min(samples.len());
+        let previous = self.previous.replace((
+            seq,
+            rate,
+            channels,
+            samples[samples.len() - tail..].to_vec(),
+        ));
+        match previous {
+            Some((previous, previous_rate, previous_channels, tail))
+                if previous.wrapping_add(1) == seq
+                    && previous_rate == rate
+                    && previous_channels == channels =>
+            {
+                Audio::from_interleaved(rate, channels, tail.into_iter().chain(samples))
+            }
+            _ => audio,
+        }
+    }
+}
+
+/// Gains equalizing the RMS of the channels of `audio`, recorded with `gains`
+/// applied, to their mean. Diffuse noise reaches all mics at the same level,
+/// so the remaining differences are the sensitivities of the mics.
+pub fn calibrate(audio: &Audio, gains: &[F]) -> Result<Vec<F>, String> {
+    let rms = (0..audio.channels())
+        .map(|channel| {
+            let samples = audio.channel(channel);
+            (samples.iter().map(|s| s * s).sum::<F>() / samples.len().max(1) as F).sqrt()
+        })
+        .collect_vec();
+    if let Some(silent) = rms.iter().position(|&rms| rms <= 0.) {
+        return Err(format!("channel {silent} is silent"));
+    }
+    let mean = rms.iter().sum::<F>() / rms.len() as F;
+    Ok(rms
+        .iter()
+        .enumerate()
+        // rounded to keep the parameter readable
+        .map(|(channel, rms)| {
+            let gain = gains.get(channel).copied().unwrap_or(1.) * mean / rms;
+            ((gain * 1000.).round() / 1000.).max(0.001)
+        })
+        .collect())
+}
+
+const S24_MAX: F = 8_388_607.;
+
+/// Decodes interleaved little endian samples in `format`, the inverse of
+/// [`AudioExt::encode`].
+pub fn decode(format: PublishFormat, data: &[u8]) -> Vec<F> {
+    match format {
+        PublishFormat::S16 => data
+            .chunks_exact(2)
+            .map(|b| F::from(i16::from_le_bytes([b[0], b[1]])) / F::from(i16::MAX))
+            .collect(),
+        // shifting the sign bit into place sign extends
+        PublishFormat::S24 => data
+            .chunks_exact(3)
+            .map(|b| F::from(i32::from_le_bytes([0, b[0], b[1], b[2]]) >> 8) / S24_MAX)
+            .collect(),
+        PublishFormat::S32 => data
+            .chunks_exact(4)
+            .map(|b| F::from(i32::from_le_bytes([b[0], b[1], b[2], b[3]])) / F::from(i32::MAX))
+            .collect(),
+        PublishFormat::F32 => data
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]).into())
+            .collect(),
+    }
+}
+
+/// Audio of the `AudioInfo` `sample_format`, formats of `output/audio/format`
+/// are decoded with [`decode`], as ssloc does not sign extend integer samples
+/// shorter than 32 bits.
+pub fn from_pcm_bytes(
+    sample_format: &str,
+    rate: F,
+    channels: usize,
+    data: &[u8],
+) -> Result<Audio, Box<dyn Error>> {
+    Ok(match sample_format.to_lowercase().parse() {
+        Ok(format) => Audio::from_interleaved(rate, channels, decode(format, data)),
+        Err(_) => Audio::from_pcm_bytes(sample_format.parse::<PcmFormat>()?, rate, channels, data),
+    })
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+/// Exponentially growing delay between attempts to reopen a failing device,
+/// so an unplugged device neither busy-waits nor floods the log.
+#[derive(Debug, Default)]
+pub struct Backoff {
+    /// Consecutive failed attempts.
+    failures: u32,
+}
+
+impl Backoff {
+    pub const INITIAL: Duration = Duration::from_secs(1);
+    pub const MAX: Duration = Duration::from_secs(30);
+
+    /// Records a failed attempt, returning the delay before the next one.
+    pub fn failed(&mut self) -> Duration {
+        let delay = Self::INITIAL
+            .saturating_mul(2u32.saturating_pow(self.failures))
+            .min(Self::MAX);
+        self.failures = self.failures.saturating_add(1);
+        delay
+    }
+
+    /// Whether the last attempt was the first to fail after a success.
+    pub fn started_failing(&self) -> bool {
+        self.failures == 1
+    }
+
+    /// Records a successful attempt, returning how many attempts failed
+    /// before.
+    pub fn succeeded(&mut self) -> u32 {
+        std::mem::take(&mut self.failures)
+    }
+}
@@ -0,0 +1,84 @@
+//! Debug output of the candidate peaks considered for sources.
+use std::fmt::{self, Display};
+
+use ssloc::{Direction, F};
+
+use crate::config::{Config, SourceSelection};
+use crate::spectrum;
+
+/// Why a candidate peak was or was not reported as a source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Reported,
+    /// Within `min_angle` of a stronger reported peak.
+    MinAngle,
+    /// Merged into a stronger peak of the same lobe within `nms_radius`.
+    Merged,
+    /// Below `prominence_ratio` times the strongest peak.
+    Prominence,
+    /// Already found `max_sources` stronger peaks.
+    MaxSources,
+    /// Outside `ssl_threshold` and `max_strength`.
+    Strength,
+    /// Outside the `mbss/mask/*` ranges.
+    Masked,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Reported => "reported",
+            Verdict::MinAngle => "within min_angle of a stronger peak",
+            Verdict::Merged => "merged into a stronger peak within nms_radius",
+            Verdict::Prominence => "below prominence_ratio of the strongest peak",
+            Verdict::MaxSources => "beyond max_sources",
+            Verdict::Strength => "outside ssl_threshold/max_strength",
+            Verdict::Masked => "outside mbss/mask",
+        })
+    }
+}
+
+/// Classifies the `candidates` (see [`crate::spectrum::local_maxima`])
+/// according to the sources `found` by the peak picker and the strength
+/// band of `config`.
+pub fn classify(
+    candidates: Vec<(Direction, F)>,
+    found: &[(Direction, F)],
+    config: &Config,
+) -> Vec<(Direction, F, Verdict)> {
+    let same =
+        |a: Direction, b: Direction| spectrum::great_circle(a, b) < config.mbss.grid_res / 2.;
+    candidates
+        .into_iter()
+        .map(|(direction, strength)| {
+            let verdict = if !config.unmasked(direction) {
+                Verdict::Masked
+            } else if found.iter().any(|(found, _)| same(*found, direction)) {
+                if config.accept_strength(strength).is_some() {
+                    Verdict::Reported
+                } else {
+                    Verdict::Strength
+                }
+            } else if found
+                .iter()
+                .any(|(found, _)| spectrum::great_circle(*found, direction) < config.mbss.min_angle)
+            {
+                Verdict::MinAngle
+            } else if found
+                .iter()
+                .any(|(found, _)| spectrum::great_circle(*found, direction) < config.nms_radius)
+            {
+                Verdict::Merged
+            } else if config.source_selection == SourceSelection::Prominence
+                && found
+                    .first()
+                    .is_some_and(|&(_, strongest)| strength < strongest * config.prominence_ratio)
+            {
+                Verdict::Prominence
+            } else {
+                Verdict::MaxSources
+            };
+            (direction, strength, verdict)
+        })
+        .collect()
+}
@@ -0,0 +1,91 @@
+//! Tap on the recorded audio for the `~capture` service, recording a fixed
+//! length on demand instead of the continuous stream.
+use std::sync::Arc;
+use std::time::Duration;
+
+use crossbeam::channel::{unbounded, RecvTimeoutError, Sender, TrySendError};
+use itertools::Itertools;
+use parking_lot::Mutex;
+use ssloc::{Audio, F};
+
+/// Longest capture in seconds, the recordings are kept in memory until the
+/// capture is complete.
+pub const MAX_DURATION: F = 60.;
+/// Seconds of diffuse noise recorded by `~calibrate`.
+pub const CALIBRATION: F = 5.;
+/// Time without a recording after which a capture fails, longer than the
+/// longest `recording/frame_length`.
+const TIMEOUT: Duration = Duration::from_secs(11);
+
+#[derive(Clone, Default)]
+pub struct Tap(Arc<Mutex<State>>);
+
+#[derive(Default)]
+struct State {
+    /// Sample rate, channels and interleaved samples of each recording.
+    listeners: Vec<Sender<(F, usize, Vec<F>)>>,
+    /// Why the recorder currently fails to record.
+    error: Option<String>,
+}
+
+impl Tap {
+    /// Forwards a recording to running captures.
+    pub fn send(&self, audio: &Audio) {
+        let mut state = self.0.lock();
+        if state.listeners.is_empty() {
+            return;
+        }
+        let samples = audio.to_interleaved::<F>().collect_vec();
+        state.listeners.retain(|listener| {
+            let recording = (audio.sample_rate(), audio.channels(), samples.clone());
+            !matches!(listener.try_send(recording), Err(TrySendError::Disconnected(_)))
+        });
+    }
+
+    /// Reports whether the recorder is currently failing.
+    pub fn set_error(&self, error: Option<String>) {
+        self.0.lock().error = error;
+    }
+
+    /// Records `duration` seconds of audio.
+    pub fn capture(&self, duration: F) -> Result<Audio, String> {
+        if !(duration > 0. && duration <= MAX_DURATION) {
+            return Err(format!(
+                "duration must be between 0 and {MAX_DURATION} seconds, got {duration}"
+            ));
+        }
+        let (send, recv) = unbounded();
+        {
+            let mut state = self.0.lock();
+            if let Some(error) = &state.error {
+                return Err(format!("recorder is failing: {error}"));
+            }
+            state.listeners.push(send);
+        }
+
+        let mut samples = Vec::new();
+        let mut format = None;
+        loop {
+            let recording = match recv.recv_timeout(TIMEOUT) {
+                Ok(recording) => recording,
+                Err(RecvTimeoutError::Timeout) => {
+                    return Err(match &self.0.lock().error {
+                        Some(error) => format!("recorder is failing: {error}"),
+                        None => format!("no audio was recorded for {} seconds", TIMEOUT.as_secs()),
+                    });
+                }
+                Err(RecvTimeoutError::Disconnected) => unreachable!("the tap holds the sender"),
+            };
+            let (rate, channels) = *format.get_or_insert((recording.0, recording.1));
+            if (rate, channels) != (recording.0, recording.1) {
+                return Err("recording was reconfigured during the capture".to_owned());
+            }
+            samples.extend(recording.2);
+            let len = (duration * rate).round() as usize * channels;
+            if samples.len() >= len {
+                samples.truncate(len);
+                return Ok(Audio::from_interleaved(rate, channels, samples));
+            }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Rendering of the angular spectrum as an image, elevations from bottom to
+//! top and azimuths from left to right like [`ssloc::spec_to_image`].
+use std::io::Cursor;
+
+use image::{
+    DynamicImage, GenericImage, GrayImage, ImageOutputFormat, ImageResult, Luma, Rgb, RgbImage,
+    Rgba,
+};
+use ndarray::ArrayView2;
+use ssloc::{Direction, MbssConfig, F};
+
+use crate::config::{Colormap, Config, ImageFormat, ImageNormalization, MarkerColors};
+
+/// Samples of the palettes at equidistant levels from 0 to 1, interpolated
+/// linearly in between.
+const VIRIDIS: [[u8; 3]; 9] = [
+    [68, 1, 84],
+    [71, 44, 122],
+    [59, 81, 139],
+    [44, 113, 142],
+    [33, 144, 141],
+    [39, 173, 129],
+    [92, 200, 99],
+    [170, 220, 50],
+    [253, 231, 37],
+];
+const MAGMA: [[u8; 3]; 9] = [
+    [0, 0, 4],
+    [28, 16, 68],
+    [79, 18, 123],
+    [129, 37, 129],
+    [181, 54, 122],
+    [229, 80, 100],
+    [251, 135, 97],
+    [254, 194, 135],
+    [252, 253, 191],
+];
+/// Distinguishable colors of tracks, Tableau 10 starting with red.
+const TRACKS: [[u8; 3]; 10] = [
+    [214, 39, 40],
+    [31, 119, 180],
+    [44, 160, 44],
+    [255, 127, 14],
+    [148, 103, 189],
+    [23, 190, 207],
+    [227, 119, 194],
+    [188, 189, 34],
+    [140, 86, 75],
+    [127, 127, 127],
+];
+
+impl Colormap {
+    /// Color of a `level` between 0 and 1.
+    fn color(self, level: F) -> [u8; 3] {
+        let level = level.clamp(0., 1.);
+        match self {
+            Colormap::Grayscale => [(level * 255.) as u8; 3],
+            Colormap::Viridis => interpolate(&VIRIDIS, level),
+            Colormap::Magma => interpolate(&MAGMA, level),
+            Colormap::Jet => {
+                let channel =
+                    |offset: F| ((1.5 - (4. * level - offset).abs()).clamp(0., 1.) * 255.) as u8;
+                [channel(3.), channel(2.), channel(1.)]
+            }
+        }
+    }
+}
+
+impl MarkerColors {
+    /// Color of the marker of track `id` with a `level` relative to the
+    /// strongest source.
+    pub fn color(self, id: i64, level: F) -> [f32; 3] {
+        let color = match self {
+            MarkerColors::Red => [255, 0, 0],
+            MarkerColors::Track => TRACKS[id.rem_euclid(TRACKS.len() as i64) as usize],
+            MarkerColors::Strength => Colormap::Jet.color(level),
+        };
+        color.map(|channel| f32::from(channel) / 255.)
+    }
+}
+
+fn interpolate(palette: &[[u8; 3]], level: F) -> [u8; 3] {
+    let position = level * (palette.len() - 1) as F;
+    let lower = (position.floor() as usize).min(palette.len() - 2);
+    let lerp = position - lower as F;
+    let mut color = [0; 3];
+    for (i, color) in color.iter_mut().enumerate() {
+        let (a, b) = (F::from(palette[lower][i]), F::from(palette[lower + 1][i]));
+        *color = (a + (b - a) * lerp).round() as u8;
+    }
+    color
+}
+
+/// The `spectrum` as image with the colormap and normalization of `config`,
+/// grayscale images have a single channel.
+pub fn spectrum_image(spectrum: ArrayView2<F>, config: &Config) -> DynamicImage {
+    let max = spectrum.iter().copied().fold(F::MIN, F::max);
+    let level = |value: F| match config.spectrum_normalization {
+        ImageNormalization::Frame => value / max,
+        ImageNormalization::Fixed => value / config.spectrum_reference,
+        ImageNormalization::Decibel => {
+            let db = 10. * (value.max(F::MIN_POSITIVE) / config.spectrum_reference).log10();
+            (db + config.spectrum_range_db) / config.spectrum_range_db
+        }
+    };
+    let (width, height) = (spectrum.ncols() as u32, spectrum.nrows() as u32);
+    let pixels = spectrum
+        .indexed_iter()
+        .map(|((y, x), &value)| (x as u32, height - 1 - y as u32, level(value)));
+    if config.spectrum_colormap == Colormap::Grayscale {
+        let mut image = GrayImage::new(width, height);
+        for (x, y, level) in pixels {
+            // truncates like `ssloc::spec_to_image`
+            image.put_pixel(x, y, Luma([(level.clamp(0., 1.) * F::from(u8::MAX)) as u8]));
+        }
+        image.into()
+    } else {
+        let mut image = RgbImage::new(width, height);
+        for (x, y, level) in pixels {
+            image.put_pixel(x, y, Rgb(config.spectrum_colormap.color(level)));
+        }
+        image.into()
+    }
+}
+
+/// Pixels between the center of a crosshair and its arms, which keep the peak
+/// itself visible.
+const CROSSHAIR_GAP: i64 = 2;
+/// Pixels of a crosshair's arms.
+const CROSSHAIR_ARM: i64 = 3;
+
+/// Draws a white crosshair on `image` of a spectrum on the grid of `mbss` at
+/// each of the `peaks`, arms leaving the image are cut off.
+pub fn mark_peaks(
+    image: &mut DynamicImage,
+    mbss: &MbssConfig,
+    peaks: impl IntoIterator<Item = Direction>,
+) {
+    let (width, height) = (i64::from(image.width()), i64::from(image.height()));
+    for peak in peaks {
+        let x = ((peak.azimuth - mbss.azimuth_range.0) / mbss.grid_res).round() as i64;
+        let row = ((peak.elevation - mbss.elevation_range.0) / mbss.grid_res).round() as i64;
+        let y = height - 1 - row;
+        for offset in CROSSHAIR_GAP..CROSSHAIR_GAP + CROSSHAIR_ARM {
+            for (x, y) in [(x - offset, y), (x + offset, y), (x, y - offset), (x, y + offset)] {
+                if (0..width).contains(&x) && (0..height).contains(&y) {
+                    image.put_pixel(x as u32, y as u32, Rgba([u8::MAX; 4]));
+                }
+            }
+        }
+    }
+}
+
+/// Encodes `image` into `buffer` as `spectrum_format`, replacing its content.
+pub fn encode(image: &DynamicImage, config: &Config, buffer: &mut Vec<u8>) -> ImageResult<()> {
+    buffer.clear();
+    let format = match config.spectrum_format {
+        ImageFormat::Png => ImageOutputFormat::Png,
+        ImageFormat::Jpeg => ImageOutputFormat::Jpeg(config.spectrum_jpeg_quality as u8),
+    };
+    image.write_to(&mut Cursor::new(buffer), format)
+}
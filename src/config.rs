@@ -1,13 +1,21 @@
-use std::f64::consts::PI;
-use std::iter;
+use std::f64::consts::{FRAC_PI_2, PI};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::Duration;
+use std::{env, fs, iter};
 
 use alsa::device_name::{Hint, HintIter};
 use alsa::pcm::HwParams;
 use alsa::{Direction, PCM};
-use nalgebra::vector;
-use rosrust::ros_info;
-use rosrust_dynamic_reconfigure::{Group, GroupType, Property, Type, Value, Variant};
-use ssloc::{Format, MbssConfig, Position};
+use itertools::Itertools;
+use nalgebra::{vector, UnitQuaternion};
+use ndarray::ArrayView2;
+use rosrust::{ros_err, ros_info, ros_warn};
+use rosrust_dynamic_reconfigure::{Config as _, Group, GroupType, Property, Type, Value, Variant};
+use ssloc::{Format, MbssConfig, Position, F};
+use yaml_rust::{Yaml, YamlLoader};
+
+use crate::{convention, geometry, spectrum};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Device {
@@ -18,36 +26,43 @@ pub struct Device {
     pub formats: Vec<Format>,
 }
 
-impl From<&Device> for Variant {
-    fn from(value: &Device) -> Self {
-        Variant {
-            name: value.description.clone(),
-            type_: Type::String,
-            value: (&value.name).into(),
-            description: String::new(),
-        }
+impl Device {
+    /// Supported format closest to `format` other than itself, preferring the
+    /// same kind of samples (integer or float), then the closest bit depth and
+    /// finally the same signedness.
+    pub fn nearest_format(&self, format: Format) -> Option<Format> {
+        // (float, bits, signed)
+        let kind = |format| match format {
+            Format::S8 => (false, 8, true),
+            Format::U8 => (false, 8, false),
+            Format::S16 => (false, 16, true),
+            Format::U16 => (false, 16, false),
+            Format::S32 => (false, 32, true),
+            Format::U32 => (false, 32, false),
+            Format::F32 => (true, 32, true),
+            Format::F64 => (true, 64, true),
+        };
+        let (float, bits, signed) = kind(format);
+        self.formats
+            .iter()
+            .copied()
+            .filter(|&other| other != format)
+            .min_by_key(|&other| {
+                let (other_float, other_bits, other_signed) = kind(other);
+                // prefer higher bit depths on ties to not lose precision
+                (
+                    other_float != float,
+                    i32::abs_diff(bits, other_bits),
+                    other_bits < bits,
+                    other_signed != signed,
+                )
+            })
     }
-}
 
-#[derive(Debug, Clone)]
-pub struct Config {
-    pub format: Format,
-    pub rate: u16,
-    pub audio_message_topic: Option<String>,
-    pub device: Device,
-    pub devices: Vec<Device>,
-    pub localisation_frame: f64,
-    pub channels: u16,
-    pub mics: Vec<(Position, bool)>,
-    pub max_sources: u16,
-    pub mbss: MbssConfig,
-    pub mbss_ssl_threshold: f64,
-    pub tracking_persistence: f64,
-}
-impl Config {
-    pub fn init() -> rosrust::api::error::Result<Config> {
-        let devices: Vec<_> = HintIter::new_str(None, "pcm")
-            .map_err(|e| e.to_string())?
+    /// Capture devices that can currently be opened, devices in use by
+    /// another recorder are missing.
+    pub fn enumerate() -> alsa::Result<Vec<Device>> {
+        Ok(HintIter::new_str(None, "pcm")?
             .chain(iter::once(Hint {
                 name: Some("default".into()),
                 desc: Some("System Default".into()),
@@ -82,7 +97,7 @@ impl Config {
                         .or_else(|| {
                             Some((
                                 params.get_rate_min().ok()? as u16,
-                                params.get_rate_max().ok()? as u16,
+                                params.get_rate_max().ok()?.min(u16::MAX.into()) as u16,
                             ))
                         })?;
                     Some(Device {
@@ -94,27 +109,1044 @@ impl Config {
                     })
                 },
             )
-            .collect();
-        Ok(Config {
+            .collect())
+    }
+
+    /// Whether `rate` is within the rates the device reported.
+    pub fn supports_rate(&self, rate: u16) -> bool {
+        (self.rate.0..=self.rate.1).contains(&rate)
+    }
+
+    /// Supported rate closest to `rate` other than itself, trying the common
+    /// rates before the limits of the reported range, as devices often only
+    /// support a few rates within it.
+    pub fn nearest_rate(&self, rate: u16) -> Option<u16> {
+        [8000, 11025, 16000, 22050, 32000, 44100, 48000]
+            .into_iter()
+            .chain([self.rate.0, self.rate.1])
+            .filter(|&other| other != rate && self.supports_rate(other))
+            // prefer higher rates on ties to downsample rather than upsample
+            .min_by_key(|&other| (u16::abs_diff(rate, other), other < rate))
+    }
+}
+
+impl From<&Device> for Variant {
+    fn from(value: &Device) -> Self {
+        Variant {
+            name: value.description.clone(),
+            type_: Type::String,
+            value: (&value.name).into(),
+            description: String::new(),
+        }
+    }
+}
+
+/// Implements `Display` and `FromStr` for a field-less enum, and provides the
+/// names via `names()` for use in enum properties.
+macro_rules! string_enum {
+    ($ty:ident { $($variant:ident => $name:literal),* $(,)? }) => {
+        impl $ty {
+            fn names() -> impl Iterator<Item = &'static str> {
+                [$($name),*].into_iter()
+            }
+        }
+
+        impl Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(match self {
+                    $(Self::$variant => $name,)*
+                })
+            }
+        }
+
+        impl FromStr for $ty {
+            type Err = String;
+
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                Ok(match s.to_ascii_lowercase().as_str() {
+                    $($name => Self::$variant,)*
+                    e => return Err(format!("Unsupported {} {e:?}", stringify!($ty))),
+                })
+            }
+        }
+    };
+}
+
+/// Handling of sources stronger than [`Config::max_strength`], usually
+/// artifacts of clipping or overloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overload {
+    /// Drops the source.
+    Drop,
+    /// Keeps the source with its strength capped to the maximum.
+    Cap,
+}
+string_enum!(Overload { Drop => "drop", Cap => "cap" });
+
+/// How many of the peaks are reported as sources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceSelection {
+    /// The `max_sources` strongest peaks.
+    FixedCount,
+    /// The peaks above `prominence_ratio` times the strongest one, at most
+    /// `max_sources`.
+    Prominence,
+}
+string_enum!(SourceSelection { FixedCount => "fixed_count", Prominence => "prominence" });
+
+/// Source single-target outputs like `heading` report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Follow {
+    /// The currently strongest source.
+    Strongest,
+    /// The source reported last, as long as it is still tracked.
+    Tracked,
+}
+string_enum!(Follow { Strongest => "strongest", Tracked => "tracked" });
+
+/// Where the recorder takes audio from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputSource {
+    /// The ALSA `device`, or `audio_message_topic` if set.
+    Device,
+    /// Replays the WAV file at `input_file`.
+    File,
+    /// Replays a synthesized source at `input_mock_azimuth` and
+    /// `input_mock_elevation`, see [`crate::source::MockRecorder::tone`].
+    Mock,
+}
+string_enum!(InputSource { Device => "device", File => "file", Mock => "mock" });
+
+/// Sample format of published audio, independent of the recording
+/// [`Config::format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublishFormat {
+    S16,
+    S24,
+    S32,
+    F32,
+}
+string_enum!(PublishFormat { S16 => "s16le", S24 => "s24le", S32 => "s32le", F32 => "f32le" });
+
+impl PublishFormat {
+    pub fn bits(self) -> u32 {
+        match self {
+            PublishFormat::S16 => 16,
+            PublishFormat::S24 => 24,
+            PublishFormat::S32 | PublishFormat::F32 => 32,
+        }
+    }
+}
+
+/// Directions the analysis searches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// The grid of `mbss/azimuth` × `mbss/elevation`.
+    Spherical3D,
+    /// Only the azimuths at zero elevation, for linear arrays that cannot
+    /// resolve the elevation.
+    Azimuth2D,
+}
+string_enum!(Mode { Spherical3D => "spherical_3d", Azimuth2D => "azimuth_2d" });
+
+/// Local angular spectrum of a mic pair, see [`crate::subband`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpectrumMethod {
+    /// GCC-PHAT, the phase transform weighted cross-correlation.
+    GccPhat,
+    /// GCC-NONLIN, GCC-PHAT with a nonlinear function emphasizing the
+    /// frequencies in phase, which sharpens the peaks.
+    GccNonlin,
+}
+string_enum!(SpectrumMethod { GccPhat => "gcc_phat", GccNonlin => "gcc_nonlin" });
+
+/// Window function of the STFT the angular spectrum is computed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Window {
+    /// The sine window of MBSS Locate and [`ssloc::Mbss`].
+    Sine,
+    Hann,
+    Hamming,
+    /// No window, the narrowest main lobe but the highest side lobes.
+    Rectangular,
+}
+string_enum!(Window {
+    Sine => "sine",
+    Hann => "hann",
+    Hamming => "hamming",
+    Rectangular => "rectangular",
+});
+
+/// Operator combining the angular spectra of sub-bands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fusion {
+    Sum,
+    /// Product of the bands, only directions strong in all bands remain.
+    Product,
+}
+string_enum!(Fusion { Sum => "sum", Product => "product" });
+
+/// Strength reported for a peak of the angular spectrum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrengthMeasure {
+    /// The value at the peak.
+    PeakSample,
+    /// The energy integrated over the peak's lobe, less sensitive to how the
+    /// peak aligns with the grid.
+    IntegratedLobe,
+}
+string_enum!(StrengthMeasure {
+    PeakSample => "peak_sample",
+    IntegratedLobe => "integrated_lobe",
+});
+
+/// Instant of a recording its header stamp refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StampAt {
+    /// The first sample.
+    Start,
+    /// Halfway through, e.g. for fusion assuming the capture midpoint.
+    Middle,
+    /// After the last sample.
+    End,
+}
+string_enum!(StampAt { Start => "start", Middle => "middle", End => "end" });
+
+impl StampAt {
+    /// Fraction of the duration of a recording after its start.
+    pub fn fraction(self) -> F {
+        match self {
+            StampAt::Start => 0.,
+            StampAt::Middle => 0.5,
+            StampAt::End => 1.,
+        }
+    }
+}
+
+/// Reduction of the angular spectrum along one axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reduction {
+    Max,
+    Sum,
+}
+string_enum!(Reduction { Max => "max", Sum => "sum" });
+
+/// Encoding of the spectrum image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Lossless.
+    Png,
+    /// Lossy with `spectrum_jpeg_quality`, a fraction of the size.
+    Jpeg,
+}
+string_enum!(ImageFormat { Png => "png", Jpeg => "jpeg" });
+
+/// Palette the spectrum image maps normalized magnitudes through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colormap {
+    Grayscale,
+    Viridis,
+    Magma,
+    Jet,
+}
+string_enum!(Colormap {
+    Grayscale => "grayscale",
+    Viridis => "viridis",
+    Magma => "magma",
+    Jet => "jet",
+});
+
+/// Colors of the arrow markers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkerColors {
+    /// Red for all sources.
+    Red,
+    /// A distinct color per track id.
+    Track,
+    /// From blue to red with the strength relative to the strongest source.
+    Strength,
+}
+string_enum!(MarkerColors { Red => "red", Track => "track", Strength => "strength" });
+
+/// Reference the spectrum image is normalized to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageNormalization {
+    /// Linear up to the maximum of each frame.
+    Frame,
+    /// Linear up to a fixed reference, so brightness is comparable between
+    /// frames.
+    Fixed,
+    /// Logarithmic over a fixed range of decibels below a fixed reference.
+    Decibel,
+}
+string_enum!(ImageNormalization {
+    Frame => "frame",
+    Fixed => "fixed",
+    Decibel => "decibel",
+});
+
+/// Handedness of the coordinate frame the mic positions are specified in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Handedness {
+    /// Right-handed, as used by ROS.
+    Right,
+    /// Left-handed, e.g. exported from some CAD tools, mirrored along y on
+    /// import.
+    Left,
+}
+string_enum!(Handedness { Right => "right", Left => "left" });
+
+/// Collects the scalars of `yaml` by their path of keys joined by `/`,
+/// starting at `prefix`.
+fn flatten(prefix: String, yaml: &Yaml, parameters: &mut Vec<(String, Yaml)>) {
+    let Yaml::Hash(hash) = yaml else {
+        parameters.push((prefix, yaml.clone()));
+        return;
+    };
+    for (key, value) in hash {
+        let key = match key {
+            Yaml::Integer(key) => key.to_string(),
+            key => key.as_str().unwrap_or_default().to_owned(),
+        };
+        let path = if prefix.is_empty() { key } else { format!("{prefix}/{key}") };
+        flatten(path, value, parameters);
+    }
+}
+
+/// `yaml` as value of a parameter currently set to `current`, integers are
+/// accepted for floats and scalars for strings.
+fn yaml_value(current: &Value, yaml: &Yaml) -> Option<Value> {
+    Some(match (current, yaml) {
+        (Value::Bool(_), &Yaml::Boolean(value)) => Value::Bool(value),
+        (Value::Int(_), &Yaml::Integer(value)) => Value::Int(value.try_into().ok()?),
+        (Value::Float(_), &Yaml::Integer(value)) => Value::Float(value as f64),
+        (Value::Float(_), Yaml::Real(_)) => Value::Float(yaml.as_f64()?),
+        (Value::Str(_), Yaml::String(value) | Yaml::Real(value)) => Value::Str(value.clone()),
+        // e.g. a `channel_map` of a single channel
+        (Value::Str(_), Yaml::Integer(value)) => Value::Str(value.to_string()),
+        _ => return None,
+    })
+}
+
+/// Parameters that require reopening the audio input when changed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Capture {
+    device: Device,
+    rate: u16,
+    format: Format,
+    channels: u16,
+    channel_map: Vec<u16>,
+    localisation_frame: f64,
+    audio_message_topic: Option<String>,
+    audio_message_stamped: bool,
+    input_source: InputSource,
+    input_file: String,
+    input_file_loop: bool,
+    input_mock_azimuth: f64,
+    input_mock_elevation: f64,
+}
+
+/// Parameters that require rebuilding the analyzer when changed, all others
+/// are applied to the running analysis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    channels: u16,
+    mics: Vec<Position>,
+    analyzer: MbssConfig,
+    spectrum_method: SpectrumMethod,
+    subbands: Option<(u16, Fusion)>,
+    band: Option<(F, F)>,
+    stft: (Option<usize>, Window),
+    threads: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub format: Format,
+    pub rate: u16,
+    pub audio_message_topic: Option<String>,
+    pub device: Device,
+    pub devices: Vec<Device>,
+    pub localisation_frame: f64,
+    pub channels: u16,
+    /// Device channels recorded as the `channels` mics, in order, empty to
+    /// record the first `channels`.
+    pub channel_map: Vec<u16>,
+    /// Linear gain of each mic channel, missing ones are 1.
+    pub channel_gains: Vec<F>,
+    pub mics: Vec<(Position, bool)>,
+    pub max_sources: u16,
+    pub source_selection: SourceSelection,
+    pub prominence_ratio: f64,
+    pub mbss: MbssConfig,
+    pub mbss_ssl_threshold: f64,
+    pub max_strength: f64,
+    pub min_source_strength: f64,
+    pub nms_radius: f64,
+    pub overload: Overload,
+    pub tracking_persistence: f64,
+    pub rep103: bool,
+    pub reconfigure_debounce: f64,
+    pub spectrum_grid_elevations: u16,
+    pub spectrum_grid_azimuths: u16,
+    pub heading_source: Follow,
+    pub heading_degrees: bool,
+    pub marker_alpha_by_confidence: bool,
+    pub publish_empty: bool,
+    pub geometry_handedness: Handedness,
+    pub steering_azimuth: f64,
+    pub steering_elevation: f64,
+    pub vad_enabled: bool,
+    pub vad_aggressiveness: u16,
+    pub vad_channel: i32,
+    pub interpolation_factor: u16,
+    pub dominant_source: Follow,
+    pub aliasing_warning: bool,
+    pub debug_candidates: bool,
+    pub audio_publish_format: PublishFormat,
+    pub max_sources_cap: u16,
+    pub adaptive_threshold: bool,
+    pub adaptive_threshold_multiplier: f64,
+    pub adaptive_threshold_rate: f64,
+    pub spectrum_flip_x: bool,
+    pub spectrum_flip_y: bool,
+    pub aggregate_output: bool,
+    pub max_angular_velocity: f64,
+    pub snr: bool,
+    pub subband_fusion: bool,
+    pub subband_count: u16,
+    pub subband_fusion_operator: Fusion,
+    pub spectrum_method: SpectrumMethod,
+    pub allow_format_fallback: bool,
+    pub spectrum_marginal_azimuth: bool,
+    pub spectrum_marginal_elevation: bool,
+    pub spectrum_marginal_reduction: Reduction,
+    pub flatten_elevation: bool,
+    pub strength_measure: StrengthMeasure,
+    pub strength_lobe_level: f64,
+    pub optical_frame: bool,
+    pub reconfigure_min_interval: f64,
+    pub marker_strength_scale: f64,
+    pub marker_min_length: f64,
+    pub marker_max_length: f64,
+    pub input_source: InputSource,
+    pub input_file: String,
+    pub input_file_loop: bool,
+    pub input_mock_azimuth: f64,
+    pub input_mock_elevation: f64,
+    pub record_to_file: String,
+    pub frame_id: String,
+    pub tracking_gate: f64,
+    pub tracking_max_missed: u16,
+    pub max_drop_rate: f64,
+    pub silence_threshold: f64,
+    pub silence_hold: u16,
+    pub spectrum_colormap: Colormap,
+    pub spectrum_normalization: ImageNormalization,
+    pub spectrum_reference: f64,
+    pub spectrum_range_db: f64,
+    pub spectrum_raw: bool,
+    pub num_threads: u16,
+    pub geometry_file: String,
+    /// Positions loaded from `geometry_file`.
+    mic_geometry: Option<Vec<Position>>,
+    pub window_overlap: f64,
+    pub mask_azimuth_min: f64,
+    pub mask_azimuth_max: f64,
+    pub mask_elevation_min: f64,
+    pub mask_elevation_max: f64,
+    pub tf_enabled: bool,
+    pub tf_distance: f64,
+    pub tf_prefix: String,
+    pub marker_lifetime: f64,
+    pub marker_colors: MarkerColors,
+    pub beamformed: bool,
+    pub process_every_n: u16,
+    pub freq_min: f64,
+    pub freq_max: f64,
+    pub invert_azimuth: bool,
+    pub invert_elevation: bool,
+    pub azimuth_offset: f64,
+    pub mode: Mode,
+    pub spectrum_format: ImageFormat,
+    pub spectrum_jpeg_quality: u16,
+    pub spectrogram_frames: u16,
+    pub warmup_frames: u16,
+    pub stamp_at: StampAt,
+    pub fft_size: u16,
+    pub window: Window,
+    pub audio_message_stamped: bool,
+    pub ssl_map: bool,
+    pub audio_timeout: f64,
+    pub tracking_smoothing: f64,
+}
+impl Config {
+    /// Config at startup, the defaults overridden by the YAML file at
+    /// `~config_file` or `$SSLOC_CONFIG`, see [`Config::load`]. Parameters on
+    /// the parameter server are applied by the reconfigure server afterwards,
+    /// taking precedence over the file.
+    pub fn init() -> rosrust::api::error::Result<Config> {
+        let mut config = Self::defaults()?;
+        let path = rosrust::param("~config_file")
+            .and_then(|param| param.get::<String>().ok())
+            .or_else(|| env::var("SSLOC_CONFIG").ok())
+            .filter(|path| !path.is_empty());
+        if let Some(path) = path {
+            match config.load(&path) {
+                Ok(count) => {
+                    ros_info!("loaded {count} parameters from `{path}`");
+                }
+                Err(err) => {
+                    ros_err!("error loading `{path}`: {err}");
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    /// Applies the parameters of the YAML file at `path`, nested like
+    /// `param/defaults.yaml`, returning how many were set. Unknown and
+    /// invalid parameters are skipped with a warning, numbers outside the
+    /// range of their parameter are clamped to it.
+    pub fn load(&mut self, path: &str) -> Result<usize, String> {
+        let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let documents = YamlLoader::load_from_str(&text).map_err(|e| e.to_string())?;
+        let mut parameters = Vec::new();
+        if let Some(document) = documents.first() {
+            flatten(String::new(), document, &mut parameters);
+        }
+        let mut count = 0;
+        for (name, yaml) in parameters {
+            // looked up every time, the parameters depend on the ones set
+            // before, e.g. the `mic/*` on `recording/channels`
+            let Some(property) = self.properties().into_iter().find(|p| p.name == name) else {
+                ros_warn!("`{path}`: unknown parameter `{name}`, skipping");
+                continue;
+            };
+            let Some(value) = yaml_value(&property.value, &yaml) else {
+                ros_warn!("`{path}`: expected {} for `{name}`, skipping", property.type_);
+                continue;
+            };
+            let clamped = match (&value, &property.min, &property.max) {
+                (&Value::Int(value), &Value::Int(min), &Value::Int(max)) => {
+                    Value::Int(value.max(min).min(max))
+                }
+                (&Value::Float(value), &Value::Float(min), &Value::Float(max)) => {
+                    Value::Float(value.max(min).min(max))
+                }
+                _ => value.clone(),
+            };
+            if clamped != value {
+                ros_warn!("`{path}`: `{name}` {value} is out of range, clamped to {clamped}");
+            }
+            match self.set(&name, clamped) {
+                Ok(()) => count += 1,
+                Err(err) => {
+                    ros_warn!("`{path}`: invalid `{name}`, skipping: {err}");
+                }
+            }
+            self.clean_up();
+        }
+        Ok(count)
+    }
+
+    fn defaults() -> rosrust::api::error::Result<Config> {
+        let devices = Device::enumerate().map_err(|e| e.to_string())?;
+        Ok(Self::with_devices(devices))
+    }
+
+    /// Defaults recording from the first of `devices`.
+    fn with_devices(devices: Vec<Device>) -> Config {
+        Config {
             format: devices[0].formats[0],
             rate: devices[0].rate.0,
             audio_message_topic: None,
             device: devices[0].clone(),
             localisation_frame: 1.0,
             channels: devices[0].channels.0,
+            channel_map: Vec::new(),
+            channel_gains: Vec::new(),
             devices,
             mics: vec![(vector!(0., 0., 0.), true); 20],
             max_sources: 5,
+            source_selection: SourceSelection::FixedCount,
+            prominence_ratio: 0.4,
             mbss: MbssConfig::default(),
             mbss_ssl_threshold: 5000.,
+            max_strength: 0.,
+            min_source_strength: 0.,
+            nms_radius: 10f64.to_radians(),
+            overload: Overload::Drop,
             tracking_persistence: 1.,
+            rep103: false,
+            reconfigure_debounce: 0.5,
+            spectrum_grid_elevations: 0,
+            spectrum_grid_azimuths: 0,
+            heading_source: Follow::Strongest,
+            heading_degrees: false,
+            marker_alpha_by_confidence: false,
+            publish_empty: true,
+            geometry_handedness: Handedness::Right,
+            steering_azimuth: 0.,
+            steering_elevation: 0.,
+            vad_enabled: false,
+            vad_aggressiveness: 1,
+            vad_channel: -1,
+            interpolation_factor: 1,
+            dominant_source: Follow::Strongest,
+            aliasing_warning: true,
+            debug_candidates: false,
+            audio_publish_format: PublishFormat::F32,
+            max_sources_cap: 10,
+            adaptive_threshold: false,
+            adaptive_threshold_multiplier: 3.,
+            adaptive_threshold_rate: 0.05,
+            spectrum_flip_x: false,
+            spectrum_flip_y: false,
+            aggregate_output: false,
+            max_angular_velocity: 0.,
+            snr: false,
+            subband_fusion: false,
+            subband_count: 4,
+            subband_fusion_operator: Fusion::Sum,
+            spectrum_method: SpectrumMethod::GccPhat,
+            allow_format_fallback: false,
+            spectrum_marginal_azimuth: false,
+            spectrum_marginal_elevation: false,
+            spectrum_marginal_reduction: Reduction::Max,
+            flatten_elevation: false,
+            strength_measure: StrengthMeasure::PeakSample,
+            strength_lobe_level: 0.5,
+            optical_frame: false,
+            reconfigure_min_interval: 0.,
+            marker_strength_scale: 8000.,
+            marker_min_length: 0.2,
+            marker_max_length: 2.,
+            input_source: InputSource::Device,
+            input_file: String::new(),
+            input_file_loop: false,
+            input_mock_azimuth: 0.,
+            input_mock_elevation: 0.,
+            record_to_file: String::new(),
+            frame_id: "ssloc".to_owned(),
+            tracking_gate: 0.1,
+            tracking_max_missed: 5,
+            max_drop_rate: 0.05,
+            silence_threshold: 0.,
+            silence_hold: 3,
+            spectrum_colormap: Colormap::Grayscale,
+            spectrum_normalization: ImageNormalization::Frame,
+            spectrum_reference: 10000.,
+            spectrum_range_db: 40.,
+            spectrum_raw: true,
+            num_threads: 1,
+            geometry_file: String::new(),
+            mic_geometry: None,
+            window_overlap: 0.,
+            mask_azimuth_min: -PI,
+            mask_azimuth_max: PI,
+            mask_elevation_min: -PI,
+            mask_elevation_max: PI,
+            tf_enabled: false,
+            tf_distance: 1.,
+            tf_prefix: "ssloc/source_".to_owned(),
+            marker_lifetime: 1.,
+            marker_colors: MarkerColors::Red,
+            beamformed: false,
+            process_every_n: 1,
+            freq_min: 0.,
+            freq_max: 24_000.,
+            invert_azimuth: false,
+            invert_elevation: false,
+            azimuth_offset: 0.,
+            mode: Mode::Spherical3D,
+            spectrum_format: ImageFormat::Png,
+            spectrum_jpeg_quality: 80,
+            spectrogram_frames: 100,
+            warmup_frames: 2,
+            stamp_at: StampAt::Start,
+            fft_size: 0,
+            window: Window::Sine,
+            audio_message_stamped: cfg!(feature = "audio_common_msgs-stamped"),
+            ssl_map: false,
+            audio_timeout: 5.,
+            tracking_smoothing: 0.,
+        }
+    }
+
+    /// Strength of the peak at `direction` of `spectrum` according to
+    /// `strength_measure`, `sampled` is the strength reported by the peak
+    /// picker.
+    pub fn strength(&self, spectrum: ArrayView2<F>, direction: ssloc::Direction, sampled: F) -> F {
+        match self.strength_measure {
+            StrengthMeasure::PeakSample => sampled,
+            StrengthMeasure::IntegratedLobe => {
+                spectrum::lobe_energy(spectrum, &self.grid(), direction, self.strength_lobe_level)
+            }
+        }
+    }
+
+    /// Applies the acceptance band (`ssl_threshold`, `max_strength`) to a
+    /// source's strength, returns the strength to report or `None` if the
+    /// source should be dropped.
+    pub fn accept_strength(&self, strength: f64) -> Option<f64> {
+        if strength <= self.mbss_ssl_threshold {
+            None
+        } else if self.max_strength <= 0. || strength <= self.max_strength {
+            Some(strength)
+        } else {
+            match self.overload {
+                Overload::Drop => None,
+                Overload::Cap => Some(self.max_strength),
+            }
+        }
+    }
+
+    /// Applies `source_selection` to the `peaks` found, strongest first.
+    pub fn select_sources(
+        &self,
+        mut peaks: Vec<(ssloc::Direction, F)>,
+    ) -> Vec<(ssloc::Direction, F)> {
+        if self.source_selection == SourceSelection::Prominence {
+            let floor = peaks.first().map_or(0., |&(_, strongest)| strongest)
+                * self.prominence_ratio;
+            peaks.retain(|&(_, strength)| strength >= floor);
+        }
+        peaks
+    }
+
+    /// Fixed grid (elevations × azimuths) the published spectrum array is
+    /// resampled to, `None` to publish the analyzer's grid.
+    pub fn spectrum_grid(&self) -> Option<(usize, usize)> {
+        (self.spectrum_grid_elevations > 0 && self.spectrum_grid_azimuths > 0).then_some((
+            self.spectrum_grid_elevations.into(),
+            self.spectrum_grid_azimuths.into(),
+        ))
+    }
+
+    /// Quiet period after the last reconfigure update before the recorder or
+    /// analyzer are rebuilt.
+    pub fn debounce(&self) -> Duration {
+        Duration::from_secs_f64(self.reconfigure_debounce)
+    }
+
+    /// Minimal time between two rebuilds of the recorder or analyzer.
+    pub fn rebuild_interval(&self) -> Duration {
+        Duration::from_secs_f64(self.reconfigure_min_interval)
+    }
+
+    /// Whether any of the `mbss/mask/*` ranges excludes directions.
+    pub fn masking(&self) -> bool {
+        self.mask_azimuth_min > -PI
+            || self.mask_azimuth_max < PI
+            || self.mask_elevation_min > -PI
+            || self.mask_elevation_max < PI
+    }
+
+    /// Whether `direction`, in the frame of the grid, is within the
+    /// `mbss/mask/*` ranges. Azimuth ranges with a minimum above the maximum
+    /// wrap around ±π.
+    pub fn unmasked(&self, direction: ssloc::Direction) -> bool {
+        let azimuth = (direction.azimuth + PI).rem_euclid(2. * PI) - PI;
+        let (min, max) = (self.mask_azimuth_min, self.mask_azimuth_max);
+        let azimuth = if min <= max {
+            (min..=max).contains(&azimuth)
+        } else {
+            azimuth >= min || azimuth <= max
+        };
+        azimuth
+            && (self.mask_elevation_min..=self.mask_elevation_max).contains(&direction.elevation)
+    }
+
+    /// Whether a tracked source moving by `distance` (radians) within `elapsed`
+    /// seconds stays within `max_angular_velocity`.
+    pub fn plausible_motion(&self, distance: F, elapsed: f64) -> bool {
+        self.max_angular_velocity <= 0.
+            || distance <= self.max_angular_velocity.to_radians() * elapsed.max(0.)
+    }
+
+    /// Checks that the mics match the recorded channels, the band the rate and
+    /// that the device can plausibly record them, returning every problem
+    /// found, one per line. The positions of the mics are only checked to
+    /// `localize`.
+    pub fn validate(&self, localize: bool) -> Result<(), String> {
+        let mut problems = Vec::new();
+        if usize::from(self.channels) > self.mics.len() {
+            problems.push(format!(
+                "`recording/channels` {} exceeds the {} supported mics",
+                self.channels,
+                self.mics.len()
+            ));
+        } else if localize {
+            let mics = self.enabled_mics().collect_vec();
+            for (idx, mic) in &mics {
+                if !mic.iter().all(|coordinate| coordinate.is_finite()) {
+                    problems.push(format!("mic {idx} is at {}, {}, {}", mic.x, mic.y, mic.z));
+                }
+            }
+            for ((a, mic_a), (b, mic_b)) in mics.iter().tuple_combinations() {
+                // also rejects mics whose positions were never set
+                if (mic_a - mic_b).norm() < 1e-6 {
+                    problems.push(format!("mics {a} and {b} are at the same position"));
+                }
+            }
+        }
+        if self.rate == 0 {
+            problems.push("`recording/rate` is 0".to_owned());
+        }
+        let nyquist = F::from(self.rate) / 2.;
+        if self.freq_min > 0. && self.freq_min >= nyquist {
+            problems.push(format!(
+                "`mbss/freq_min` {} Hz is not below the nyquist frequency {nyquist} Hz",
+                self.freq_min
+            ));
+        }
+        if self.input_source == InputSource::Device {
+            let device = &self.device;
+            let channels = self.device_channels();
+            if !(device.channels.0..=device.channels.1).contains(&channels) {
+                problems.push(format!(
+                    "`{}` records {} to {} channels, not {channels}",
+                    device.name, device.channels.0, device.channels.1
+                ));
+            }
+            if !(device.rate.0..=device.rate.1).contains(&self.rate) {
+                problems.push(format!(
+                    "`{}` records at {} to {} Hz, not {} Hz",
+                    device.name, device.rate.0, device.rate.1, self.rate
+                ));
+            }
+            if !device.formats.contains(&self.format) {
+                problems.push(format!("`{}` does not record {}", device.name, self.format));
+            }
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems.join("\n"))
+        }
+    }
+
+    /// Parameters of the audio input, processing parameters are not included so
+    /// changing them never interrupts capture.
+    pub fn capture(&self) -> Capture {
+        Capture {
+            device: self.device.clone(),
+            rate: self.rate,
+            format: self.format,
+            channels: self.channels,
+            channel_map: self.channel_map.clone(),
+            localisation_frame: self.localisation_frame,
+            audio_message_topic: self.audio_message_topic.clone(),
+            audio_message_stamped: self.audio_message_stamped,
+            input_source: self.input_source,
+            input_file: self.input_file.clone(),
+            input_file_loop: self.input_file_loop,
+            input_mock_azimuth: self.input_mock_azimuth,
+            input_mock_elevation: self.input_mock_elevation,
+        }
+    }
+
+    /// Channels the device is opened with, enough to record all channels of
+    /// `channel_map`.
+    pub fn device_channels(&self) -> u16 {
+        match self.channel_map.iter().max() {
+            Some(&max) => (max + 1).max(self.device.channels.0),
+            None => self.channels,
+        }
+    }
+
+    /// Parameters the analyzer is built from.
+    pub fn analysis(&self) -> Analysis {
+        Analysis {
+            channels: self.channels,
+            mics: self.mic_positions(),
+            analyzer: self.analyzer(),
+            spectrum_method: self.spectrum_method,
+            subbands: self.subbands(),
+            band: self.band(),
+            stft: self.stft(),
+            threads: self.threads(),
+        }
+    }
+
+    /// Takes every parameter of `update` but those [`Self::analysis`] is
+    /// derived from, which only apply once the analyzer is rebuilt.
+    pub fn apply_processing(&mut self, update: &Self) {
+        let analysis = cfg!(debug_assertions).then(|| self.analysis());
+        *self = Self {
+            channels: self.channels,
+            mics: std::mem::take(&mut self.mics),
+            geometry_handedness: self.geometry_handedness,
+            mbss: self.mbss,
+            interpolation_factor: self.interpolation_factor,
+            mode: self.mode,
+            spectrum_method: self.spectrum_method,
+            subband_fusion: self.subband_fusion,
+            subband_count: self.subband_count,
+            subband_fusion_operator: self.subband_fusion_operator,
+            rate: self.rate,
+            freq_min: self.freq_min,
+            freq_max: self.freq_max,
+            fft_size: self.fft_size,
+            window: self.window,
+            num_threads: self.num_threads,
+            ..update.clone()
+        };
+        if let Some(analysis) = analysis {
+            debug_assert_eq!(self.analysis(), analysis, "analysis changed without a rebuild");
+        }
+    }
+
+    /// Frequencies in Hz the angular spectrum is limited to, `freq_max`
+    /// clamped to nyquist. `None` for all frequencies, also if the band is
+    /// empty.
+    pub fn band(&self) -> Option<(F, F)> {
+        let nyquist = F::from(self.rate) / 2.;
+        let max = self.freq_max.min(nyquist);
+        (self.freq_min < max && (self.freq_min > 0. || max < nyquist))
+            .then_some((self.freq_min, max))
+    }
+
+    /// STFT window length in samples, `None` for the 64 ms of [`ssloc::Mbss`],
+    /// and the window function.
+    ///
+    /// Longer windows resolve the frequencies more finely, `rate / size` Hz
+    /// apart, but a recording has to span at least one window and its frames
+    /// are fewer, smoothing the spectrum over a longer time.
+    pub fn stft(&self) -> (Option<usize>, Window) {
+        let size =
+            (self.fft_size > 0).then(|| usize::from(self.fft_size).next_power_of_two().max(4));
+        (size, self.window)
+    }
+
+    /// Number of sub-bands and their fusion, if enabled.
+    pub fn subbands(&self) -> Option<(u16, Fusion)> {
+        self.subband_fusion
+            .then_some((self.subband_count, self.subband_fusion_operator))
+    }
+
+    /// Time a recording of the device may block before the recorder is
+    /// replaced, at least two `frame_length`s so long frames do not trip it,
+    /// `None` to wait forever. Also `None` for recording sources other than
+    /// the device, there is no PCM to reopen.
+    pub fn watchdog_timeout(&self) -> Option<Duration> {
+        (self.input_source == InputSource::Device && self.audio_timeout > 0.).then(|| {
+            Duration::from_secs_f64(self.audio_timeout.max(2. * self.localisation_frame))
         })
     }
+
+    /// Threads computing the angular spectrum in parallel, `None` for the
+    /// serial analysis, which is also used without the `rayon` feature.
+    pub fn threads(&self) -> Option<usize> {
+        (cfg!(feature = "rayon") && self.num_threads != 1).then_some(self.num_threads.into())
+    }
+
+    /// Configuration the analyzer is created with.
+    ///
+    /// GCC-PHAT evaluates the cross-correlation in the frequency domain at
+    /// arbitrary lags, so instead of upsampling the correlation,
+    /// `interpolation_factor` samples the TDOA grid of each mic pair more
+    /// finely than `alpha_res`, trading CPU for angular precision.
+    pub fn analyzer(&self) -> MbssConfig {
+        MbssConfig {
+            alpha_res: self.mbss.alpha_res / F::from(self.interpolation_factor.max(1)),
+            ..self.grid()
+        }
+    }
+
+    /// Configuration with the grid the angular spectrum covers, a single
+    /// elevation of 0 in [`Mode::Azimuth2D`].
+    pub fn grid(&self) -> MbssConfig {
+        match self.mode {
+            Mode::Spherical3D => self.mbss,
+            Mode::Azimuth2D => MbssConfig {
+                elevation_range: (0., self.mbss.grid_res),
+                ..self.mbss
+            },
+        }
+    }
+
+    /// Positions of the enabled mics in the right-handed frame the analysis
+    /// runs in.
+    pub fn mic_positions(&self) -> Vec<Position> {
+        self.enabled_mics().map(|(_, pos)| pos).collect()
+    }
+
+    /// Like [`Self::mic_positions`] but with the channel index of each mic.
+    pub fn enabled_mics(&self) -> impl Iterator<Item = (usize, Position)> + '_ {
+        self.mics[..self.channels as usize]
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, enabled))| *enabled)
+            .map(|(idx, (pos, _))| match self.geometry_handedness {
+                Handedness::Right => (idx, *pos),
+                Handedness::Left => (idx, vector![pos.x, -pos.y, pos.z]),
+            })
+    }
+
+    /// Direction `dump_steering` reports the forward model for.
+    pub fn steering_direction(&self) -> ssloc::Direction {
+        ssloc::Direction::new(self.steering_azimuth, self.steering_elevation)
+    }
+
+    /// Converts a detected direction into the convention used for publishing.
+    ///
+    /// `invert_azimuth`, `invert_elevation` and `azimuth_offset` align it with
+    /// the mounting of the array first, see [`crate::convention`].
+    /// `flatten_elevation` projects onto the horizontal plane, which requires
+    /// normalizing like `rep103` first, as elevations beyond the poles point
+    /// backwards.
+    pub fn output_direction(&self, direction: ssloc::Direction) -> ssloc::Direction {
+        let sign = |invert: bool| if invert { -1. } else { 1. };
+        let direction = ssloc::Direction::new(
+            sign(self.invert_azimuth) * direction.azimuth + self.azimuth_offset,
+            sign(self.invert_elevation) * direction.elevation,
+        );
+        if self.flatten_elevation {
+            ssloc::Direction::new(convention::rep103(direction).azimuth, 0.)
+        } else if self.rep103 {
+            convention::rep103(direction)
+        } else {
+            direction
+        }
+    }
+
+    /// Length of the arrow marker of a source with `strength`, proportional
+    /// to `marker_strength_scale` within `marker_min_length` and
+    /// `marker_max_length`.
+    pub fn marker_length(&self, strength: F) -> F {
+        (strength / self.marker_strength_scale)
+            .min(self.marker_max_length)
+            .max(self.marker_min_length)
+    }
+
+    /// Unit vector of a published direction, see [`convention`].
+    pub fn unit_vec(&self, direction: ssloc::Direction) -> Position {
+        if self.optical_frame {
+            convention::optical() * direction.to_unit_vec()
+        } else {
+            direction.to_unit_vec()
+        }
+    }
+
+    /// Orientation of poses and markers pointing in a published direction,
+    /// see [`convention`].
+    pub fn orientation(&self, direction: ssloc::Direction) -> UnitQuaternion<F> {
+        if self.optical_frame {
+            convention::optical() * direction.to_quaternion()
+        } else {
+            direction.to_quaternion()
+        }
+    }
 }
 
 const AUDIO_GROUP: i32 = 100;
 const MIC_GROUP: i32 = 200;
 const MBSS_GROUP: i32 = 300;
+const OUTPUT_GROUP: i32 = 400;
+const DEBUG_GROUP: i32 = 500;
+const RECONFIGURE_GROUP: i32 = 600;
 
 impl rosrust_dynamic_reconfigure::Config for Config {
     fn clean_up(&mut self) {
@@ -125,9 +1157,72 @@ impl rosrust_dynamic_reconfigure::Config for Config {
             self.format = self.device.formats[0];
         }
         self.rate = self.rate.clamp(self.device.rate.0, self.device.rate.1);
-        self.channels = self
-            .channels
-            .clamp(self.device.channels.0, self.device.channels.1);
+        if let Some(index) = self.channel_map.iter().find(|&&index| index >= self.device.channels.1)
+        {
+            ros_err!(
+                "channel {index} of `recording/channel_map` exceeds the {} channels of `{}`, \
+                 ignoring the map",
+                self.device.channels.1,
+                self.device.name
+            );
+            self.channel_map.clear();
+        }
+        if let Some(mics) = &self.mic_geometry {
+            if !self.channel_map.is_empty() && self.channel_map.len() != mics.len() {
+                ros_err!(
+                    "`recording/channel_map` maps {} channels, but `{}` defines {} mics, ignoring \
+                     the map",
+                    self.channel_map.len(),
+                    self.geometry_file,
+                    mics.len()
+                );
+                self.channel_map.clear();
+            }
+        }
+        self.channels = if self.channel_map.is_empty() {
+            self.channels
+                .clamp(self.device.channels.0, self.device.channels.1)
+        } else {
+            self.channel_map.len() as u16
+        };
+        // applied on every update, so the file wins over `mic/*` regardless
+        // of the order they are set in
+        if let Some(mics) = &self.mic_geometry {
+            if usize::from(self.channels) != mics.len() {
+                ros_err!(
+                    "`recording/channels` {} does not match the {} mics of `{}`, using the file",
+                    self.channels,
+                    mics.len(),
+                    self.geometry_file
+                );
+                self.channels = mics.len() as u16;
+            }
+            for ((position, _), mic) in self.mics.iter_mut().zip(mics) {
+                *position = *mic;
+            }
+        }
+        if self.max_sources > self.max_sources_cap {
+            ros_warn!(
+                "max_sources {} exceeds max_sources_cap, clamped to {}",
+                self.max_sources,
+                self.max_sources_cap
+            );
+            self.max_sources = self.max_sources_cap;
+        }
+        if self.vad_channel >= i32::from(self.channels) {
+            ros_warn!(
+                "`vad/channel` {} is not one of the {} recorded channels, listening to all",
+                self.vad_channel,
+                self.channels
+            );
+            self.vad_channel = -1;
+        } else if self.vad_channel >= 0 && !self.mics[self.vad_channel as usize].1 {
+            ros_warn!(
+                "`vad/channel` {0} is disabled by `mic/{0}/enabled`, listening to all",
+                self.vad_channel
+            );
+            self.vad_channel = -1;
+        }
     }
 
     fn groups(&self) -> Vec<Group> {
@@ -160,6 +1255,27 @@ impl rosrust_dynamic_reconfigure::Config for Config {
                 parent: 0,
                 type_: GroupType::Tab,
             },
+            Group {
+                name: "Output".into(),
+                state: false,
+                id: OUTPUT_GROUP,
+                parent: 0,
+                type_: GroupType::Tab,
+            },
+            Group {
+                name: "Debug".into(),
+                state: false,
+                id: DEBUG_GROUP,
+                parent: 0,
+                type_: GroupType::Tab,
+            },
+            Group {
+                name: "Reconfigure".into(),
+                state: false,
+                id: RECONFIGURE_GROUP,
+                parent: 0,
+                type_: GroupType::Tab,
+            },
         ];
         groups.extend((0..self.channels).map(|c| Group {
             name: format!("Mic {c}"),
@@ -215,6 +1331,12 @@ impl rosrust_dynamic_reconfigure::Config for Config {
                 self.device.channels.1,
             )
             .group(AUDIO_GROUP),
+            Property::new("recording/channel_map", self.channel_map.iter().join(",").as_str())
+                .description("comma separated device channels of the mics, e.g. `2,3,6,7`")
+                .group(AUDIO_GROUP),
+            Property::new("recording/channel_gains", self.channel_gains.iter().join(",").as_str())
+                .description("comma separated linear gains of the mic channels, see `~calibrate`")
+                .group(AUDIO_GROUP),
             Property::new_enum("mbss/pooling", self.mbss.pooling.to_string(), [
                 "max", "sum",
             ])
@@ -226,8 +1348,30 @@ impl rosrust_dynamic_reconfigure::Config for Config {
                 1.,
                 10_000.,
             )
+            .description("minimal strength of a source")
+            .group(MBSS_GROUP),
+            Property::new_default_range("mbss/max_strength", self.max_strength, 0., 0., 100_000.)
+                .description("maximal strength of a source, 0 for no limit")
+                .group(MBSS_GROUP),
+            Property::new_enum("mbss/overload", self.overload.to_string(), Overload::names())
+                .description("handling of sources above `max_strength`")
+                .group(MBSS_GROUP),
+            Property::new_default_range(
+                "mbss/min_source_strength",
+                self.min_source_strength,
+                0.,
+                0.,
+                100_000.,
+            )
+            .description("minimal tracked strength of a published source, 0 to publish all")
+            .group(MBSS_GROUP),
+            Property::new_enum(
+                "mbss/spectrum_method",
+                self.spectrum_method.to_string(),
+                SpectrumMethod::names(),
+            )
+            .description("local angular spectrum of the mic pairs")
             .group(MBSS_GROUP),
-            // TODO spectrum_method
             Property::new_default_range(
                 "mbss/azimuth/min",
                 self.mbss.azimuth_range.0,
@@ -261,6 +1405,15 @@ impl rosrust_dynamic_reconfigure::Config for Config {
             Property::new_default_range("mbss/min_angle", self.mbss.min_angle, 0.1, 0.01, 0.5)
                 .description("minimal angle between two audio sources")
                 .group(MBSS_GROUP),
+            Property::new_default_range(
+                "mbss/nms_radius",
+                self.nms_radius,
+                10f64.to_radians(),
+                0.,
+                PI / 2.,
+            )
+            .description("radius in which weaker peaks of the same lobe are merged, 0 to disable")
+            .group(MBSS_GROUP),
             Property::new_default_range(
                 "mbss/tracking_persistence",
                 self.tracking_persistence,
@@ -270,9 +1423,530 @@ impl rosrust_dynamic_reconfigure::Config for Config {
             )
             .description("factor sources from previous frames are reduced by per frame")
             .group(MBSS_GROUP),
-            Property::new_default_range("mbss/max_sources", self.max_sources, 5, 1, 20)
+            Property::new_default_range("mbss/max_sources", self.max_sources, 5, 1, 100)
                 .description("maximal number of detected sources")
                 .group(MBSS_GROUP),
+            Property::new_enum(
+                "mbss/source_selection",
+                self.source_selection.to_string(),
+                SourceSelection::names(),
+            )
+            .description("report the `max_sources` strongest peaks or those above the prominence")
+            .group(MBSS_GROUP),
+            Property::new_default_range(
+                "mbss/prominence_ratio",
+                self.prominence_ratio,
+                0.4,
+                0.,
+                1.,
+            )
+            .description("strength relative to the strongest peak reported with `prominence`")
+            .group(MBSS_GROUP),
+            Property::new_default_range(
+                "reconfigure/debounce",
+                self.reconfigure_debounce,
+                0.5,
+                0.,
+                5.,
+            )
+            .description("seconds without changes before expensive updates are applied")
+            .group(RECONFIGURE_GROUP),
+            Property::new_default("output/rep103", self.rep103, false)
+                .description("normalize directions to REP 103 ranges")
+                .group(OUTPUT_GROUP),
+            Property::new_default_range(
+                "output/spectrum/elevations",
+                self.spectrum_grid_elevations,
+                0,
+                0,
+                1800,
+            )
+            .description("rows of the fixed grid `intensity/array` is resampled to, 0 disables")
+            .group(OUTPUT_GROUP),
+            Property::new_default_range(
+                "output/spectrum/azimuths",
+                self.spectrum_grid_azimuths,
+                0,
+                0,
+                3600,
+            )
+            .description("columns of the fixed grid `intensity/array` is resampled to, 0 disables")
+            .group(OUTPUT_GROUP),
+            Property::new_enum(
+                "output/heading/source",
+                self.heading_source.to_string(),
+                Follow::names(),
+            )
+            .description("source reported on `heading`")
+            .group(OUTPUT_GROUP),
+            Property::new_default("output/heading/degrees", self.heading_degrees, false)
+                .description("report `heading` in degrees instead of radians")
+                .group(OUTPUT_GROUP),
+            Property::new_default(
+                "output/marker/alpha_by_confidence",
+                self.marker_alpha_by_confidence,
+                false,
+            )
+            .description("scale marker opacity with the strength relative to the strongest source")
+            .group(OUTPUT_GROUP),
+            Property::new_default("output/publish_empty", self.publish_empty, true)
+                .description("publish empty source arrays as a heartbeat when nothing is detected")
+                .group(OUTPUT_GROUP),
+            Property::new_enum(
+                "geometry/handedness",
+                self.geometry_handedness.to_string(),
+                Handedness::names(),
+            )
+            .description("handedness of the frame the mic positions are given in, left mirrors y")
+            .group(MIC_GROUP),
+            Property::new_default_range(
+                "debug/steering/azimuth",
+                self.steering_azimuth,
+                0.,
+                -PI,
+                PI,
+            )
+            .description("azimuth `dump_steering` reports the forward model for")
+            .group(DEBUG_GROUP),
+            Property::new_default_range(
+                "debug/steering/elevation",
+                self.steering_elevation,
+                0.,
+                -PI / 2.,
+                PI / 2.,
+            )
+            .description("elevation `dump_steering` reports the forward model for")
+            .group(DEBUG_GROUP),
+            Property::new_default("vad/enabled", self.vad_enabled, false)
+                .description("only localize recordings containing speech")
+                .group(MBSS_GROUP),
+            Property::new_default_range("vad/aggressiveness", self.vad_aggressiveness, 1, 0, 3)
+                .description("higher values reject more non-speech at the risk of missing speech")
+                .group(MBSS_GROUP),
+            Property::new_default_range("vad/channel", self.vad_channel, -1, -1, 31)
+                .description("channel the voice activity detection listens to, -1 for all enabled")
+                .group(MBSS_GROUP),
+            Property::new_default_range(
+                "mbss/interpolation_factor",
+                self.interpolation_factor,
+                1,
+                1,
+                16,
+            )
+            .description("refines the TDOA grid beyond `alpha_res` for sub-sample precision")
+            .group(MBSS_GROUP),
+            Property::new_enum(
+                "output/dominant/source",
+                self.dominant_source.to_string(),
+                Follow::names(),
+            )
+            .description("source reported on `dominant_source`")
+            .group(OUTPUT_GROUP),
+            Property::new_default("geometry/aliasing_warning", self.aliasing_warning, true)
+                .description("warn if the analyzed band exceeds the spatial nyquist frequency")
+                .group(MIC_GROUP),
+            Property::new_default("debug/candidates", self.debug_candidates, false)
+                .description("publish and debug log all candidate peaks and why they were reported")
+                .group(DEBUG_GROUP),
+            Property::new_enum(
+                "output/audio/format",
+                self.audio_publish_format.to_string(),
+                PublishFormat::names(),
+            )
+            .description("sample format of published audio, independent of the recording format")
+            .group(OUTPUT_GROUP),
+            Property::new_default_range("mbss/max_sources_cap", self.max_sources_cap, 10, 1, 100)
+                .description("upper limit `max_sources` is clamped to")
+                .group(MBSS_GROUP),
+            Property::new_default("mbss/adaptive_threshold/enabled", self.adaptive_threshold, false)
+                .description("derive `ssl_threshold` from a running noise floor estimate")
+                .group(MBSS_GROUP),
+            Property::new_default_range(
+                "mbss/adaptive_threshold/multiplier",
+                self.adaptive_threshold_multiplier,
+                3.,
+                1.,
+                20.,
+            )
+            .description("multiple of the noise floor sources need to exceed")
+            .group(MBSS_GROUP),
+            Property::new_default_range(
+                "mbss/adaptive_threshold/rate",
+                self.adaptive_threshold_rate,
+                0.05,
+                0.,
+                1.,
+            )
+            .description("weight of the newest frame in the noise floor estimate")
+            .group(MBSS_GROUP),
+            Property::new_default("output/spectrum/flip_x", self.spectrum_flip_x, false)
+                .description("mirror the spectrum image horizontally (azimuth)")
+                .group(OUTPUT_GROUP),
+            Property::new_default("output/spectrum/flip_y", self.spectrum_flip_y, false)
+                .description("mirror the spectrum image vertically (elevation)")
+                .group(OUTPUT_GROUP),
+            Property::new_default("output/aggregate", self.aggregate_output, false)
+                .description("publish all detections of a cycle with timing on `detections`")
+                .group(OUTPUT_GROUP),
+            Property::new_default_range(
+                "mbss/max_angular_velocity",
+                self.max_angular_velocity,
+                0.,
+                0.,
+                1000.,
+            )
+            .description("degrees per second a tracked source can move, faster starts a new track")
+            .group(MBSS_GROUP),
+            Property::new_default("output/snr", self.snr, false)
+                .description("estimate the SNR of every detection from the angular spectrum")
+                .group(OUTPUT_GROUP),
+            Property::new_default("mbss/subband_fusion/enabled", self.subband_fusion, false)
+                .description("localize in overlapping frequency sub-bands and fuse their spectra")
+                .group(MBSS_GROUP),
+            Property::new_default_range("mbss/subband_fusion/bands", self.subband_count, 4, 2, 16)
+                .description("number of sub-bands, overlapping by half their width")
+                .group(MBSS_GROUP),
+            Property::new_enum(
+                "mbss/subband_fusion/operator",
+                self.subband_fusion_operator.to_string(),
+                Fusion::names(),
+            )
+            .description("how the spectra of the sub-bands are combined")
+            .group(MBSS_GROUP),
+            Property::new_default(
+                "recording/allow_format_fallback",
+                self.allow_format_fallback,
+                false,
+            )
+            .description("record in the nearest supported format if the device rejects `format`")
+            .group(AUDIO_GROUP),
+            Property::new_default(
+                "output/spectrum/marginal/azimuth",
+                self.spectrum_marginal_azimuth,
+                false,
+            )
+            .description("publish the spectrum reduced over elevation on `intensity/azimuth`")
+            .group(OUTPUT_GROUP),
+            Property::new_default(
+                "output/spectrum/marginal/elevation",
+                self.spectrum_marginal_elevation,
+                false,
+            )
+            .description("publish the spectrum reduced over azimuth on `intensity/elevation`")
+            .group(OUTPUT_GROUP),
+            Property::new_enum(
+                "output/spectrum/marginal/reduction",
+                self.spectrum_marginal_reduction.to_string(),
+                Reduction::names(),
+            )
+            .description("how the marginal spectra reduce the other axis")
+            .group(OUTPUT_GROUP),
+            Property::new_default("output/flatten_elevation", self.flatten_elevation, false)
+                .description("report all directions with zero elevation, the search is unchanged")
+                .group(OUTPUT_GROUP),
+            Property::new_enum(
+                "mbss/strength/measure",
+                self.strength_measure.to_string(),
+                StrengthMeasure::names(),
+            )
+            .description("strength of a source, its peak value or the energy of its lobe")
+            .group(MBSS_GROUP),
+            Property::new_default_range(
+                "mbss/strength/lobe_level",
+                self.strength_lobe_level,
+                0.5,
+                0.,
+                1.,
+            )
+            .description("part of the peak value bounding its lobe for `integrated_lobe`")
+            .group(MBSS_GROUP),
+            Property::new_default("output/optical_frame", self.optical_frame, false)
+                .description("publish poses, markers and points in a camera optical frame")
+                .group(OUTPUT_GROUP),
+            Property::new_default_range(
+                "reconfigure/min_interval",
+                self.reconfigure_min_interval,
+                0.,
+                0.,
+                60.,
+            )
+            .description("minimal seconds between rebuilds, later updates are coalesced")
+            .group(RECONFIGURE_GROUP),
+            Property::new_default_range(
+                "output/marker/strength_scale",
+                self.marker_strength_scale,
+                8000.,
+                1.,
+                100000.,
+            )
+            .description("strength of a source drawn as a 1 m long arrow marker")
+            .group(OUTPUT_GROUP),
+            Property::new_default_range(
+                "output/marker/min_length",
+                self.marker_min_length,
+                0.2,
+                0.,
+                10.,
+            )
+            .description("length in m of arrow markers of weak sources")
+            .group(OUTPUT_GROUP),
+            Property::new_default_range(
+                "output/marker/max_length",
+                self.marker_max_length,
+                2.,
+                0.,
+                10.,
+            )
+            .description("length in m of arrow markers of strong sources")
+            .group(OUTPUT_GROUP),
+            Property::new_enum(
+                "recording/source",
+                self.input_source.to_string(),
+                InputSource::names(),
+            )
+                .description("record from the device, replay `file/path` or a `mock` source")
+                .group(AUDIO_GROUP),
+            Property::new("recording/file/path", self.input_file.as_str())
+                .description("WAV file replayed with `source` \"file\"")
+                .group(AUDIO_GROUP),
+            Property::new_default("recording/file/loop", self.input_file_loop, false)
+                .description("restart the replay at the end of the file")
+                .group(AUDIO_GROUP),
+            Property::new_default_range(
+                "recording/mock/azimuth",
+                self.input_mock_azimuth,
+                0.,
+                -PI,
+                PI,
+            )
+            .description("azimuth in rad of the source synthesized with `source` \"mock\"")
+            .group(AUDIO_GROUP),
+            Property::new_default_range(
+                "recording/mock/elevation",
+                self.input_mock_elevation,
+                0.,
+                -FRAC_PI_2,
+                FRAC_PI_2,
+            )
+            .description("elevation in rad of the source synthesized with `source` \"mock\"")
+            .group(AUDIO_GROUP),
+            Property::new("recording/record_to_file", self.record_to_file.as_str())
+                .description("WAV file the recording is written to, empty to disable")
+                .group(AUDIO_GROUP),
+            Property::new("frame_id", self.frame_id.as_str())
+                .description("frame_id of all published headers")
+                .group(OUTPUT_GROUP),
+            Property::new_default_range("mbss/tracking/gate", self.tracking_gate, 0.1, 0.01, PI)
+                .description("maximal angle in radians a detection is associated to a track over")
+                .group(MBSS_GROUP),
+            Property::new_default_range(
+                "mbss/tracking/max_missed",
+                self.tracking_max_missed,
+                5,
+                0,
+                100,
+            )
+            .description("frames without detection before a track is dropped")
+            .group(MBSS_GROUP),
+            Property::new_default_range(
+                "diagnostics/max_drop_rate",
+                self.max_drop_rate,
+                0.05,
+                0.,
+                1.,
+            )
+            .description("fraction of dropped frames above which diagnostics warn")
+            .group(DEBUG_GROUP),
+            Property::new_default_range("vad/silence_threshold", self.silence_threshold, 0., 0., 1.)
+                .description("RMS below which recordings are not localized, 0 to disable")
+                .group(MBSS_GROUP),
+            Property::new_default_range("vad/silence_hold", self.silence_hold, 3, 0, 100)
+                .description("silent recordings still localized before the gate closes")
+                .group(MBSS_GROUP),
+            Property::new_enum(
+                "output/spectrum/image/colormap",
+                self.spectrum_colormap.to_string(),
+                Colormap::names(),
+            )
+            .description("palette of `intensity/compressed`")
+            .group(OUTPUT_GROUP),
+            Property::new_enum(
+                "output/spectrum/image/normalization",
+                self.spectrum_normalization.to_string(),
+                ImageNormalization::names(),
+            )
+            .description("reference the brightness of `intensity/compressed` is relative to")
+            .group(OUTPUT_GROUP),
+            Property::new_default_range(
+                "output/spectrum/image/reference",
+                self.spectrum_reference,
+                10000.,
+                1.,
+                100000.,
+            )
+            .description("strength of full brightness with \"fixed\" and \"decibel\" normalization")
+            .group(OUTPUT_GROUP),
+            Property::new_default_range(
+                "output/spectrum/image/range_db",
+                self.spectrum_range_db,
+                40.,
+                1.,
+                120.,
+            )
+            .description("decibels below `reference` shown with \"decibel\" normalization")
+            .group(OUTPUT_GROUP),
+            Property::new_default("output/spectrum/image/raw", self.spectrum_raw, true)
+                .description("publish the uncompressed image on `intensity`")
+                .group(OUTPUT_GROUP),
+            Property::new_default_range("mbss/num_threads", self.num_threads, 1, 0, 64)
+                .description("threads computing the angular spectrum, 0 for one per CPU")
+                .group(MBSS_GROUP),
+            Property::new("geometry/file", self.geometry_file.as_str())
+                .description("CSV or YAML file of `x, y, z` per mic, overrides the mic positions and channels")
+                .group(MIC_GROUP),
+            Property::new_default_range("mbss/window_overlap", self.window_overlap, 0., 0., 0.75)
+                .description("fraction of the previous recording prepended to each analysis window")
+                .group(MBSS_GROUP),
+            Property::new_default_range(
+                "mbss/mask/azimuth/min",
+                self.mask_azimuth_min,
+                -PI,
+                -PI,
+                PI,
+            )
+            .description("minimal azimuth sources are reported at, above the maximum wraps around")
+            .group(MBSS_GROUP),
+            Property::new_default_range(
+                "mbss/mask/azimuth/max",
+                self.mask_azimuth_max,
+                PI,
+                -PI,
+                PI,
+            )
+            .description("maximal azimuth sources are reported at, in the frame of the grid")
+            .group(MBSS_GROUP),
+            Property::new_default_range(
+                "mbss/mask/elevation/min",
+                self.mask_elevation_min,
+                -PI,
+                -PI,
+                PI,
+            )
+            .description("minimal elevation sources are reported at, in the frame of the grid")
+            .group(MBSS_GROUP),
+            Property::new_default_range(
+                "mbss/mask/elevation/max",
+                self.mask_elevation_max,
+                PI,
+                -PI,
+                PI,
+            )
+            .description("maximal elevation sources are reported at, in the frame of the grid")
+            .group(MBSS_GROUP),
+            Property::new_default("output/tf/enabled", self.tf_enabled, false)
+                .description("broadcast a TF frame per tracked source")
+                .group(OUTPUT_GROUP),
+            Property::new_default_range("output/tf/distance", self.tf_distance, 1., 0., 100.)
+                .description("distance in meters of the source frames along their direction")
+                .group(OUTPUT_GROUP),
+            Property::new("output/tf/prefix", self.tf_prefix.as_str())
+                .description("prefix of the source frames, followed by the track id")
+                .group(OUTPUT_GROUP),
+            Property::new_default_range("output/marker/lifetime", self.marker_lifetime, 1., 0., 60.)
+                .description("seconds until rviz removes an arrow marker, 0 for forever")
+                .group(OUTPUT_GROUP),
+            Property::new_enum(
+                "output/marker/colors",
+                self.marker_colors.to_string(),
+                MarkerColors::names(),
+            )
+            .description("colors of the arrow markers, per track id or by strength")
+            .group(OUTPUT_GROUP),
+            Property::new_default("output/beamformed/enabled", self.beamformed, false)
+                .description("publish the audio of the strongest source on `beamformed_audio`")
+                .group(OUTPUT_GROUP),
+            Property::new_default_range("mbss/process_every_n", self.process_every_n, 1, 1, 100)
+                .description("analyze only every n-th recording, all are still published")
+                .group(MBSS_GROUP),
+            Property::new_default_range("mbss/freq_min", self.freq_min, 0., 0., 32_768.)
+                .description("lowest frequency in Hz the angular spectrum accumulates")
+                .group(MBSS_GROUP),
+            Property::new_default_range("mbss/freq_max", self.freq_max, 24_000., 0., 32_768.)
+                .description("highest frequency in Hz accumulated, clamped to nyquist")
+                .group(MBSS_GROUP),
+            Property::new_default("output/invert_azimuth", self.invert_azimuth, false)
+                .description("publish azimuths clockwise instead of counter clockwise")
+                .group(OUTPUT_GROUP),
+            Property::new_default("output/invert_elevation", self.invert_elevation, false)
+                .description("publish elevations downwards instead of upwards")
+                .group(OUTPUT_GROUP),
+            Property::new_default_range("output/azimuth_offset", self.azimuth_offset, 0., -PI, PI)
+                .description("radians added to published azimuths, after `invert_azimuth`")
+                .group(OUTPUT_GROUP),
+            Property::new_enum("mbss/mode", self.mode.to_string(), Mode::names())
+                .description("search the sphere, or only the azimuth circle for linear arrays")
+                .group(MBSS_GROUP),
+            Property::new_enum(
+                "output/spectrum/image/format",
+                self.spectrum_format.to_string(),
+                ImageFormat::names(),
+            )
+            .description("encoding of `intensity/compressed`")
+            .group(OUTPUT_GROUP),
+            Property::new_default_range(
+                "output/spectrum/image/jpeg_quality",
+                self.spectrum_jpeg_quality,
+                80,
+                1,
+                100,
+            )
+            .description("quality of `intensity/compressed` encoded as \"jpeg\"")
+            .group(OUTPUT_GROUP),
+            Property::new_default_range(
+                "output/spectrogram/frames",
+                self.spectrogram_frames,
+                100,
+                1,
+                1000,
+            )
+            .description("frames of azimuth history shown by `spectrogram`")
+            .group(OUTPUT_GROUP),
+            Property::new_default_range("recording/warmup_frames", self.warmup_frames, 2, 0, 100)
+                .description("recordings discarded after opening the device")
+                .group(AUDIO_GROUP),
+            Property::new_enum("recording/stamp_at", self.stamp_at.to_string(), StampAt::names())
+                .description("instant of a recording its stamp refers to")
+                .group(AUDIO_GROUP),
+            Property::new_default_range("mbss/stft/size", self.fft_size, 0, 0, 16_384)
+                .description("STFT window in samples, rounded up to a power of two, 0 for 64 ms")
+                .group(MBSS_GROUP),
+            Property::new_enum("mbss/stft/window", self.window.to_string(), Window::names())
+                .description("window function of the STFT")
+                .group(MBSS_GROUP),
+            Property::new_default(
+                "recording/audio_message_stamped",
+                self.audio_message_stamped,
+                cfg!(feature = "audio_common_msgs-stamped"),
+            )
+            .description("subscribe `audio_stamped` instead of `audio`, stamped on arrival")
+            .group(AUDIO_GROUP),
+            Property::new_default("output/ssl_map/enabled", self.ssl_map, false)
+                .description("render the spectrum with the found sources marked on `ssl_map`")
+                .group(OUTPUT_GROUP),
+            Property::new_default_range("recording/audio_timeout", self.audio_timeout, 5., 0., 60.)
+                .description(
+                    "seconds a recording of the device may block before the recorder is replaced",
+                )
+                .group(AUDIO_GROUP),
+            Property::new_default_range(
+                "mbss/tracking/smoothing",
+                self.tracking_smoothing,
+                0.,
+                0.,
+                0.99,
+            )
+            .description("weight of the previous direction of a track, 0 to disable smoothing")
+            .group(MBSS_GROUP),
         ];
         props.extend(
             self.mics
@@ -317,6 +1991,47 @@ impl rosrust_dynamic_reconfigure::Config for Config {
             "recording/format" => self.format = value.as_string(name)?.parse()?,
             "recording/frame_length" => self.localisation_frame = value.as_float(name)?,
             "recording/channels" => self.channels = value.as_int(name)? as u16,
+            "recording/channel_map" => {
+                let map: Vec<u16> = value
+                    .as_string(name)?
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|index| !index.is_empty())
+                    .map(str::parse)
+                    .try_collect()
+                    .map_err(|e| format!("invalid channel in `{name}`: {e}"))?;
+                let max = self.device.channels.1;
+                if let Some(index) = map.iter().find(|&&index| index >= max) {
+                    return Err(format!(
+                        "channel {index} of `{name}` exceeds the {max} channels of `{}`",
+                        self.device.name
+                    )
+                    .into());
+                }
+                if map.len() > self.mics.len() {
+                    return Err(format!(
+                        "`{name}` maps {} channels, but only {} mics are supported",
+                        map.len(),
+                        self.mics.len()
+                    )
+                    .into());
+                }
+                self.channel_map = map;
+            }
+            "recording/channel_gains" => {
+                let gains: Vec<F> = value
+                    .as_string(name)?
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|gain| !gain.is_empty())
+                    .map(str::parse)
+                    .try_collect()
+                    .map_err(|e| format!("invalid gain in `{name}`: {e}"))?;
+                if let Some(gain) = gains.iter().find(|gain| !(gain.is_finite() && **gain > 0.)) {
+                    return Err(format!("gain {gain} of `{name}` is not positive").into());
+                }
+                self.channel_gains = gains;
+            }
             mic if mic.starts_with("mic/") => {
                 let (idx, coord) = mic
                     .strip_prefix("mic/")
@@ -342,6 +2057,7 @@ impl rosrust_dynamic_reconfigure::Config for Config {
                 }
             }
             "mbss/pooling" => self.mbss.pooling = value.as_string(name)?.parse()?,
+            "mbss/spectrum_method" => self.spectrum_method = value.as_string(name)?.parse()?,
             "mbss/azimuth/min" => self.mbss.azimuth_range.0 = value.as_float(name)?,
             "mbss/azimuth/max" => self.mbss.azimuth_range.1 = value.as_float(name)?,
             "mbss/elevation/min" => self.mbss.elevation_range.0 = value.as_float(name)?,
@@ -349,11 +2065,313 @@ impl rosrust_dynamic_reconfigure::Config for Config {
             "mbss/grid_res" => self.mbss.grid_res = value.as_float(name)?,
             "mbss/alpha_res" => self.mbss.alpha_res = value.as_float(name)?,
             "mbss/min_angle" => self.mbss.min_angle = value.as_float(name)?,
+            "mbss/nms_radius" => self.nms_radius = value.as_float(name)?,
             "mbss/max_sources" => self.max_sources = value.as_int(name)? as u16,
+            "mbss/source_selection" => self.source_selection = value.as_string(name)?.parse()?,
+            "mbss/prominence_ratio" => self.prominence_ratio = value.as_float(name)?,
             "mbss/ssl_threshold" => self.mbss_ssl_threshold = value.as_float(name)?,
+            "mbss/max_strength" => self.max_strength = value.as_float(name)?,
+            "mbss/min_source_strength" => self.min_source_strength = value.as_float(name)?,
+            "mbss/overload" => self.overload = value.as_string(name)?.parse()?,
             "mbss/tracking_persistence" => self.tracking_persistence = value.as_float(name)?,
+            "output/rep103" => self.rep103 = value.as_bool(name)?,
+            "reconfigure/debounce" => self.reconfigure_debounce = value.as_float(name)?,
+            "output/spectrum/elevations" => {
+                self.spectrum_grid_elevations = value.as_int(name)? as u16;
+            }
+            "output/spectrum/azimuths" => self.spectrum_grid_azimuths = value.as_int(name)? as u16,
+            "output/heading/source" => self.heading_source = value.as_string(name)?.parse()?,
+            "output/heading/degrees" => self.heading_degrees = value.as_bool(name)?,
+            "output/marker/alpha_by_confidence" => {
+                self.marker_alpha_by_confidence = value.as_bool(name)?;
+            }
+            "output/publish_empty" => self.publish_empty = value.as_bool(name)?,
+            "geometry/handedness" => {
+                self.geometry_handedness = value.as_string(name)?.parse()?;
+            }
+            "debug/steering/azimuth" => self.steering_azimuth = value.as_float(name)?,
+            "debug/steering/elevation" => self.steering_elevation = value.as_float(name)?,
+            "vad/enabled" => self.vad_enabled = value.as_bool(name)?,
+            "vad/aggressiveness" => self.vad_aggressiveness = value.as_int(name)? as u16,
+            "vad/channel" => self.vad_channel = value.as_int(name)?,
+            "mbss/interpolation_factor" => {
+                self.interpolation_factor = value.as_int(name)? as u16;
+            }
+            "output/dominant/source" => self.dominant_source = value.as_string(name)?.parse()?,
+            "geometry/aliasing_warning" => self.aliasing_warning = value.as_bool(name)?,
+            "debug/candidates" => self.debug_candidates = value.as_bool(name)?,
+            "output/audio/format" => self.audio_publish_format = value.as_string(name)?.parse()?,
+            "mbss/max_sources_cap" => self.max_sources_cap = value.as_int(name)? as u16,
+            "mbss/adaptive_threshold/enabled" => self.adaptive_threshold = value.as_bool(name)?,
+            "mbss/adaptive_threshold/multiplier" => {
+                self.adaptive_threshold_multiplier = value.as_float(name)?;
+            }
+            "mbss/adaptive_threshold/rate" => self.adaptive_threshold_rate = value.as_float(name)?,
+            "output/spectrum/flip_x" => self.spectrum_flip_x = value.as_bool(name)?,
+            "output/spectrum/flip_y" => self.spectrum_flip_y = value.as_bool(name)?,
+            "output/aggregate" => self.aggregate_output = value.as_bool(name)?,
+            "mbss/max_angular_velocity" => self.max_angular_velocity = value.as_float(name)?,
+            "output/snr" => self.snr = value.as_bool(name)?,
+            "mbss/subband_fusion/enabled" => self.subband_fusion = value.as_bool(name)?,
+            "mbss/subband_fusion/bands" => self.subband_count = value.as_int(name)? as u16,
+            "mbss/subband_fusion/operator" => {
+                self.subband_fusion_operator = value.as_string(name)?.parse()?;
+            }
+            "recording/allow_format_fallback" => self.allow_format_fallback = value.as_bool(name)?,
+            "output/spectrum/marginal/azimuth" => {
+                self.spectrum_marginal_azimuth = value.as_bool(name)?;
+            }
+            "output/spectrum/marginal/elevation" => {
+                self.spectrum_marginal_elevation = value.as_bool(name)?;
+            }
+            "output/spectrum/marginal/reduction" => {
+                self.spectrum_marginal_reduction = value.as_string(name)?.parse()?;
+            }
+            "output/flatten_elevation" => self.flatten_elevation = value.as_bool(name)?,
+            "mbss/strength/measure" => self.strength_measure = value.as_string(name)?.parse()?,
+            "mbss/strength/lobe_level" => self.strength_lobe_level = value.as_float(name)?,
+            "output/optical_frame" => self.optical_frame = value.as_bool(name)?,
+            "reconfigure/min_interval" => self.reconfigure_min_interval = value.as_float(name)?,
+            "output/marker/strength_scale" => self.marker_strength_scale = value.as_float(name)?,
+            "output/marker/min_length" => self.marker_min_length = value.as_float(name)?,
+            "output/marker/max_length" => self.marker_max_length = value.as_float(name)?,
+            "recording/source" => self.input_source = value.as_string(name)?.parse()?,
+            "recording/file/path" => self.input_file = value.as_string(name)?.trim().to_owned(),
+            "recording/file/loop" => self.input_file_loop = value.as_bool(name)?,
+            "recording/mock/azimuth" => self.input_mock_azimuth = value.as_float(name)?,
+            "recording/mock/elevation" => self.input_mock_elevation = value.as_float(name)?,
+            "recording/record_to_file" => {
+                self.record_to_file = value.as_string(name)?.trim().to_owned()
+            }
+            "frame_id" => self.frame_id = value.as_string(name)?.trim().to_owned(),
+            "mbss/tracking/gate" => self.tracking_gate = value.as_float(name)?,
+            "mbss/tracking/max_missed" => self.tracking_max_missed = value.as_int(name)? as u16,
+            "diagnostics/max_drop_rate" => self.max_drop_rate = value.as_float(name)?,
+            "vad/silence_threshold" => self.silence_threshold = value.as_float(name)?,
+            "vad/silence_hold" => self.silence_hold = value.as_int(name)? as u16,
+            "output/spectrum/image/colormap" => {
+                self.spectrum_colormap = value.as_string(name)?.parse()?
+            }
+            "output/spectrum/image/normalization" => {
+                self.spectrum_normalization = value.as_string(name)?.parse()?
+            }
+            "output/spectrum/image/reference" => self.spectrum_reference = value.as_float(name)?,
+            "output/spectrum/image/range_db" => self.spectrum_range_db = value.as_float(name)?,
+            "output/spectrum/image/raw" => self.spectrum_raw = value.as_bool(name)?,
+            "mbss/num_threads" => self.num_threads = value.as_int(name)? as u16,
+            "geometry/file" => {
+                let path = value.as_string(name)?.trim().to_owned();
+                self.mic_geometry = if path.is_empty() {
+                    None
+                } else {
+                    let mics =
+                        geometry::load(&path).map_err(|e| format!("error loading `{path}`: {e}"))?;
+                    let (min, max) = self.device.channels;
+                    // the mapped channels are checked against the device
+                    let min = if self.channel_map.is_empty() { min } else { 1 };
+                    if mics.len() > self.mics.len() || !(min..=max).contains(&(mics.len() as u16)) {
+                        return Err(format!(
+                            "`{path}` defines {} mics, but `{}` records {min} to {max} channels",
+                            mics.len(),
+                            self.device.name
+                        )
+                        .into());
+                    }
+                    ros_info!("loaded {} mic positions from `{path}`", mics.len());
+                    self.channels = mics.len() as u16;
+                    Some(mics)
+                };
+                self.geometry_file = path;
+            }
+            "mbss/window_overlap" => self.window_overlap = value.as_float(name)?,
+            "mbss/mask/azimuth/min" => self.mask_azimuth_min = value.as_float(name)?,
+            "mbss/mask/azimuth/max" => self.mask_azimuth_max = value.as_float(name)?,
+            "mbss/mask/elevation/min" => self.mask_elevation_min = value.as_float(name)?,
+            "mbss/mask/elevation/max" => self.mask_elevation_max = value.as_float(name)?,
+            "output/tf/enabled" => self.tf_enabled = value.as_bool(name)?,
+            "output/tf/distance" => self.tf_distance = value.as_float(name)?,
+            "output/tf/prefix" => self.tf_prefix = value.as_string(name)?.trim().to_owned(),
+            "output/marker/lifetime" => self.marker_lifetime = value.as_float(name)?,
+            "output/marker/colors" => self.marker_colors = value.as_string(name)?.parse()?,
+            "output/beamformed/enabled" => self.beamformed = value.as_bool(name)?,
+            "mbss/process_every_n" => self.process_every_n = value.as_int(name)?.max(1) as u16,
+            "mbss/freq_min" => self.freq_min = value.as_float(name)?,
+            "mbss/freq_max" => self.freq_max = value.as_float(name)?,
+            "output/invert_azimuth" => self.invert_azimuth = value.as_bool(name)?,
+            "output/invert_elevation" => self.invert_elevation = value.as_bool(name)?,
+            "output/azimuth_offset" => self.azimuth_offset = value.as_float(name)?,
+            "mbss/mode" => self.mode = value.as_string(name)?.parse()?,
+            "output/spectrum/image/format" => {
+                self.spectrum_format = value.as_string(name)?.parse()?
+            }
+            "output/spectrum/image/jpeg_quality" => {
+                self.spectrum_jpeg_quality = value.as_int(name)?.clamp(1, 100) as u16
+            }
+            "output/spectrogram/frames" => {
+                self.spectrogram_frames = value.as_int(name)?.max(1) as u16
+            }
+            "recording/warmup_frames" => self.warmup_frames = value.as_int(name)? as u16,
+            "recording/stamp_at" => self.stamp_at = value.as_string(name)?.parse()?,
+            "mbss/stft/size" => self.fft_size = value.as_int(name)? as u16,
+            "mbss/stft/window" => self.window = value.as_string(name)?.parse()?,
+            "recording/audio_message_stamped" => self.audio_message_stamped = value.as_bool(name)?,
+            "output/ssl_map/enabled" => self.ssl_map = value.as_bool(name)?,
+            "recording/audio_timeout" => self.audio_timeout = value.as_float(name)?,
+            "mbss/tracking/smoothing" => self.tracking_smoothing = value.as_float(name)?,
             other => return Err(format!("unexpected field: {other}").into()),
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+impl Config {
+    /// Defaults recording from a fake device, without enumerating ALSA.
+    pub fn test() -> Config {
+        Self::with_devices(vec![Device {
+            name: "test".to_owned(),
+            description: "Test Device".to_owned(),
+            channels: (1, 8),
+            rate: (8_000, 48_000),
+            formats: vec![Format::S16, Format::F32],
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{vector, Vector3};
+    use ssloc::{Audio, Direction, Format, Normalize, F};
+
+    use super::{Config, Device};
+
+    fn assert_near(a: Vector3<F>, b: Vector3<F>) {
+        assert!((a - b).norm() < 1e-9, "{a:?} != {b:?}");
+    }
+
+    /// Unit vector the published pose of a source at `azimuth` and
+    /// `elevation` (degrees) points along, checking it matches `unit_vec`.
+    fn published(config: &Config, azimuth: F, elevation: F) -> Vector3<F> {
+        let direction = config
+            .output_direction(Direction::new(azimuth.to_radians(), elevation.to_radians()));
+        let pointing = config.orientation(direction) * Vector3::x();
+        assert_near(pointing, config.unit_vec(direction));
+        pointing
+    }
+
+    #[test]
+    fn apply_processing() {
+        let mut config = Config::test();
+        let mut update = Config::test();
+        update.tracking_gate = 0.3;
+        update.tracking_max_missed = 2;
+        update.frame_id = "update".to_owned();
+        update.fft_size = 4096;
+        update.mbss.grid_res = 0.2;
+        config.apply_processing(&update);
+        assert_eq!((config.tracking_gate, config.tracking_max_missed), (0.3, 2));
+        assert_eq!(config.frame_id, "update");
+        // wait for the rebuild
+        assert_eq!(config.analysis(), Config::test().analysis());
+        assert_ne!(config.analysis(), update.analysis());
+    }
+
+    #[test]
+    fn validate_leaves_vad_channel_to_clean_up() {
+        // `clean_up` falls back to all channels, with a warning that needs a
+        // running node
+        let mut config = Config::test();
+        config.channels = 4;
+        config.vad_channel = 6;
+        assert_eq!(config.validate(false), Ok(()));
+    }
+
+    #[test]
+    fn output_direction() {
+        let source = Direction::new(30f64.to_radians(), 20f64.to_radians()).to_unit_vec();
+        let mut config = Config::test();
+        assert_near(published(&config, 30., 20.), source);
+
+        config.invert_azimuth = true;
+        assert_near(published(&config, 30., 20.), vector![source.x, -source.y, source.z]);
+
+        config.invert_azimuth = false;
+        config.invert_elevation = true;
+        assert_near(published(&config, 30., 20.), vector![source.x, source.y, -source.z]);
+
+        config.invert_elevation = false;
+        config.azimuth_offset = 90f64.to_radians();
+        assert_near(published(&config, 30., 20.), vector![-source.y, source.x, source.z]);
+
+        // inverted, then offset, then normalized
+        config.invert_azimuth = true;
+        config.azimuth_offset = 270f64.to_radians();
+        config.rep103 = true;
+        let direction = config
+            .output_direction(Direction::new(30f64.to_radians(), 20f64.to_radians()));
+        assert!((direction.azimuth - (-120f64).to_radians()).abs() < 1e-9, "{direction:?}");
+        assert_near(published(&config, 30., 20.), vector![-source.y, -source.x, source.z]);
+
+        // beyond the pole
+        config.invert_azimuth = false;
+        config.azimuth_offset = 0.;
+        config.invert_elevation = true;
+        let direction = config
+            .output_direction(Direction::new(30f64.to_radians(), 100f64.to_radians()));
+        assert!((direction.elevation - (-80f64).to_radians()).abs() < 1e-9, "{direction:?}");
+        let beyond = Direction::new(30f64.to_radians(), 100f64.to_radians()).to_unit_vec();
+        assert_near(published(&config, 30., 100.), vector![beyond.x, beyond.y, -beyond.z]);
+    }
+
+    #[test]
+    fn nearest_format() {
+        let nearest = |formats: &[Format], format| {
+            let device = Device {
+                formats: formats.to_vec(),
+                ..Config::test().device
+            };
+            device.nearest_format(format)
+        };
+        // the same kind of samples before the bit depth
+        assert_eq!(nearest(&[Format::S16, Format::F32], Format::F64), Some(Format::F32));
+        assert_eq!(nearest(&[Format::S16, Format::F32], Format::S32), Some(Format::S16));
+        // then the closest bit depth
+        assert_eq!(nearest(&[Format::S8, Format::S32], Format::S16), Some(Format::S8));
+        assert_eq!(nearest(&[Format::S8, Format::S32], Format::U32), Some(Format::S32));
+        // before the signedness
+        assert_eq!(nearest(&[Format::S8, Format::U16], Format::S16), Some(Format::U16));
+        assert_eq!(nearest(&[Format::U16, Format::S16], Format::U8), Some(Format::U16));
+        assert_eq!(nearest(&[Format::U8, Format::S8], Format::S16), Some(Format::S8));
+        // also across kinds, the depths of 8, 16, 32 and 64 bits never tie so
+        // the preference of higher ones does not apply to these formats
+        assert_eq!(nearest(&[Format::S16, Format::S32], Format::F32), Some(Format::S32));
+        assert_eq!(nearest(&[Format::S8, Format::S32], Format::F64), Some(Format::S32));
+        // never the format itself
+        assert_eq!(nearest(&[Format::S16], Format::S16), None);
+        assert_eq!(nearest(&[], Format::S16), None);
+    }
+
+    #[test]
+    fn format_fallback_conversion() {
+        let device = Device {
+            formats: vec![Format::U8, Format::S16],
+            ..Config::test().device
+        };
+        // decoded like `AudioRecorder::record` in the fallback format, full
+        // scale is one below the maximum of the signed type
+        let decode = |samples: Vec<F>| Audio::from_interleaved(16_000., 2, samples);
+        let expected = [0., 0.5, -0.5, 1., -1., 0.];
+
+        assert_eq!(device.nearest_format(Format::S32), Some(Format::S16));
+        let recorded: [i16; 6] = [0, 16_383, -16_383, 32_766, -32_766, 0];
+        let audio = decode(recorded.map(Normalize::normalize).to_vec());
+        assert_eq!((audio.channels(), audio.samples(), audio.sample_rate()), (2, 3, 16_000.));
+        assert!(audio.to_interleaved::<F>().eq(expected), "{audio:?}");
+
+        assert_eq!(device.nearest_format(Format::S8), Some(Format::U8));
+        let recorded: [u8; 6] = [127, 190, 64, 253, 1, 127];
+        let audio = decode(recorded.map(Normalize::normalize).to_vec());
+        assert_eq!((audio.channels(), audio.samples(), audio.sample_rate()), (2, 3, 16_000.));
+        assert!(audio.to_interleaved::<F>().eq(expected), "{audio:?}");
+    }
+}
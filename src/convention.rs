@@ -0,0 +1,133 @@
+//! Coordinate conventions of the published directions.
+//!
+//! `ssloc` reports directions relative to the frame the microphone positions
+//! are specified in, as azimuth (counter clockwise around z, starting at x)
+//! and elevation (upwards from the xy-plane), both in radians. All poses and
+//! markers use `UnitQuaternion::from_euler_angles(0., -elevation, azimuth)`,
+//! i.e. an arrow pointing along x, pitched up by the elevation and yawed by
+//! the azimuth. The point clouds contain the matching unit vectors
+//! `(cos(el) cos(az), cos(el) sin(az), sin(el))`.
+//!
+//! With mic positions given in a REP 103 frame (x forward, y left, z up) this
+//! already is REP 103 compliant. The only deviation is that directions are
+//! not normalized, with an elevation range larger than `[-π/2, π/2]` (e.g.
+//! the `[-π, π]` in `param/defaults.yaml`) the same direction can be reported
+//! with different angles, which [`rep103`] resolves.
+//!
+//! Arrays mounted differently than their mic positions suggest, e.g. upside
+//! down or rotated, can be aligned with `output/invert_azimuth` (clockwise
+//! azimuths), `output/invert_elevation` (downward elevations) and
+//! `output/azimuth_offset` (added after the inversion), applied before any
+//! normalization by [`crate::config::Config::output_direction`]. Mirrored
+//! arrows usually mean the mic positions are given in a left-handed frame,
+//! which `geometry/handedness` corrects at the source instead.
+//!
+//! Camera optical frames (REP 103 suffix `_optical`) instead use z forward,
+//! x right and y down, [`optical`] maps vectors and orientations into them:
+//! `x_optical = -y`, `y_optical = -z` and `z_optical = x`. Azimuth and
+//! elevation stay relative to the body axes.
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+use nalgebra::{Matrix3, Rotation3, UnitQuaternion};
+use ssloc::{Direction, F};
+
+/// Wraps an angle into `(-π, π]`.
+fn wrap(angle: F) -> F {
+    let angle = (angle + PI).rem_euclid(TAU) - PI;
+    if angle <= -PI { PI } else { angle }
+}
+
+/// Normalizes a direction to REP 103, i.e. azimuth in `(-π, π]` and elevation
+/// in `[-π/2, π/2]`, without changing where it points.
+pub fn rep103(Direction { azimuth, elevation }: Direction) -> Direction {
+    let elevation = wrap(elevation);
+    // beyond the poles the direction points backwards
+    let (azimuth, elevation) = if elevation > FRAC_PI_2 {
+        (azimuth + PI, PI - elevation)
+    } else if elevation < -FRAC_PI_2 {
+        (azimuth + PI, -PI - elevation)
+    } else {
+        (azimuth, elevation)
+    };
+    Direction::new(wrap(azimuth), elevation)
+}
+
+/// Rotation from a body frame (x forward, y left, z up) into the matching
+/// camera optical frame (z forward, x right, y down).
+pub fn optical() -> UnitQuaternion<F> {
+    #[rustfmt::skip]
+    let matrix = Matrix3::new(
+        0., -1., 0.,
+        0., 0., -1.,
+        1., 0., 0.,
+    );
+    UnitQuaternion::from_rotation_matrix(&Rotation3::from_matrix_unchecked(matrix))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_3, PI};
+
+    use nalgebra::Vector3;
+    use ssloc::{Direction, F};
+
+    use super::{optical, rep103};
+    use crate::config::Config;
+
+    fn assert_near(a: Vector3<F>, b: Vector3<F>) {
+        assert!((a - b).norm() < 1e-9, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn azimuth_counter_clockwise_elevation_up() {
+        assert_near(Direction::new(0., 0.).to_unit_vec(), Vector3::x());
+        assert_near(Direction::new(FRAC_PI_2, 0.).to_unit_vec(), Vector3::y());
+        assert_near(Direction::new(-FRAC_PI_2, 0.).to_unit_vec(), -Vector3::y());
+        assert_near(Direction::new(0., FRAC_PI_2).to_unit_vec(), Vector3::z());
+        assert_near(Direction::new(PI, 0.).to_unit_vec(), -Vector3::x());
+    }
+
+    #[test]
+    fn quaternion_points_along_unit_vector() {
+        for (azimuth, elevation) in [(0.3, 0.2), (-2., -0.7), (3., 1.2), (-0.5, 0.)] {
+            let direction = Direction::new(azimuth, elevation);
+            assert_near(direction.to_quaternion() * Vector3::x(), direction.to_unit_vec());
+        }
+    }
+
+    #[test]
+    fn rep103_normalizes_without_turning() {
+        for (direction, normalized) in [
+            ((0.5, 0.2), (0.5, 0.2)),
+            ((3. * FRAC_PI_2, 0.), (-FRAC_PI_2, 0.)),
+            ((-PI, 0.3), (PI, 0.3)),
+            ((0., 2. * FRAC_PI_3), (PI, FRAC_PI_3)),
+            ((FRAC_PI_2, -2. * FRAC_PI_3), (-FRAC_PI_2, -FRAC_PI_3)),
+        ] {
+            let direction = Direction::from(direction);
+            let found = rep103(direction);
+            let (azimuth, elevation) = normalized;
+            assert!((found.azimuth - azimuth).abs() < 1e-9, "{direction:?} -> {found:?}");
+            assert!((found.elevation - elevation).abs() < 1e-9, "{direction:?} -> {found:?}");
+            assert_near(found.to_unit_vec(), direction.to_unit_vec());
+        }
+    }
+    #[test]
+    fn optical_axes() {
+        assert_near(optical() * Vector3::x(), Vector3::z());
+        assert_near(optical() * Vector3::y(), -Vector3::x());
+        assert_near(optical() * Vector3::z(), -Vector3::y());
+    }
+
+    #[test]
+    fn optical_orientation_points_along_unit_vector() {
+        let mut config = Config::test();
+        config.optical_frame = true;
+        for (azimuth, elevation) in [(0., 0.), (0.3, 0.2), (-2., -0.7), (FRAC_PI_2, 1.)] {
+            let direction = Direction::new(azimuth, elevation);
+            assert_near(config.orientation(direction) * Vector3::x(), config.unit_vec(direction));
+        }
+        // forward is along the optical axis
+        assert_near(config.unit_vec(Direction::new(0., 0.)), Vector3::z());
+    }
+}
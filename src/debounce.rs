@@ -0,0 +1,62 @@
+use std::time::{Duration, Instant};
+
+use rosrust::ros_info;
+
+/// Delays acting on a value until it stopped changing for a quiet period.
+///
+/// Used to coalesce rapid reconfigure updates (e.g. dragging a slider) into a
+/// single rebuild of the recorder or the mbss analyzer. Additionally rebuilds
+/// can be limited to a minimal interval, protecting against programmatic
+/// config churn that settles repeatedly.
+#[derive(Debug)]
+pub struct Debounce<T> {
+    pending: Option<(T, Instant)>,
+    last: Option<Instant>,
+    limited: bool,
+}
+
+impl<T> Default for Debounce<T> {
+    fn default() -> Self {
+        Self {
+            pending: None,
+            last: None,
+            limited: false,
+        }
+    }
+}
+
+impl<T: PartialEq + Clone> Debounce<T> {
+    /// Returns whether `value` has been unchanged for at least `quiet` and the
+    /// last time this returned `true` is at least `min_interval` ago.
+    ///
+    /// Needs to be polled repeatedly, every change of `value` restarts the
+    /// quiet period.
+    pub fn settled(&mut self, value: &T, quiet: Duration, min_interval: Duration) -> bool {
+        let settled = match &self.pending {
+            Some((pending, since)) if pending == value => since.elapsed() >= quiet,
+            _ if quiet.is_zero() => true,
+            _ => {
+                self.pending = Some((value.clone(), Instant::now()));
+                false
+            }
+        };
+        if !settled {
+            return false;
+        }
+        if let Some(last) = self.last.filter(|last| last.elapsed() < min_interval) {
+            if !self.limited {
+                self.limited = true;
+                ros_info!(
+                    "rebuilds are limited to one every {min_interval:?}, applying the update in \
+                     {:?}",
+                    min_interval - last.elapsed()
+                );
+            }
+            return false;
+        }
+        self.pending = None;
+        self.last = Some(Instant::now());
+        self.limited = false;
+        true
+    }
+}
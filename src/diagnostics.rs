@@ -0,0 +1,84 @@
+//! Real-time performance summary published on `~diagnostics`, e.g. for
+//! `rqt_runtime_monitor`.
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use ssloc::F;
+
+use crate::msgs;
+
+#[derive(Default)]
+pub struct Diagnostics(Mutex<State>);
+
+#[derive(Default)]
+struct State {
+    recorded: u64,
+    dropped: u64,
+    /// Counts at the last status, the drop rate is reported since then.
+    reported: (u64, u64),
+    /// Seconds from recording to publishing of the last analyzed frame.
+    latency: F,
+    /// Seconds spent in the analysis of the last frame.
+    processing: F,
+}
+
+impl Diagnostics {
+    /// Counts a frame queued for the analysis.
+    pub fn recorded(&self) {
+        self.0.lock().recorded += 1;
+    }
+
+    /// Counts a frame dropped because the analysis was too slow.
+    pub fn dropped(&self) {
+        self.0.lock().dropped += 1;
+    }
+
+    /// Reports the timing of an analyzed frame.
+    pub fn analyzed(&self, processing: Duration, latency: F) {
+        let mut state = self.0.lock();
+        state.processing = processing.as_secs_f64();
+        state.latency = latency;
+    }
+
+    /// Summary since the last status, warns if more than `max_drop_rate` of
+    /// the recorded frames were dropped.
+    pub fn status(&self, max_drop_rate: F) -> msgs::DiagnosticStatus {
+        let mut state = self.0.lock();
+        let recorded = state.recorded - state.reported.0;
+        let dropped = state.dropped - state.reported.1;
+        state.reported = (state.recorded, state.dropped);
+        let drop_rate = if recorded > 0 {
+            dropped as F / recorded as F
+        } else {
+            0.
+        };
+        let (level, message) = if drop_rate > max_drop_rate {
+            (
+                msgs::DiagnosticStatus::WARN,
+                format!("{:.0}% of the frames dropped, analysis too slow", drop_rate * 100.),
+            )
+        } else {
+            (msgs::DiagnosticStatus::OK, "ok".to_owned())
+        };
+        let values = [
+            ("frames recorded", state.recorded.to_string()),
+            ("frames dropped", state.dropped.to_string()),
+            ("drop rate", format!("{drop_rate:.3}")),
+            ("latency [s]", format!("{:.3}", state.latency)),
+            ("processing time [s]", format!("{:.3}", state.processing)),
+        ];
+        msgs::DiagnosticStatus {
+            level,
+            name: format!("{}: performance", rosrust::name()),
+            message,
+            hardware_id: String::new(),
+            values: values
+                .into_iter()
+                .map(|(key, value)| msgs::KeyValue {
+                    key: key.to_owned(),
+                    value,
+                })
+                .collect(),
+        }
+    }
+}
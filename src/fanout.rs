@@ -0,0 +1,107 @@
+//! Fan-out of the recordings to several consumers, each with a bounded queue
+//! of its own, so a slow consumer only drops its own recordings.
+//!
+//! Consumers are registered with [`FanOut::subscribe`] before the recorder
+//! starts, threads sharing a [`Receiver`] split the recordings among
+//! themselves like the analyzer threads do.
+use std::sync::Arc;
+
+use crossbeam::channel::{bounded, Receiver, SendTimeoutError, Sender};
+use parking_lot::Mutex;
+use rosrust::{ros_err, ros_warn, Time};
+use ssloc::Audio;
+
+use crate::audio::AudioExt;
+use crate::diagnostics::Diagnostics;
+use crate::SHUTDOWN_POLL;
+
+/// Stamp, sequence number and audio of a recording.
+pub type Recording = (Time, u32, Audio);
+
+#[derive(Clone, Default)]
+pub struct FanOut(Arc<Mutex<Vec<Consumer>>>);
+
+#[derive(Clone)]
+struct Consumer {
+    name: String,
+    send: Sender<Recording>,
+    /// Kept to drop the oldest recording when the queue is full.
+    recv: Receiver<Recording>,
+}
+
+impl FanOut {
+    /// Registers a consumer with a queue of `capacity` recordings, `name`
+    /// identifies it in warnings.
+    pub fn subscribe(&self, name: impl Into<String>, capacity: usize) -> Receiver<Recording> {
+        let (send, recv) = bounded(capacity.max(1));
+        self.0.lock().push(Consumer {
+            name: name.into(),
+            send,
+            recv: recv.clone(),
+        });
+        recv
+    }
+
+    /// Sends a recording to all consumers, dropping the oldest queued one of
+    /// consumers that are too slow.
+    pub fn send(&self, recording: Recording, diagnostics: &Diagnostics) {
+        let consumers = self.0.lock();
+        let Some((last, others)) = consumers.split_last() else {
+            return;
+        };
+        for consumer in others {
+            consumer.push(duplicate(&recording), diagnostics);
+        }
+        last.push(recording, diagnostics);
+    }
+
+    /// Sends a recording to all consumers, waiting for room in their queues
+    /// instead of dropping. Returns `false` on shutdown.
+    pub fn queue(&self, recording: Recording) -> bool {
+        // not locked while blocking, so consumers can still be registered
+        let consumers = self.0.lock().clone();
+        let Some((last, others)) = consumers.split_last() else {
+            return true;
+        };
+        others
+            .iter()
+            .all(|consumer| queue(&consumer.send, duplicate(&recording)))
+            && queue(&last.send, recording)
+    }
+}
+
+impl Consumer {
+    fn push(&self, recording: Recording, diagnostics: &Diagnostics) {
+        if self.send.is_full() {
+            // otherwise emptied by the consumer in the meantime
+            if let Ok((stamp, ..)) = self.recv.try_recv() {
+                ros_warn!("recording from {stamp} was dropped, {} too slow", self.name);
+                diagnostics.dropped();
+            }
+        }
+        // only fails if another producer filled the queue again, which drops
+        // the newest recording instead
+        let _ = self.send.try_send(recording);
+    }
+}
+
+fn duplicate((stamp, seq, audio): &Recording) -> Recording {
+    (*stamp, *seq, audio.duplicate())
+}
+
+/// Blocks until `item` is queued, returns `false` on shutdown.
+fn queue<T>(channel: &Sender<T>, mut item: T) -> bool {
+    loop {
+        match channel.send_timeout(item, SHUTDOWN_POLL) {
+            Ok(()) => return true,
+            Err(SendTimeoutError::Timeout(returned)) if rosrust::is_ok() => item = returned,
+            Err(SendTimeoutError::Timeout(_)) => return false,
+            Err(SendTimeoutError::Disconnected(_)) => {
+                if rosrust::is_ok() {
+                    ros_err!("channel disconnected, process must have exited");
+                }
+                return false;
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Mic array geometry files for `geometry/file`, one `x, y, z` position in
+//! meters per mic.
+//!
+//! Files ending in `.yaml` or `.yml` contain a list of positions, either at
+//! the top level or under `mics`, e.g. `mics: [[0.1, 0, 0], [-0.1, 0, 0]]`.
+//! All other files are read as CSV with one position per row, empty rows and
+//! rows starting with `#` are skipped.
+use std::fs;
+use std::path::Path;
+
+use itertools::Itertools;
+use nalgebra::vector;
+use ssloc::{Position, F};
+use yaml_rust::{Yaml, YamlLoader};
+
+/// Positions of the mics in the file at `path`.
+pub fn load(path: &str) -> Result<Vec<Position>, String> {
+    let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let yaml = Path::new(path)
+        .extension()
+        .is_some_and(|extension| extension == "yaml" || extension == "yml");
+    let mics = if yaml { parse_yaml(&text)? } else { parse_csv(&text)? };
+    if mics.is_empty() {
+        return Err("no mic positions".to_owned());
+    }
+    Ok(mics)
+}
+
+fn parse_csv(text: &str) -> Result<Vec<Position>, String> {
+    text.lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line, row)| {
+            let coordinates: Vec<F> = row
+                .split(',')
+                .map(|value| value.trim().parse())
+                .try_collect()
+                .map_err(|e| format!("line {line}: {e}"))?;
+            position(&coordinates).ok_or_else(|| {
+                format!("line {line}: expected `x, y, z`, got {} values", coordinates.len())
+            })
+        })
+        .collect()
+}
+
+fn parse_yaml(text: &str) -> Result<Vec<Position>, String> {
+    let documents = YamlLoader::load_from_str(text).map_err(|e| e.to_string())?;
+    let document = documents.first().ok_or("empty document")?;
+    let mics = match &document["mics"] {
+        Yaml::BadValue => document,
+        mics => mics,
+    };
+    mics.as_vec()
+        .ok_or("expected a list of positions")?
+        .iter()
+        .enumerate()
+        .map(|(idx, mic)| {
+            mic.as_vec()
+                .and_then(|coordinates| {
+                    let coordinates: Option<Vec<F>> = coordinates
+                        .iter()
+                        .map(|value| value.as_f64().or_else(|| Some(value.as_i64()? as F)))
+                        .collect();
+                    position(&coordinates?)
+                })
+                .ok_or_else(|| format!("mic {idx}: expected `[x, y, z]` in meters"))
+        })
+        .collect()
+}
+
+fn position(coordinates: &[F]) -> Option<Position> {
+    match *coordinates {
+        [x, y, z] => Some(vector![x, y, z]),
+        _ => None,
+    }
+}
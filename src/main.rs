@@ -1,20 +1,20 @@
-use std::io::Cursor;
-use std::mem::size_of;
-use std::sync::atomic::AtomicI64;
+use std::sync::atomic::AtomicU32;
 use std::sync::{atomic, Arc};
-use std::time::Duration;
-use std::{iter, mem, thread};
+use std::time::{Duration, Instant};
+use std::{iter, thread};
 
-use crossbeam::channel::{bounded, Receiver, Sender, TryRecvError};
+use crossbeam::channel::{Receiver, RecvTimeoutError};
 use extend::ext;
-use image::ImageOutputFormat;
+use image::imageops;
 use itertools::Itertools;
+use ndarray::{Array1, Array2, Axis};
 use parking_lot::Mutex;
-use rosrust::error::ResultExt;
-use rosrust::{ros_err, ros_info, ros_warn, ros_warn_throttle, Message, Publisher, Time};
+use rosrust::{
+    ros_debug, ros_debug_throttle, ros_err, ros_err_throttle, ros_info, ros_warn, ros_warn_throttle,
+    Message, Publisher, Time,
+};
 use rosrust_dynamic_reconfigure::Updating;
-use ssloc::mbss::angular_distance;
-use ssloc::{for_format, Audio, AudioRecorder, DelayAndSum, Direction, Format, PcmFormat, F};
+use ssloc::{for_format, Audio, AudioRecorder, DelayAndSum, Format, F};
 
 #[cfg(feature = "builtin-msgs")]
 mod msgs;
@@ -24,57 +24,116 @@ mod msgs {
     #[cfg(all(feature = "odas-msgs", feature = "audio_common_msgs-stamped"))]
     rosrust::rosmsg_include! {
         audio_common_msgs/AudioData, audio_common_msgs/AudioDataStamped, audio_common_msgs/AudioInfo,
-        geometry_msgs/Point, geometry_msgs/Pose, geometry_msgs/PoseArray, geometry_msgs/Quaternion, geometry_msgs/Vector3,
+        diagnostic_msgs/DiagnosticArray, diagnostic_msgs/DiagnosticStatus, diagnostic_msgs/KeyValue,
+        geometry_msgs/Point, geometry_msgs/Pose, geometry_msgs/PoseArray, geometry_msgs/PoseStamped, geometry_msgs/Quaternion, geometry_msgs/Transform, geometry_msgs/TransformStamped, geometry_msgs/Vector3,
         odas_ros/OdasSsl, odas_ros/OdasSslArrayStamped, odas_ros/OdasSst, odas_ros/OdasSstArrayStamped,
-        sensor_msgs/CompressedImage, sensor_msgs/PointCloud2, sensor_msgs/PointField,
+        sensor_msgs/CompressedImage, sensor_msgs/Image, sensor_msgs/PointCloud2, sensor_msgs/PointField,
+        ssloc_ros/Capture, ssloc_ros/Detection, ssloc_ros/Detections, ssloc_ros/Device, ssloc_ros/ListDevices, ssloc_ros/ValidateConfig,
         ssloc_ros_msgs/Ssl, ssloc_ros_msgs/SslArray, ssloc_ros_msgs/Sst, ssloc_ros_msgs/SstArray, ssloc_ros_msgs/SssMapping,
-        std_msgs/ColorRGBA, std_msgs/Header,
+        std_msgs/ColorRGBA, std_msgs/Float32, std_msgs/Float32MultiArray, std_msgs/Float64, std_msgs/Float64MultiArray, std_msgs/Header, std_msgs/MultiArrayDimension, std_msgs/MultiArrayLayout, std_msgs/UInt32,
+        std_srvs/Trigger,
+        tf2_msgs/TFMessage,
         visualization_msgs/Marker,
     }
     #[cfg(all(feature = "odas-msgs", not(feature = "audio_common_msgs-stamped")))]
     rosrust::rosmsg_include! {
         audio_common_msgs/AudioData, audio_common_msgs/AudioInfo,
-        geometry_msgs/Point, geometry_msgs/Pose, geometry_msgs/PoseArray, geometry_msgs/Quaternion, geometry_msgs/Vector3,
+        diagnostic_msgs/DiagnosticArray, diagnostic_msgs/DiagnosticStatus, diagnostic_msgs/KeyValue,
+        geometry_msgs/Point, geometry_msgs/Pose, geometry_msgs/PoseArray, geometry_msgs/PoseStamped, geometry_msgs/Quaternion, geometry_msgs/Transform, geometry_msgs/TransformStamped, geometry_msgs/Vector3,
         odas_ros/OdasSsl, odas_ros/OdasSslArrayStamped, odas_ros/OdasSst, odas_ros/OdasSstArrayStamped,
-        sensor_msgs/CompressedImage, sensor_msgs/PointCloud2, sensor_msgs/PointField,
+        sensor_msgs/CompressedImage, sensor_msgs/Image, sensor_msgs/PointCloud2, sensor_msgs/PointField,
+        ssloc_ros/Capture, ssloc_ros/Detection, ssloc_ros/Detections, ssloc_ros/Device, ssloc_ros/ListDevices, ssloc_ros/ValidateConfig,
         ssloc_ros_msgs/Ssl, ssloc_ros_msgs/SslArray, ssloc_ros_msgs/Sst, ssloc_ros_msgs/SstArray, ssloc_ros_msgs/SssMapping,
-        std_msgs/ColorRGBA, std_msgs/Header,
+        std_msgs/ColorRGBA, std_msgs/Float32, std_msgs/Float32MultiArray, std_msgs/Float64, std_msgs/Float64MultiArray, std_msgs/Header, std_msgs/MultiArrayDimension, std_msgs/MultiArrayLayout, std_msgs/UInt32,
+        std_srvs/Trigger,
+        tf2_msgs/TFMessage,
         visualization_msgs/Marker,
     }
     #[cfg(not(any(feature = "odas-msgs", feature = "audio_common_msgs-stamped")))]
     rosrust::rosmsg_include! {
         audio_common_msgs/AudioData, audio_common_msgs/AudioInfo,
-        geometry_msgs/Point, geometry_msgs/Pose, geometry_msgs/PoseArray, geometry_msgs/Quaternion, geometry_msgs/Vector3,
-        sensor_msgs/CompressedImage, sensor_msgs/PointCloud2, sensor_msgs/PointField,
+        diagnostic_msgs/DiagnosticArray, diagnostic_msgs/DiagnosticStatus, diagnostic_msgs/KeyValue,
+        geometry_msgs/Point, geometry_msgs/Pose, geometry_msgs/PoseArray, geometry_msgs/PoseStamped, geometry_msgs/Quaternion, geometry_msgs/Transform, geometry_msgs/TransformStamped, geometry_msgs/Vector3,
+        sensor_msgs/CompressedImage, sensor_msgs/Image, sensor_msgs/PointCloud2, sensor_msgs/PointField,
+        ssloc_ros/Capture, ssloc_ros/Detection, ssloc_ros/Detections, ssloc_ros/Device, ssloc_ros/ListDevices, ssloc_ros/ValidateConfig,
         ssloc_ros_msgs/Ssl, ssloc_ros_msgs/SslArray, ssloc_ros_msgs/Sst, ssloc_ros_msgs/SstArray, ssloc_ros_msgs/SssMapping,
-        std_msgs/ColorRGBA, std_msgs/Header,
+        std_msgs/ColorRGBA, std_msgs/Float32, std_msgs/Float32MultiArray, std_msgs/Float64, std_msgs/Float64MultiArray, std_msgs/Header, std_msgs/MultiArrayDimension, std_msgs/MultiArrayLayout, std_msgs/UInt32,
+        std_srvs/Trigger,
+        tf2_msgs/TFMessage,
         visualization_msgs/Marker,
     }
     #[cfg(all(not(feature = "odas-msgs"), feature = "audio_common_msgs-stamped"))]
     rosrust::rosmsg_include! {
         audio_common_msgs/AudioData, audio_common_msgs/AudioDataStamped, audio_common_msgs/AudioInfo,
-        geometry_msgs/Point, geometry_msgs/Pose, geometry_msgs/PoseArray, geometry_msgs/Quaternion, geometry_msgs/Vector3,
-        sensor_msgs/CompressedImage, sensor_msgs/PointCloud2, sensor_msgs/PointField,
+        diagnostic_msgs/DiagnosticArray, diagnostic_msgs/DiagnosticStatus, diagnostic_msgs/KeyValue,
+        geometry_msgs/Point, geometry_msgs/Pose, geometry_msgs/PoseArray, geometry_msgs/PoseStamped, geometry_msgs/Quaternion, geometry_msgs/Transform, geometry_msgs/TransformStamped, geometry_msgs/Vector3,
+        sensor_msgs/CompressedImage, sensor_msgs/Image, sensor_msgs/PointCloud2, sensor_msgs/PointField,
+        ssloc_ros/Capture, ssloc_ros/Detection, ssloc_ros/Detections, ssloc_ros/Device, ssloc_ros/ListDevices, ssloc_ros/ValidateConfig,
         ssloc_ros_msgs/Ssl, ssloc_ros_msgs/SslArray, ssloc_ros_msgs/Sst, ssloc_ros_msgs/SstArray, ssloc_ros_msgs/SssMapping,
-        std_msgs/ColorRGBA, std_msgs/Header,
+        std_msgs/ColorRGBA, std_msgs/Float32, std_msgs/Float32MultiArray, std_msgs/Float64, std_msgs/Float64MultiArray, std_msgs/Header, std_msgs/MultiArrayDimension, std_msgs/MultiArrayLayout, std_msgs/UInt32,
+        std_srvs/Trigger,
+        tf2_msgs/TFMessage,
         visualization_msgs/Marker,
     }
     #[cfg(feature = "audio_common_msgs-stamped")]
     pub use audio_common_msgs::AudioDataStamped;
     pub use audio_common_msgs::{AudioData, AudioInfo};
-    pub use geometry_msgs::{Point, Pose, PoseArray, Quaternion, Vector3};
+    pub use diagnostic_msgs::{DiagnosticArray, DiagnosticStatus, KeyValue};
+    pub use geometry_msgs::{
+        Point, Pose, PoseArray, PoseStamped, Quaternion, Transform, TransformStamped, Vector3,
+    };
     #[cfg(feature = "odas-msgs")]
     pub use odas_ros::{OdasSsl, OdasSslArrayStamped, OdasSst, OdasSstArrayStamped};
-    pub use sensor_msgs::{CompressedImage, PointCloud2, PointField};
+    pub use sensor_msgs::{CompressedImage, Image, PointCloud2, PointField};
+    pub use ssloc_ros::{
+        Capture, CaptureRes, Detection, Detections, Device, ListDevices, ListDevicesRes,
+        ValidateConfig, ValidateConfigRes,
+    };
     pub use ssloc_ros_msgs::{Ssl, SslArray, SssMapping, Sst, SstArray};
-    pub use std_msgs::{ColorRGBA, Header};
+    pub use std_msgs::{
+        ColorRGBA, Float32, Float32MultiArray, Float64, Float64MultiArray, Header,
+        MultiArrayDimension, MultiArrayLayout, UInt32,
+    };
+    pub use std_srvs::{Trigger, TriggerRes};
+    pub use tf2_msgs::TFMessage;
     pub use visualization_msgs::Marker;
 }
 
 type Result<T = (), E = rosrust::error::Error> = std::result::Result<T, E>;
 
+mod audio;
+use audio::{AudioExt, Overlap};
+mod backoff;
+use backoff::Backoff;
+mod candidates;
+mod capture;
+mod colormap;
 mod config;
-use config::Config;
+use config::{
+    Config, Device, Follow, Fusion, InputSource, PublishFormat, SpectrumMethod, Window,
+};
+mod convention;
+mod debounce;
+use debounce::Debounce;
+mod diagnostics;
+use diagnostics::Diagnostics;
+mod fanout;
+use fanout::{FanOut, Recording};
+mod geometry;
+mod source;
+use source::{AudioSource, FileReplay, MockRecorder, Replay, ReplayEnd};
+mod spectrum;
+use spectrum::{NoiseFloor, Spectrogram};
+mod steering;
+mod subband;
+use subband::Subbands;
+mod tracker;
+use tracker::{Track, Tracker};
+mod vad;
+use vad::{SilenceGate, Vad};
+mod watchdog;
+use watchdog::Watchdog;
+mod wav;
 
 #[ext]
 impl<T: Message> Publisher<T> {
@@ -111,11 +170,17 @@ fn main() -> Result {
     env_logger::init();
 
     rosrust::init("ssloc");
-    let frame_id: String = rosrust::param("~frame_id")
+    let recording_only: bool = rosrust::param("~recording_only")
         .expect("should get parameter")
         .get()
-        .chain_err(|| "getting ~frame_id parameter")?;
-    let recording_only: bool = rosrust::param("~recording_only")
+        .unwrap_or_default();
+    let inject_mode: bool = rosrust::param("~inject_mode")
+        .expect("should get parameter")
+        .get()
+        .unwrap_or_default();
+    // analyzes only the audio on `recording/audio_message_topic`, e.g. replayed from a bag,
+    // without opening the device or publishing `~audio`
+    let disable_recorder: bool = rosrust::param("~disable_recorder")
         .expect("should get parameter")
         .get()
         .unwrap_or_default();
@@ -123,65 +188,549 @@ fn main() -> Result {
         .expect("should get parameter")
         .get::<usize>()
         .unwrap_or_default()
-        .max(1)
-        .min(5);
-
-    let mut config_server = rosrust_dynamic_reconfigure::Server::<Config>::new(Config::init()?)?;
-
-    let updating_config = config_server.get_config_updating();
-
-    let (audio_channel_send, audio_channel_recv) = bounded(ssloc_threads);
-    let audio_recorder = thread::Builder::new()
-        .name("audio recorder".to_owned())
-        .spawn(recorder(
-            updating_config.clone(),
-            frame_id.clone(),
-            audio_channel_send,
-            audio_channel_recv.clone(),
-            recording_only,
-        ))
-        .expect("spawning audio thread should not panic");
-
-    let ssloc: Vec<_> = (0..(!recording_only)
-        .then_some(ssloc_threads)
-        .unwrap_or_default())
+        .clamp(1, 5);
+    // recordings queued for the analysis before the oldest is dropped, deeper
+    // queues tolerate bursty processing but increase the worst case latency
+    let audio_queue_len = rosrust::param("~audio_queue_len")
+        .expect("should get parameter")
+        .get::<usize>()
+        .unwrap_or(ssloc_threads)
+        .max(1);
+
+    let config_server = Arc::new(Mutex::new(rosrust_dynamic_reconfigure::Server::<Config>::new(
+        Config::init()?,
+    )?));
+
+    let updating_config = config_server.lock().get_config_updating();
+
+    let _dump_steering = rosrust::service::<msgs::Trigger, _>("~dump_steering", {
+        let updating_config = updating_config.clone();
+        move |_| {
+            let config = updating_config.read();
+            Ok(msgs::TriggerRes {
+                success: true,
+                message: steering::describe(
+                    &config.enabled_mics().collect_vec(),
+                    config.steering_direction(),
+                    config.mbss.speed_of_sound,
+                    config.rate.into(),
+                ),
+            })
+        }
+    })?;
+
+    let _list_devices = rosrust::service::<msgs::ListDevices, _>("~list_devices", {
+        let updating_config = updating_config.clone();
+        move |_| {
+            Ok(match Device::enumerate() {
+                Ok(mut devices) => {
+                    // the device recorded from is busy and cannot be opened again
+                    let current = updating_config.read().device.clone();
+                    if !devices.iter().any(|device| device.name == current.name) {
+                        devices.push(current);
+                    }
+                    msgs::ListDevicesRes {
+                        success: true,
+                        message: format!("found {} capture devices", devices.len()),
+                        devices: devices
+                            .into_iter()
+                            .map(|device| msgs::Device {
+                                name: device.name,
+                                description: device.description,
+                                min_channels: device.channels.0,
+                                max_channels: device.channels.1,
+                                min_rate: device.rate.0.into(),
+                                max_rate: device.rate.1.into(),
+                                formats: device.formats.iter().map(ToString::to_string).collect(),
+                            })
+                            .collect(),
+                    }
+                }
+                Err(err) => msgs::ListDevicesRes {
+                    success: false,
+                    message: format!("error enumerating capture devices: {err}"),
+                    devices: Vec::new(),
+                },
+            })
+        }
+    })?;
+
+    let _validate_config = rosrust::service::<msgs::ValidateConfig, _>("~validate_config", {
+        let updating_config = updating_config.clone();
+        move |req| {
+            let mut config = updating_config.copy();
+            let loaded = if req.config_file.is_empty() {
+                Ok(())
+            } else {
+                config
+                    .load(&req.config_file)
+                    .map(drop)
+                    .map_err(|err| format!("error loading `{}`: {err}", req.config_file))
+            };
+            Ok(match loaded.and_then(|()| config.validate(true)) {
+                Ok(()) => msgs::ValidateConfigRes {
+                    success: true,
+                    message: "configuration is valid".to_owned(),
+                },
+                Err(message) => msgs::ValidateConfigRes {
+                    success: false,
+                    message,
+                },
+            })
+        }
+    })?;
+
+    let tap = capture::Tap::default();
+    let _capture = rosrust::service::<msgs::Capture, _>("~capture", {
+        let tap = tap.clone();
+        move |req| {
+            Ok(match tap.capture(req.duration) {
+                Ok(audio) => msgs::CaptureRes {
+                    success: true,
+                    message: format!(
+                        "recorded {} samples of {} channels at {} Hz",
+                        audio.samples(),
+                        audio.channels(),
+                        audio.sample_rate()
+                    ),
+                    audio: wav::encode(&audio),
+                },
+                Err(message) => msgs::CaptureRes {
+                    success: false,
+                    message,
+                    audio: Vec::new(),
+                },
+            })
+        }
+    })?;
+
+    let _calibrate = rosrust::service::<msgs::Trigger, _>("~calibrate", {
+        let tap = tap.clone();
+        let updating_config = updating_config.clone();
+        move |_| {
+            let calibrated = tap.capture(capture::CALIBRATION).and_then(|audio| {
+                let mut config = updating_config.copy();
+                config.channel_gains = audio::calibrate(&audio, &config.channel_gains)?;
+                let gains = config.channel_gains.iter().join(", ");
+                config_server.lock().set_config(config).map_err(|e| e.to_string())?;
+                Ok(gains)
+            });
+            Ok(match calibrated {
+                Ok(gains) => msgs::TriggerRes {
+                    success: true,
+                    message: format!("set `recording/channel_gains` to {gains}"),
+                },
+                Err(err) => msgs::TriggerRes {
+                    success: false,
+                    message: format!("error calibrating: {err}"),
+                },
+            })
+        }
+    })?;
+
+    // waits for a valid configuration, e.g. mics set by dynamic reconfigure,
+    // reporting all problems at once instead of the threads retrying forever
+    let mut invalid = None;
+    loop {
+        let validated = updating_config.read().validate(!recording_only);
+        match validated {
+            Ok(()) => break,
+            Err(problems) if invalid.as_ref() != Some(&problems) => {
+                ros_err!("invalid configuration, waiting for a valid one:\n{problems}");
+                invalid = Some(problems);
+            }
+            Err(_) => {}
+        }
+        if !rosrust::is_ok() {
+            return Ok(());
+        }
+        thread::sleep(SHUTDOWN_POLL);
+    }
+    if invalid.is_some() {
+        ros_info!("configuration is valid, starting");
+    }
+
+    let diagnostics = Arc::new(Diagnostics::default());
+    let fanout = FanOut::default();
+    // further consumers of the recordings subscribe here
+    let audio_channel_recv = fanout.subscribe("ssloc operation", audio_queue_len);
+    let _inject_audio = if inject_mode {
+        Some(inject(updating_config.clone(), fanout.clone())?)
+    } else {
+        None
+    };
+    let watchdog = Arc::new(Watchdog::default());
+    let spawn_recorder = || {
+        thread::Builder::new()
+            .name("audio recorder".to_owned())
+            .spawn(recorder(
+                updating_config.clone(),
+                tap.clone(),
+                diagnostics.clone(),
+                fanout.clone(),
+                watchdog.clone(),
+                recording_only,
+            ))
+            .expect("spawning audio thread should not panic")
+    };
+    let mut audio_recorder = (!inject_mode && !disable_recorder).then(spawn_recorder);
+    // recorders replaced by the watchdog, still blocked in a recording
+    let mut wedged = Vec::new();
+
+    let ssloc: Vec<_> = (0..if recording_only { 0 } else { ssloc_threads })
         .map(|idx| {
             thread::Builder::new()
                 .name(format!("ssloc{idx}"))
                 .spawn(ssloc(
                     updating_config.clone(),
-                    frame_id.clone(),
+                    diagnostics.clone(),
                     audio_channel_recv.clone(),
                 ))
                 .expect("should be able to start ssloc process")
         })
         .collect();
 
+    let diagnostics_topic = rosrust::publish::<msgs::DiagnosticArray>("~diagnostics", 1)?;
+    let mut diagnosed = Instant::now();
+    // `audio_message_topic`, whether stamped, channels and rate subscribed with for
+    // `disable_recorder`
+    let mut listening = None;
+    let mut _subscribers = None;
+    let cycle = Arc::new(AtomicU32::new(1));
+
     // Create object that maintains 10Hz between sleep requests
     let rate = rosrust::rate(10.0);
 
     // Breaks when a shutdown signal is sent
     while rosrust::is_ok() {
         rate.sleep();
+        let (timeout, device) = {
+            let config = updating_config.read();
+            (config.watchdog_timeout(), config.device.name.clone())
+        };
+        // `None` for other sources, a recorder blocked on the device switches to
+        // them once its recording returns, and without a recorder
+        let timeout = timeout.filter(|_| audio_recorder.is_some());
+        if let Some(blocked) = timeout.and_then(|timeout| watchdog.check(timeout)) {
+            ros_err!("recording blocked for {blocked:.1?}, replacing the recorder");
+            // the blocked recording still holds the PCM, so reopening it fails with EBUSY
+            ros_warn!(
+                "`{device}` stays busy until the blocked recording returns, the new recorder \
+                 retries until then"
+            );
+            wedged.extend(audio_recorder.take());
+            audio_recorder = Some(spawn_recorder());
+        }
+        if disable_recorder {
+            let config = updating_config.read();
+            let subscribed = (
+                config.audio_message_topic.clone(),
+                config.audio_message_stamped,
+                config.channels,
+                config.rate,
+            );
+            if listening.as_ref() != Some(&subscribed) {
+                // unsubscribes before subscribing the same topic again
+                _subscribers = None;
+                _subscribers = match &config.audio_message_topic {
+                    Some(topic) => {
+                        let subscribers =
+                            listen(topic, &config, &updating_config, &diagnostics, &fanout, &cycle);
+                        if let Err(err) = &subscribers {
+                            ros_err!("error subscribing to `audio_message_topic` {topic} {err}");
+                        }
+                        subscribers.ok()
+                    }
+                    None => {
+                        ros_warn!(
+                            "`~disable_recorder` is set without `recording/audio_message_topic`, \
+                             nothing to analyze"
+                        );
+                        None
+                    }
+                };
+                listening = Some(subscribed);
+            }
+        }
+        if diagnosed.elapsed() >= Duration::from_secs(1) {
+            diagnosed = Instant::now();
+            let max_drop_rate = updating_config.read().max_drop_rate;
+            log_error!(
+                diagnostics_topic.send(msgs::DiagnosticArray {
+                    header: msgs::Header {
+                        stamp: rosrust::now(),
+                        ..Default::default()
+                    },
+                    status: vec![diagnostics.status(max_drop_rate)],
+                }),
+                "error sending diagnostics {err}"
+            );
+        }
     }
-    for ssloc in ssloc {
-        ssloc.join().expect("ssloc thread should not panic")?;
-    }
-    audio_recorder
-        .join()
-        .expect("audio_recorder should not panic")?;
+    // unblocks the analyzers waiting for injected or subscribed audio
+    drop(_inject_audio);
+    drop(_subscribers);
+    join(ssloc.into_iter().chain(audio_recorder).chain(wedged), SHUTDOWN_TIMEOUT)
+}
 
+/// Time the threads get to exit after a shutdown signal.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(1);
+/// Interval blocking operations check for a shutdown signal.
+const SHUTDOWN_POLL: Duration = Duration::from_millis(100);
+
+/// Joins the `threads`, leaving the ones behind that did not exit before
+/// `timeout`, e.g. because they are blocked on a recording.
+fn join(
+    threads: impl IntoIterator<Item = thread::JoinHandle<Result>>,
+    timeout: Duration,
+) -> Result {
+    let deadline = Instant::now() + timeout;
+    for thread in threads {
+        while !thread.is_finished() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        if !thread.is_finished() {
+            ros_warn!(
+                "`{}` did not exit within {timeout:?} after shutdown",
+                thread.thread().name().unwrap_or_default()
+            );
+            continue;
+        }
+        thread.join().expect("threads should not panic")?;
+    }
     Ok(())
 }
 
+/// Sleeps for `duration` or until a shutdown signal.
+fn sleep(duration: Duration) {
+    let deadline = Instant::now() + duration;
+    while rosrust::is_ok() {
+        let Some(remaining) = deadline.checked_duration_since(Instant::now()) else {
+            return;
+        };
+        thread::sleep(remaining.min(SHUTDOWN_POLL));
+    }
+}
+
+/// Waits before reopening the device after a failure, twice as long after
+/// every consecutive one, or until the recording is reconfigured. Only the
+/// first of consecutive failures is logged as error.
+fn retry(
+    backoff: &mut Backoff,
+    error: String,
+    tap: &capture::Tap,
+    updating_config: &Updating<Config>,
+    capture: &config::Capture,
+) {
+    let delay = backoff.failed();
+    if backoff.started_failing() {
+        ros_err!("{error}, retrying with increasing delays of up to {:?}", Backoff::MAX);
+    } else {
+        ros_debug!("{error}, retrying in {delay:?}");
+    }
+    tap.set_error(Some(error));
+    let deadline = Instant::now() + delay;
+    while rosrust::is_ok()
+        && Instant::now() < deadline
+        && &updating_config.read().capture() == capture
+    {
+        thread::sleep(SHUTDOWN_POLL);
+    }
+}
+
+/// Describes published audio.
+fn audio_info(channels: u16, rate: u16, format: PublishFormat) -> msgs::AudioInfo {
+    msgs::AudioInfo {
+        channels: channels as u8,
+        sample_rate: rate.into(),
+        sample_format: format.to_string().to_uppercase(),
+        bitrate: format.bits() * u32::from(rate),
+        coding_format: "wave".into(),
+    }
+}
+
+/// Feeds audio published on `~inject_audio` into the analysis instead of
+/// recording, e.g. to replay `~audio` or `~sss/audio` from a bag.
+///
+/// The audio is expected in the format this node publishes with the current
+/// config, i.e. `output/audio/format`, `recording/rate` and
+/// `recording/channels`.
+fn inject(updating_config: Updating<Config>, fanout: FanOut) -> Result<rosrust::Subscriber> {
+    let cycle = AtomicU32::new(1);
+    rosrust::subscribe("~inject_audio", 10, move |msg: msgs::AudioData| {
+        let (format, rate, channels) = {
+            let config = updating_config.read();
+            (config.audio_publish_format, config.rate, config.channels)
+        };
+        let audio = Audio::from_interleaved(
+            rate.into(),
+            channels.into(),
+            audio::decode(format, &msg.data),
+        );
+        let seq = cycle.fetch_add(1, atomic::Ordering::SeqCst);
+        // blocks instead of dropping, to analyze all injected audio
+        fanout.queue((rosrust::now(), seq, audio));
+    })
+}
+
+/// Feeds the audio published on `audio_topic`/`audio_stamped`, or
+/// `audio_topic`/`audio` without `audio_message_stamped`, into the analysis,
+/// decoded as described on `audio_topic`/`audio_info`. The subscriptions last
+/// as long as the returned subscribers.
+///
+/// Audio with other channels or rate than `config` is skipped.
+fn listen(
+    audio_topic: &str,
+    config: &Config,
+    updating_config: &Updating<Config>,
+    diagnostics: &Arc<Diagnostics>,
+    fanout: &FanOut,
+    cycle: &Arc<AtomicU32>,
+) -> Result<[rosrust::Subscriber; 2]> {
+    let (channels, rate) = (config.channels, config.rate);
+    let audio_info_topic = format!("{audio_topic}/audio_info");
+    let audio_info = Arc::new(Mutex::new(None));
+    let recorded_with = Arc::new(Mutex::new(None));
+    let audio_info_subscriber = {
+        let audio_info = audio_info.clone();
+        let audio_info_topic = audio_info_topic.clone();
+        rosrust::subscribe(
+            &audio_info_topic.clone(),
+            1,
+            move |info: msgs::AudioInfo| {
+                ros_warn!("`{audio_info_topic} = {info:?}");
+                if info.coding_format != "wave" {
+                    ros_err!(
+                        "unsuported coding_format: `{}`, only `wave` is supported.",
+                        info.coding_format
+                    );
+                    return;
+                }
+                if info.channels as u16 != channels || info.sample_rate != u32::from(rate) {
+                    ros_warn!(
+                        "`{audio_info_topic}` has {} channels at {} Hz, but {} \
+                         channels at {} Hz are configured, skipping its audio",
+                        info.channels,
+                        info.sample_rate,
+                        channels,
+                        rate
+                    );
+                }
+                *audio_info.try_lock_for(Duration::from_secs(1)).unwrap() = Some(info);
+            }
+        )?
+    };
+    // let timestamp = Arc::new(Mutex::new(rosrust::now()));
+    // let audio_data = Arc::new(Mutex::new(Vec::<u8>::new()));
+    let feed = {
+        let audio_info = audio_info.clone();
+        let cycle = cycle.clone();
+        let diagnostics = diagnostics.clone();
+        let fanout = fanout.clone();
+        let recorded_with = recorded_with.clone();
+        let updating_config = updating_config.clone();
+        move |stamp: Time, data: &[u8]| {
+            let Some(audio_info) = audio_info.try_lock_for(Duration::from_secs(1)).unwrap().clone()
+            else {
+                ros_warn_throttle!(1., "`{audio_info_topic}` not yet recieved");
+                return;
+            };
+            // let mut audio_data = audio_data.try_lock_for(Duration::from_secs(1)).unwrap();
+            let mut recorded_with = recorded_with.try_lock_for(Duration::from_secs(1)).unwrap();
+            if recorded_with.is_none() {
+                *recorded_with = Some(audio_info.clone());
+            };
+            // let len = { updating_config.read().localisation_frame };
+            let rec_with = recorded_with.as_ref().unwrap();
+            // TODO make recording possible using longer time frames than sender
+            // if /* (&audio_info != rec_with
+                // || audio_data.len() as F
+                //     >= rec_with.sample_rate as F
+                //         * len
+                //         * rec_with.channels as F
+                //         * sample_format.bytes() as F)
+                // &&*/ // !audio_data.is_empty()
+            {
+                // some publishers send complete WAV files instead of raw samples
+                let audio = if data.starts_with(b"RIFF") {
+                    match wav::Wav::parse(data) {
+                        Ok(wav) => wav.audio(0, wav.samples()),
+                        Err(err) => {
+                            ros_warn_throttle!(1., "skipping invalid WAV audio message: {err}");
+                            return;
+                        }
+                    }
+                } else {
+                    match audio::from_pcm_bytes(
+                        &rec_with.sample_format,
+                        audio_info.sample_rate.into(),
+                        audio_info.channels.into(),
+                        data,
+                    ) {
+                        Ok(audio) => audio,
+                        Err(err) => {
+                            ros_err!(
+                                "Unsupported sample_format `{}`: {err:?}",
+                                rec_with.sample_format
+                            );
+                            return;
+                        }
+                    }
+                };
+                if audio.channels() != usize::from(channels)
+                    || audio.sample_rate() != F::from(rate)
+                {
+                    ros_warn_throttle!(
+                        1.,
+                        "skipping audio message with {} channels at {} Hz, \
+                         expected {} channels at {} Hz",
+                        audio.channels(),
+                        audio.sample_rate(),
+                        channels,
+                        rate
+                    );
+                    return;
+                }
+                let audio = audio.amplify(&updating_config.read().channel_gains);
+                diagnostics.recorded();
+                let seq = cycle.fetch_add(1, atomic::Ordering::SeqCst);
+                fanout.send((stamp, seq, audio), &diagnostics);
+                // audio_data.clear();
+                // *recorded_with = Some(audio_info);
+                // *timestamp.try_lock_for(Duration::from_secs(1)).unwrap() = stamp;
+            }
+            // audio_data.extend_from_slice(data)
+        }
+    };
+    #[cfg(feature = "audio_common_msgs-stamped")]
+    if config.audio_message_stamped {
+        let audio_stamped_subscriber = rosrust::subscribe(
+            &format!("{audio_topic}/audio_stamped"),
+            20,
+            move |msg: msgs::AudioDataStamped| feed(msg.header.stamp, &msg.audio.data),
+        )?;
+        return Ok([audio_info_subscriber, audio_stamped_subscriber]);
+    }
+    // e.g. from `audio_capture` without `audio_stamped`, or this node's `~audio`
+    let audio_subscriber = rosrust::subscribe(
+        &format!("{audio_topic}/audio"),
+        20,
+        move |msg: msgs::AudioData| feed(rosrust::now(), &msg.data),
+    )?;
+    Ok([audio_info_subscriber, audio_subscriber])
+}
+
 fn recorder(
     updating_config: Updating<Config>,
-    #[cfg_attr(not(feature = "audio_common_msgs-stamped"), allow(unused))] frame_id: String,
-    audio_channel_send: Sender<(Time, Audio)>,
-    audio_channel_recv: Receiver<(Time, Audio)>,
+    tap: capture::Tap,
+    diagnostics: Arc<Diagnostics>,
+    fanout: FanOut,
+    watchdog: Arc<Watchdog>,
     recording_only: bool,
 ) -> impl FnOnce() -> Result {
     move || {
+        // replaced by a new recorder once the watchdog finds this one blocked
+        let generation = watchdog.generation();
         let audio_topic = rosrust::publish::<msgs::AudioData>("~audio", 10)?;
         #[cfg(feature = "audio_common_msgs-stamped")]
         let audio_stamped_topic = rosrust::publish::<msgs::AudioDataStamped>("~audio_stamped", 10)?;
@@ -189,140 +738,147 @@ fn recorder(
         audio_info_topic.set_latching(true);
 
         let mut config = updating_config.copy();
+        let mut debounce = Debounce::default();
+        // numbers recordings, published as header seq to detect dropped cycles on `heartbeat`,
+        // which carries every handled one, unlike the outputs of skipped recordings; starts at
+        // 1 as publishers replace 0 with their own counter
+        let cycle = Arc::new(AtomicU32::new(1));
+        // format recorded in instead of the requested one the device rejected
+        let mut fallback: Option<(Format, Format)> = None;
+        // rate recorded at instead of the requested one the device lacks, resampled to it
+        let mut rate_fallback: Option<(u16, u16)> = None;
+        let mut backoff = Backoff::default();
+        // `recording/record_to_file`, kept across restarts while rate and channels match
+        let mut sink: Option<wav::WavWriter> = None;
         'recorder: while rosrust::is_ok() {
-            if let Some(audio_topic) = config.audio_message_topic.clone() {
-                let audio_channel_send = audio_channel_send.clone();
-                let audio_channel_recv = audio_channel_recv.clone();
-                let audio_info_topic = format!("{audio_topic}/audio_info");
-                let audio_stamped_topic = format!("{audio_topic}/audio_stamped");
-                let audio_info = Arc::new(Mutex::new(None));
-                let recorded_with = Arc::new(Mutex::new(None));
-                let _audio_info_subscriber = {
-                    let audio_info = audio_info.clone();
-                    let audio_info_topic = audio_info_topic.clone();
-                    continue_error!(
-                        rosrust::subscribe(
-                            &audio_info_topic.clone(),
-                            1,
-                            move |info: msgs::AudioInfo| {
-                                ros_warn!("`{audio_info_topic} = {info:?}");
-                                if info.coding_format != "wave" {
-                                    ros_err!(
-                                        "unsuported coding_format: `{}`, only `wave` is supported.",
-                                        info.coding_format
-                                    );
-                                    return;
-                                }
-                                if info.channels as u16 != config.channels {
-                                    todo!("missmatched channel count")
-                                }
-                                *audio_info.try_lock_for(Duration::from_secs(1)).unwrap() =
-                                    Some(info);
+            if matches!(config.input_source, InputSource::File | InputSource::Mock) {
+                let frame_duration = config.localisation_frame;
+                let frame = (frame_duration * F::from(config.rate)) as usize;
+                let mut source = if config.input_source == InputSource::File {
+                    match wav::Wav::read(&config.input_file) {
+                        Ok(wav) if wav.channels != config.channels => {
+                            ros_err!(
+                                "`{}` has {} channels, but {} are configured",
+                                config.input_file,
+                                wav.channels,
+                                config.channels
+                            );
+                            None
+                        }
+                        Ok(mut wav) => {
+                            if wav.rate != u32::from(config.rate) {
+                                ros_warn!(
+                                    "`{}` is sampled at {} Hz instead of the configured {} Hz, \
+                                     resampling it",
+                                    config.input_file,
+                                    wav.rate,
+                                    config.rate
+                                );
+                                wav = wav.resample(config.rate.into());
                             }
-                        ),
-                        "{err}"
-                    )
-                };
-                // let timestamp = Arc::new(Mutex::new(rosrust::now()));
-                // let audio_data = Arc::new(Mutex::new(Vec::<u8>::new()));
-                let _audio_stamped_recorder = {
-                    let audio_info = audio_info.clone();
-                    let recorded_with = recorded_with.clone();
-                    // let updating_config = updating_config.clone();
-                    continue_error!(
-                        rosrust::subscribe(
-                            &audio_stamped_topic,
-                            20,
-                            move |msg: msgs::AudioDataStamped| {
-                                let Some(audio_info) = audio_info
-                                        .try_lock_for(Duration::from_secs(1))
-                                        .unwrap()
-                                        .clone()
-                                else {
-                                    ros_warn_throttle!(1., "`{audio_info_topic}` not yet recieved");
-                                    return;
-                                };
-                                // let mut audio_data =
-                                //     audio_data.try_lock_for(Duration::from_secs(1)).unwrap();
-                                let mut recorded_with =
-                                    recorded_with.try_lock_for(Duration::from_secs(1)).unwrap();
-                                if recorded_with.is_none() {
-                                    *recorded_with = Some(audio_info.clone());
-                                };
-                                // let len = { updating_config.read().localisation_frame };
-                                let rec_with = recorded_with.as_ref().unwrap();
-                                let sample_format: PcmFormat = match rec_with.sample_format.parse()
-                                {
-                                    Ok(ok) => ok,
-                                    Err(err) => {
-                                        ros_err!(
-                                            "Unsupported sample_format `{}`: {err:?}",
-                                            recorded_with.as_ref().unwrap().sample_format
-                                        );
-                                        return;
-                                    }
-                                };
-                                // TODO make recording possible using longer time frames than
-                                // sender
-                                // if /* (&audio_info != rec_with
-                                    // || audio_data.len() as F
-                                    //     >= rec_with.sample_rate as F
-                                    //         * len
-                                    //         * rec_with.channels as F
-                                    //         * sample_format.bytes() as F)
-                                    // &&*/ // !audio_data.is_empty()
-                                {
-                                    if audio_channel_send.is_full() {
-                                        match audio_channel_recv.try_recv() {
-                                            Ok((stamp, _)) => {
-                                                ros_warn!(
-                                                    "recording from {stamp} was dropped, ssloc \
-                                                     operation too slow"
-                                                );
-                                            }
-                                            Err(TryRecvError::Empty) => { /* was emptied by consumer */
-                                            }
-                                            Err(TryRecvError::Disconnected) => {
-                                                ros_err!(
-                                                    "channel disconnected, process must have \
-                                                     exited"
-                                                );
-                                                return;
-                                            }
-                                        }
-                                    }
-                                    let audio = Audio::from_pcm_bytes(
-                                        sample_format,
-                                        audio_info.sample_rate.into(),
-                                        audio_info.channels.into(),
-                                        &msg.audio.data,
-                                    );
-                                    match audio_channel_send.send((msg.header.stamp, audio)) {
-                                        Ok(_) => {}
-                                        Err(_) => {
-                                            ros_err!(
-                                                "channel disconnected, process must have exited"
-                                            );
-                                        }
-                                    }
-                                    // audio_data.clear();
-                                    // *recorded_with = Some(audio_info);
-                                    // *timestamp.try_lock_for(Duration::from_secs(1)).unwrap() =
-                                    //     msg.header.stamp;
-                                }
-                                // audio_data.extend_from_slice(&msg.audio.data)
-                            },
-                        ),
-                        "error subscribing to `audio_message_topic` {} {err}",
-                        audio_topic
-                    )
+                            ros_info!("replaying `{}`", config.input_file);
+                            Some(Replay::File(FileReplay::new(wav, frame, config.input_file_loop)))
+                        }
+                        Err(err) => {
+                            ros_err!("error reading `{}`: {err}", config.input_file);
+                            None
+                        }
+                    }
+                } else {
+                    let direction = ssloc::Direction::new(
+                        config.input_mock_azimuth,
+                        config.input_mock_elevation,
+                    );
+                    let mics = config.mics[..config.channels.into()].iter().map(|(mic, _)| *mic);
+                    ros_info!(
+                        "synthesizing a source at {:.1}° azimuth and {:.1}° elevation",
+                        direction.azimuth.to_degrees(),
+                        direction.elevation.to_degrees()
+                    );
+                    // one second of a continuous signal, replayed in a loop
+                    Some(Replay::Mock(MockRecorder::tone(
+                        &mics.collect_vec(),
+                        config.rate.into(),
+                        config.mbss.speed_of_sound,
+                        direction,
+                        frame,
+                        (frame_duration.recip().ceil() as usize).max(1),
+                    )))
                 };
+                // stamps advance by exactly one frame, replay is paced to real time
+                let start = (rosrust::now(), Instant::now());
+                let mut frames = 0u32;
+                while rosrust::is_ok() {
+                    {
+                        let update = updating_config.read();
+                        if update.capture() != config.capture()
+                            && debounce.settled(
+                                &update.capture(),
+                                update.debounce(),
+                                update.rebuild_interval(),
+                            )
+                        {
+                            config = update.clone();
+                            continue 'recorder;
+                        }
+                        config.channel_gains.clone_from(&update.channel_gains);
+                        config.stamp_at = update.stamp_at;
+                    }
+                    // idles until a capture change replays it again
+                    let Some(replay) = &mut source else {
+                        thread::sleep(Duration::from_millis(100));
+                        continue;
+                    };
+                    let audio = match replay.record() {
+                        Ok(audio) => audio.amplify(&config.channel_gains),
+                        Err(ReplayEnd::Finished) => {
+                            ros_info!("finished replaying `{}`", config.input_file);
+                            source = None;
+                            continue;
+                        }
+                        Err(err) => {
+                            ros_err!("error replaying `{}`: {err}", config.input_file);
+                            source = None;
+                            continue;
+                        }
+                    };
+                    tap.send(&audio);
+                    let offset = F::from(frames) * frame_duration;
+                    frames += 1;
+                    let instant = offset + config.stamp_at.fraction() * frame_duration;
+                    let stamp = Time::from_nanos(start.0.nanos() + (instant * 1e9) as i64);
+                    let elapsed = start.1.elapsed();
+                    if let Some(wait) = Duration::from_secs_f64(offset).checked_sub(elapsed) {
+                        sleep(wait);
+                    }
+                    if !recording_only {
+                        diagnostics.recorded();
+                        let seq = cycle.fetch_add(1, atomic::Ordering::SeqCst);
+                        if !fanout.queue((stamp, seq, audio)) {
+                            return Ok(());
+                        }
+                    }
+                }
+            } else if let Some(audio_topic) = config.audio_message_topic.clone() {
+                let _subscribers = continue_error!(
+                    listen(&audio_topic, &config, &updating_config, &diagnostics, &fanout, &cycle),
+                    "error subscribing to `audio_message_topic` {audio_topic} {err}"
+                );
                 let rate = rosrust::rate(10.0);
                 while rosrust::is_ok() {
                     rate.sleep();
                     let update = updating_config.read();
-                    if config.audio_message_topic != update.audio_message_topic
+                    // other capture parameters do not apply to audio messages
+                    if (config.audio_message_topic != update.audio_message_topic
+                        || config.audio_message_stamped != update.audio_message_stamped
+                        || config.input_source != update.input_source
                         || config.channels != update.channels
+                        || config.rate != update.rate)
+                        && debounce.settled(
+                            &update.capture(),
+                            update.debounce(),
+                            update.rebuild_interval(),
+                        )
                     {
                         config = update.clone();
                         continue 'recorder;
@@ -330,67 +886,232 @@ fn recorder(
                 }
             } else {
                 log_error!(
-                    audio_info_topic.send(msgs::AudioInfo {
-                        channels: config.channels as u8,
-                        sample_rate: config.rate.into(),
-                        sample_format: "F32LE".into(),
-                        bitrate: (size_of::<f32>() as u32) * 8 * config.rate as u32,
-                        coding_format: "wave".into(),
-                    }),
+                    audio_info_topic.send(audio_info(
+                        config.channels,
+                        config.rate,
+                        config.audio_publish_format
+                    )),
                     "error sending audio info message {err}"
                 );
-                for_format!(config.format, {
+                let format = match fallback {
+                    Some((requested, fallback)) if requested == config.format => fallback,
+                    _ => config.format,
+                };
+                let rate = match rate_fallback {
+                    Some((requested, fallback)) if requested == config.rate => fallback,
+                    // ALSA would silently record at the nearest rate instead
+                    _ if !config.device.supports_rate(config.rate) => {
+                        match config.device.nearest_rate(config.rate) {
+                            Some(nearest) => {
+                                ros_warn!(
+                                    "device does not support {} Hz, recording at {nearest} Hz \
+                                     and resampling",
+                                    config.rate
+                                );
+                                rate_fallback = Some((config.rate, nearest));
+                                nearest
+                            }
+                            None => config.rate,
+                        }
+                    }
+                    _ => config.rate,
+                };
+                for_format!(format, {
                     let mut recorder = match AudioRecorder::<FORMAT>::new(
                         config.device.name.clone(),
-                        config.channels.into(),
-                        config.rate.into(),
-                        config.format,
+                        config.device_channels().into(),
+                        rate.into(),
+                        format,
                         config.localisation_frame,
                     ) {
-                        Ok(recorder) => recorder,
+                        Ok(recorder) => {
+                            tap.set_error(None);
+                            recorder
+                        }
+                        // EINVAL: the device is available but rejected the parameters
+                        Err(e)
+                            if config.allow_format_fallback
+                                && format == config.format
+                                && e.errno() == alsa::nix::Error::EINVAL =>
+                        {
+                            let Some(nearest) = config.device.nearest_format(format) else {
+                                let error = format!("error creating the audio recorder {e}");
+                                retry(
+                                    &mut backoff,
+                                    error,
+                                    &tap,
+                                    &updating_config,
+                                    &config.capture(),
+                                );
+                                continue;
+                            };
+                            ros_warn!(
+                                "device rejected format {format} ({e}), recording {nearest} \
+                                 instead"
+                            );
+                            fallback = Some((format, nearest));
+                            continue;
+                        }
+                        Err(e) if rate == config.rate && e.errno() == alsa::nix::Error::EINVAL => {
+                            let Some(nearest) = config.device.nearest_rate(rate) else {
+                                let error = format!("error creating the audio recorder {e}");
+                                retry(
+                                    &mut backoff,
+                                    error,
+                                    &tap,
+                                    &updating_config,
+                                    &config.capture(),
+                                );
+                                continue;
+                            };
+                            ros_warn!(
+                                "device rejected {rate} Hz ({e}), recording at {nearest} Hz and \
+                                 resampling"
+                            );
+                            rate_fallback = Some((rate, nearest));
+                            continue;
+                        }
                         Err(e) => {
-                            ros_err!("error creating the audio recorder {e}");
-                            thread::sleep(Duration::from_secs(1));
+                            let error = format!("error creating the audio recorder {e}");
+                            retry(&mut backoff, error, &tap, &updating_config, &config.capture());
                             continue;
                         }
                     };
+                    // recorded through `AudioSource` like the replayed sources
+                    let recorder: &mut dyn AudioSource<Error = alsa::Error> = &mut recorder;
+                    // the first recordings after opening are often still settling
+                    for _ in 0..config.warmup_frames {
+                        let Some(recorded) = watchdog.watch(generation, || recorder.record())
+                        else {
+                            ros_info!("exiting the recorder replaced while blocked");
+                            return Ok(());
+                        };
+                        if let Err(err) = recorded {
+                            let error = format!("error recording audio {err}");
+                            retry(&mut backoff, error, &tap, &updating_config, &config.capture());
+                            continue 'recorder;
+                        }
+                    }
 
                     while rosrust::is_ok() {
-                        let stamp = rosrust::now();
-                        #[cfg(feature = "audio_common_msgs-stamped")]
-                        let header = msgs::Header {
-                            stamp,
-                            frame_id: frame_id.clone(),
-                            ..Default::default()
-                        };
                         {
                             let update = updating_config.read();
-                            if update.channels != config.channels
-                                || update.device != config.device
-                                || update.rate != config.rate
-                                || update.format != config.format
-                                || update.localisation_frame != config.localisation_frame
-                                || update.audio_message_topic.is_some()
+                            if update.capture() != config.capture()
+                                && debounce.settled(
+                                    &update.capture(),
+                                    update.debounce(),
+                                    update.rebuild_interval(),
+                                )
                             {
                                 config = update.clone();
                                 continue 'recorder;
                             }
+                            if update.record_to_file != config.record_to_file {
+                                config.record_to_file = update.record_to_file.clone();
+                            }
+                            config.frame_id.clone_from(&update.frame_id);
+                            config.channel_gains.clone_from(&update.channel_gains);
+                            config.stamp_at = update.stamp_at;
+                            if update.audio_publish_format != config.audio_publish_format {
+                                config.audio_publish_format = update.audio_publish_format;
+                                log_error!(
+                                    audio_info_topic.send(audio_info(
+                                        config.channels,
+                                        config.rate,
+                                        config.audio_publish_format
+                                    )),
+                                    "error sending audio info message {err}"
+                                );
+                            }
                         }
-                        let audio = match recorder.record() {
+                        let Some(recorded) = watchdog.watch(generation, || recorder.record())
+                        else {
+                            ros_info!("exiting the recorder replaced while blocked");
+                            return Ok(());
+                        };
+                        let audio = match recorded {
+                            Ok(audio) if !config.channel_map.is_empty() => {
+                                audio.select_channels(&config.channel_map)
+                            }
                             Ok(audio) => audio,
                             Err(err) => {
-                                ros_err!("error recording audio {err}");
+                                let error = format!("error recording audio {err}");
+                                retry(
+                                    &mut backoff,
+                                    error,
+                                    &tap,
+                                    &updating_config,
+                                    &config.capture(),
+                                );
                                 continue 'recorder;
                             }
                         };
+                        // `record` returns once the last sample is captured
+                        let duration = audio.samples() as F / audio.sample_rate();
+                        let stamp = rosrust::now()
+                            - rosrust::Duration::from_nanos(
+                                ((1. - config.stamp_at.fraction()) * duration * 1e9) as i64,
+                            );
+                        #[cfg(feature = "audio_common_msgs-stamped")]
+                        let header = msgs::Header {
+                            stamp,
+                            frame_id: config.frame_id.clone(),
+                            ..Default::default()
+                        };
+                        let audio = if rate == config.rate {
+                            audio
+                        } else {
+                            audio.resample(config.rate.into())
+                        }
+                        .amplify(&config.channel_gains);
+                        let failures = backoff.succeeded();
+                        if failures > 0 {
+                            ros_info!(
+                                "recording from `{}` resumed after {failures} failed attempts",
+                                config.device.name
+                            );
+                        }
+                        tap.send(&audio);
+                        if config.record_to_file.is_empty() {
+                            sink = None;
+                        } else {
+                            if !sink
+                                .as_ref()
+                                .is_some_and(|sink| sink.accepts(&config.record_to_file, &audio))
+                            {
+                                // finalize the previous file before truncating a new one
+                                drop(sink.take());
+                                sink = wav::WavWriter::create(
+                                    &config.record_to_file,
+                                    audio.sample_rate() as u32,
+                                    audio.channels() as u16,
+                                )
+                                .map_err(|err| {
+                                    ros_err_throttle!(
+                                        10.,
+                                        "error creating `{}`: {err}",
+                                        config.record_to_file
+                                    );
+                                })
+                                .ok();
+                            }
+                            if let Some(writer) = &mut sink {
+                                if let Err(err) = writer.write(&audio) {
+                                    ros_err_throttle!(
+                                        10.,
+                                        "error writing `{}`: {err}",
+                                        config.record_to_file
+                                    );
+                                }
+                            }
+                        }
                         #[cfg_attr(feature = "audio_common_msgs-stamped", allow(unused))]
                         let subbed = audio_topic.has_subscribers();
                         #[cfg(feature = "audio_common_msgs-stamped")]
                         let subbed = audio_stamped_topic.has_subscribers();
                         if subbed {
-                            // TODO consider supporting more than one output format.
                             let msg = msgs::AudioData {
-                                data: audio.to_interleaved().flat_map(f32::to_le_bytes).collect(),
+                                data: audio.encode(config.audio_publish_format),
                             };
                             #[cfg(feature = "audio_common_msgs-stamped")]
                             log_error!(
@@ -403,28 +1124,9 @@ fn recorder(
                             log_error!(audio_topic.send(msg), "error sending audio message {err}");
                         }
                         if !recording_only {
-                            if audio_channel_send.is_full() {
-                                match audio_channel_recv.try_recv() {
-                                    Ok((stamp, _)) => {
-                                        ros_warn!(
-                                            "recording from {stamp} was dropped, ssloc operation \
-                                             too slow"
-                                        );
-                                    }
-                                    Err(TryRecvError::Empty) => { /* was emptied by consumer */ }
-                                    Err(TryRecvError::Disconnected) => {
-                                        ros_err!("channel disconnected, process must have exited");
-                                        return Ok(());
-                                    }
-                                }
-                            }
-                            match audio_channel_send.try_send((stamp, audio)) {
-                                Ok(_) => {}
-                                Err(_) => {
-                                    ros_err!("channel disconnected, process must have exited");
-                                    return Ok(());
-                                }
-                            }
+                            diagnostics.recorded();
+                            let seq = cycle.fetch_add(1, atomic::Ordering::SeqCst);
+                            fanout.send((stamp, seq, audio), &diagnostics);
                         }
                     }
                 });
@@ -434,29 +1136,97 @@ fn recorder(
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-struct Track {
-    direction: Direction,
-    stamp: rosrust::Time,
-    power: F,
-    id: i64,
-    channel: Option<usize>,
+/// Id of the arrow marker of track `id`, markers are keyed by `ns` and `id`.
+fn marker_id(id: i64) -> i32 {
+    (id.rem_euclid(i64::from(i32::MAX)) + 1) as i32
+}
+
+/// One dimensional array of a marginal spectrum along `label`.
+fn marginal_array(label: &str, marginal: Array1<F>) -> msgs::Float32MultiArray {
+    msgs::Float32MultiArray {
+        layout: msgs::MultiArrayLayout {
+            dim: vec![msgs::MultiArrayDimension {
+                label: label.to_owned(),
+                size: marginal.len() as u32,
+                stride: marginal.len() as u32,
+            }],
+            data_offset: 0,
+        },
+        data: marginal.iter().map(|&v| v as f32).collect(),
+    }
+}
+
+/// Picks the track a single-target output reports out of `sources` sorted by
+/// power, `last` is the id reported previously.
+fn follow(sources: &[Track], follow: Follow, last: &mut Option<i64>) -> Option<Track> {
+    let track = match follow {
+        Follow::Tracked => last
+            .and_then(|id| sources.iter().find(|track| track.id == id))
+            .or(sources.first()),
+        Follow::Strongest => sources.first(),
+    };
+    *last = track.map(|track| track.id);
+    track.copied()
 }
 
 fn ssloc(
     updating_config: Updating<Config>,
-    frame_id: String,
-    audio_channel_recv: Receiver<(Time, Audio)>,
+    diagnostics: Arc<Diagnostics>,
+    audio_channel_recv: Receiver<Recording>,
 ) -> impl FnOnce() -> Result {
     move || {
         let arrow_markers = rosrust::publish::<msgs::Marker>("~arrow_markers", 20)?;
+        let tf = rosrust::publish::<msgs::TFMessage>("/tf", 100)?;
+        let heartbeat = rosrust::publish::<msgs::Header>("~heartbeat", 10)?;
+        // published sources per recording, 0 for those the VAD or silence gate skip;
+        // unstamped, but sent along with the stamped outputs
+        let source_count = rosrust::publish::<msgs::UInt32>("~source_count", 20)?;
+        // milliseconds of the spectrum and source search, and recordings still queued for the
+        // analysis, of every analyzed recording
+        let mbss_ms = rosrust::publish::<msgs::Float64>("~stats/mbss_ms", 20)?;
+        let queue_depth = rosrust::publish::<msgs::UInt32>("~stats/queue_depth", 20)?;
+        let count = |sources: usize| {
+            log_error!(
+                source_count.send(msgs::UInt32 { data: sources as u32 }),
+                "error sending source count {err}"
+            );
+        };
+        // liveness beacon for every recording handled, its absence signals a stall
+        let beat = |seq: u32, frame_id: &str| {
+            log_error!(
+                heartbeat.send(msgs::Header {
+                    seq,
+                    stamp: rosrust::now(),
+                    frame_id: frame_id.to_owned(),
+                }),
+                "error sending heartbeat {err}"
+            );
+        };
+        // a plain number e.g. for `rqt_plot`, the only output without header and seq
+        let heading = rosrust::publish::<msgs::Float32>("~heading", 20)?;
+        let dominant_source = rosrust::publish::<msgs::PoseStamped>("~dominant_source", 20)?;
+        let detections = rosrust::publish::<msgs::Detections>("~detections", 20)?;
         let unit_sphere_sst = rosrust::publish::<msgs::SstArray>("~sst", 20)?;
         let unit_sphere_sst_poses =
             rosrust::publish::<msgs::PoseArray>("~sst/poses", 20)?;
+        let source_poses = rosrust::publish::<msgs::PoseArray>("~sources", 20)?;
+        // strengths of the `sources` by index, both strongest first
+        let source_strengths =
+            rosrust::publish::<msgs::Float64MultiArray>("~source_strengths", 20)?;
         let unit_sphere_ssl = rosrust::publish::<msgs::SslArray>("~ssl", 20)?;
         let unit_sphere_ssl_points =
             rosrust::publish::<msgs::PointCloud2>("~ssl/points", 20)?;
+        let candidates = rosrust::publish::<msgs::SslArray>("~debug/candidates", 20)?;
         let spectrums = rosrust::publish::<msgs::CompressedImage>("~intensity/compressed", 20)?;
+        let spectrogram_image = rosrust::publish::<msgs::CompressedImage>("~spectrogram", 20)?;
+        // the spectrum with the sources of the same recording marked
+        let ssl_map = rosrust::publish::<msgs::CompressedImage>("~ssl_map", 20)?;
+        let raw_spectrums = rosrust::publish::<msgs::Image>("~intensity", 20)?;
+        let spectrum_array = rosrust::publish::<msgs::Float32MultiArray>("~intensity/array", 20)?;
+        let azimuth_marginal =
+            rosrust::publish::<msgs::Float32MultiArray>("~intensity/azimuth", 20)?;
+        let elevation_marginal =
+            rosrust::publish::<msgs::Float32MultiArray>("~intensity/elevation", 20)?;
         let mut sss_mapping = rosrust::publish::<msgs::SssMapping>("~sss/mapping", 10)?;
         let sss_audio_topic = rosrust::publish::<msgs::AudioData>("~sss/audio", 10)?;
         #[cfg(feature = "audio_common_msgs-stamped")]
@@ -465,6 +1235,10 @@ fn ssloc(
         let mut sss_audio_info_topic = rosrust::publish::<msgs::AudioInfo>("~sss/audio_info", 10)?;
         sss_audio_info_topic.set_latching(true);
         sss_mapping.set_latching(true);
+        let beamformed_audio = rosrust::publish::<msgs::AudioData>("~beamformed_audio", 10)?;
+        let mut beamformed_audio_info =
+            rosrust::publish::<msgs::AudioInfo>("~beamformed_audio_info", 10)?;
+        beamformed_audio_info.set_latching(true);
 
         #[cfg(feature = "odas-msgs")]
         let odas_unit_sphere_sst = rosrust::publish::<msgs::OdasSstArrayStamped>("~odas/sst", 10)?;
@@ -478,67 +1252,372 @@ fn ssloc(
             rosrust::publish::<msgs::PointCloud2>("~odas/ssl_pcl2", 10)?;
 
         let mut config = updating_config.copy();
+        let mut debounce = Debounce::default();
 
-        let last_tracks: Vec<Track> = Vec::new();
-        let last_tracks = Arc::new(Mutex::new(last_tracks));
-
-        // continuiously increases and will wrap at some point
-        let track_index = AtomicI64::default();
+        let mut tracker = Tracker::default();
+        let mut beam_direction = None;
+        // rate and format last sent on the latched `beamformed_audio_info`
+        let mut beamformed_info = None;
+        // track last reported on `heading`
+        let mut heading_id = None;
+        let mut dominant_id = None;
+        // ids of the arrow markers published last cycle, stale ones are deleted
+        let mut marker_ids = Vec::new();
+        let mut vad = Vad::default();
+        let mut silence = SilenceGate::default();
+        let mut overlap = Overlap::default();
+        // reused to encode `intensity/compressed`, the message only copies the final size
+        let mut encoded = Vec::new();
+        let mut noise_floor = NoiseFloor::default();
+        let mut spectrogram = Spectrogram::default();
+        // time of the last rebuild and the recordings queued then, which were
+        // recorded before the update and might not match it anymore
+        let mut stale: Option<(Time, usize)> = None;
+        let mut discarded_stale = 0;
 
         'mbss: while rosrust::is_ok() {
-            let mics = config.mics[..config.channels as usize]
-                .iter()
-                .filter(|(_, enabled)| *enabled)
-                .map(|(pos, _)| *pos)
-                .collect_vec();
-            let mbss = config.mbss.create(mics.clone());
+            let mics = config.mic_positions();
+            ros_info!(
+                "using mic geometry (imported {}-handed): {}",
+                config.geometry_handedness,
+                mics.iter()
+                    .map(|mic| format!("({:.3}, {:.3}, {:.3})", mic.x, mic.y, mic.z))
+                    .join(", ")
+            );
+            let band = config.band();
+            if band.is_none() && config.freq_min >= config.freq_max.min(F::from(config.rate) / 2.) {
+                ros_warn!(
+                    "`mbss/freq_min` {} Hz is not below `mbss/freq_max` {} Hz or nyquist, \
+                     analyzing all frequencies",
+                    config.freq_min,
+                    config.freq_max
+                );
+            }
+            let stft = config.stft();
+            if config.fft_size > 0 && !config.fft_size.is_power_of_two() {
+                ros_warn!(
+                    "`mbss/stft/size` {} is no power of two, rounded up to {}",
+                    config.fft_size,
+                    stft.0.unwrap_or_default()
+                );
+            }
+            let window = stft.0.unwrap_or_else(|| spectrum::window_len(F::from(config.rate)));
+            ros_info!(
+                "analyzing {window} sample {} windows, {:.1} ms long and {:.1} Hz apart",
+                stft.1,
+                window as F / F::from(config.rate) * 1000.,
+                F::from(config.rate) / window as F
+            );
+            if config.aliasing_warning {
+                // GCC-PHAT uses all frequencies up to nyquist
+                let max_frequency = band.map_or(F::from(config.rate) / 2., |(_, max)| max);
+                steering::warn_aliasing(&mics, config.mbss.speed_of_sound, max_frequency);
+            }
+            let mbss = config.analyzer().create(mics.clone());
+            let method = config.spectrum_method;
+            let subbands = match (config.subbands(), config.threads(), method, band, stft) {
+                (Some((bands, fusion)), ..) => Some(Subbands::new(
+                    config.analyzer(),
+                    &mics,
+                    method,
+                    band,
+                    stft,
+                    bands.into(),
+                    fusion,
+                )),
+                (None, None, SpectrumMethod::GccPhat, None, (None, Window::Sine)) => None,
+                // a single band sums all frequencies like `mbss`
+                _ => Some(Subbands::new(
+                    config.analyzer(),
+                    &mics,
+                    method,
+                    band,
+                    stft,
+                    1,
+                    Fusion::Sum,
+                )),
+            };
+            #[cfg(feature = "rayon")]
+            let subbands = subbands.map(|mut subbands| {
+                if let Some(threads) = config.threads() {
+                    log_error!(
+                        subbands.parallelize(threads),
+                        "error starting analysis threads {err}"
+                    );
+                }
+                subbands
+            });
+            #[cfg(not(feature = "rayon"))]
+            if config.num_threads != 1 {
+                ros_warn!("`mbss/num_threads` requires the `rayon` feature, analyzing serially");
+            }
             let das = DelayAndSum {
                 speed_of_sound: config.mbss.speed_of_sound,
                 mics,
                 ..Default::default()
             };
+            // recordings left out before the next analyzed one, the first
+            // after a rebuild is analyzed
+            let mut skip = 0;
             while rosrust::is_ok() {
                 {
                     let update = updating_config.read();
-                    if update.channels != config.channels
-                        || update.mics != config.mics
-                        || update.mbss != config.mbss
+                    if update.analysis() != config.analysis()
+                        && debounce.settled(
+                            &update.analysis(),
+                            update.debounce(),
+                            update.rebuild_interval(),
+                        )
                     {
                         config = update.clone();
+                        stale = Some((rosrust::now(), audio_channel_recv.len()));
                         continue 'mbss;
                     }
-                    config.max_sources = update.max_sources;
-                    config.tracking_persistence = update.tracking_persistence;
-                    config.mbss_ssl_threshold = update.mbss_ssl_threshold;
+                    config.apply_processing(&update);
                 };
-                let Ok((stamp, mut audio)) = audio_channel_recv.recv() else {
-                    ros_err!("channel disconnected, process must have exited");
-                    return Ok(());
+                let (stamp, seq, audio) = match audio_channel_recv.recv_timeout(SHUTDOWN_POLL)
+                {
+                    Ok(recording) => recording,
+                    // checks for shutdown
+                    Err(RecvTimeoutError::Timeout) => continue,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        if rosrust::is_ok() {
+                            ros_err!("channel disconnected, process must have exited");
+                        }
+                        return Ok(());
+                    }
+                };
+                // the queue is shared with the other analyzers, so only
+                // recordings stamped before the update are discarded, at most
+                // as many as were queued then
+                if let Some((since, queued)) = &mut stale {
+                    if stamp < *since && *queued > 0 {
+                        *queued -= 1;
+                        discarded_stale += 1;
+                        continue;
+                    }
+                    stale = None;
+                    if discarded_stale > 0 {
+                        ros_info!(
+                            "discarded {discarded_stale} recordings queued before the reconfigure"
+                        );
+                        discarded_stale = 0;
+                    }
+                }
+                let channels = usize::from(config.channels);
+                let (stamp, seq, audio) = if audio.channels() == channels {
+                    (stamp, seq, audio)
+                } else {
+                    // recordings queued before a channel change, discarded at once
+                    let mut discarded = 1;
+                    let fresh = loop {
+                        match audio_channel_recv.try_recv() {
+                            Ok((.., audio)) if audio.channels() != channels => discarded += 1,
+                            Ok(recording) => break Some(recording),
+                            Err(_) => break None,
+                        }
+                    };
+                    ros_info!(
+                        "discarded {discarded} recordings with {} instead of {channels} channels, \
+                         probably config was updated",
+                        audio.channels()
+                    );
+                    match fresh {
+                        Some(recording) => recording,
+                        None => continue,
+                    }
                 };
+                let received = Instant::now();
                 let header = msgs::Header {
+                    seq,
                     stamp,
-                    frame_id: frame_id.clone(),
-                    ..Default::default()
+                    frame_id: config.frame_id.clone(),
                 };
-                if audio.channels() != config.channels as usize {
-                    ros_info!("channels of recording missmatched, probably config was updated");
+                // the beamformed outputs leave out the overlap published before
+                let recorded = audio.samples();
+                let mut audio = overlap.apply(seq, audio, config.window_overlap);
+                // sheds load deterministically instead of by overflowing queues, after
+                // the overlap to keep it continuous for the analyzed recordings
+                if skip > 0 {
+                    skip -= 1;
+                    beat(seq, &config.frame_id);
+                    continue;
+                }
+                skip = config.process_every_n.max(1) - 1;
+                if config.vad_enabled
+                    && !vad.is_speech(
+                        &audio,
+                        |c| match config.vad_channel {
+                            -1 => config.mics[c].1,
+                            channel => channel as usize == c,
+                        },
+                        config.vad_aggressiveness,
+                    )
+                {
+                    count(0);
+                    beat(seq, &config.frame_id);
                     continue;
                 }
                 audio.retain_channels(|c| config.mics[c].1);
-                let spectrum = mbss.analyze_spectrum(&audio);
-                if spectrums.subscriber_count() > 0 {
-                    let mut data: Vec<u8> = Vec::new();
-                    ssloc::spec_to_image(spectrum.view())
-                        .write_to(&mut Cursor::new(&mut data), ImageOutputFormat::Png)
-                        .unwrap();
-                    log_error!(
-                        spectrums.send(msgs::CompressedImage {
-                            header: header.clone(),
-                            format: "png".to_string(),
-                            data,
-                        }),
-                        "error sending spectrum image {err}"
+                if silence.is_silent(audio.rms(), config.silence_threshold, config.silence_hold) {
+                    if !marker_ids.is_empty() {
+                        log_error!(
+                            arrow_markers.send(msgs::Marker {
+                                header: header.clone(),
+                                ns: "sslocate".to_string(),
+                                action: msgs::Marker::DELETEALL as i32,
+                                ..Default::default()
+                            }),
+                            "error deleting markers {err}"
+                        );
+                        marker_ids.clear();
+                    }
+                    count(0);
+                    beat(seq, &config.frame_id);
+                    continue;
+                }
+                if !spectrum::analyzable(&audio, stft.0) {
+                    ros_warn_throttle!(
+                        1.,
+                        "recording of {} samples is too short for the analysis window, skipping",
+                        audio.samples()
                     );
+                    count(0);
+                    beat(seq, &config.frame_id);
+                    continue;
+                }
+                let analysis = Instant::now();
+                let spectrum = match &subbands {
+                    Some(subbands) => subbands.analyze_spectrum(&audio),
+                    None => mbss.analyze_spectrum(&audio),
+                };
+                let mut analysis = analysis.elapsed();
+                if config.adaptive_threshold {
+                    // replaces the threshold until the next update from the server
+                    config.mbss_ssl_threshold = config.adaptive_threshold_multiplier
+                        * noise_floor.update(spectrum.view(), config.adaptive_threshold_rate);
+                }
+                let raw_subbed = config.spectrum_raw && raw_spectrums.has_subscribers();
+                if spectrums.has_subscribers() || raw_subbed {
+                    let mut image = colormap::spectrum_image(spectrum.view(), &config);
+                    if config.spectrum_flip_x {
+                        imageops::flip_horizontal_in_place(&mut image);
+                    }
+                    if config.spectrum_flip_y {
+                        imageops::flip_vertical_in_place(&mut image);
+                    }
+                    if spectrums.has_subscribers() {
+                        if let Err(err) = colormap::encode(&image, &config, &mut encoded) {
+                            ros_err!("error encoding spectrum image {err}");
+                        } else {
+                            log_error!(
+                                spectrums.send(msgs::CompressedImage {
+                                    header: header.clone(),
+                                    format: config.spectrum_format.to_string(),
+                                    data: encoded.clone(),
+                                }),
+                                "error sending spectrum image {err}"
+                            );
+                        }
+                    }
+                    if raw_subbed {
+                        let channels = u32::from(image.color().channel_count());
+                        log_error!(
+                            raw_spectrums.send(msgs::Image {
+                                header: header.clone(),
+                                height: image.height(),
+                                width: image.width(),
+                                encoding: if channels == 1 { "mono8" } else { "rgb8" }.to_string(),
+                                is_bigendian: 0,
+                                step: image.width() * channels,
+                                data: image.into_bytes(),
+                            }),
+                            "error sending raw spectrum image {err}"
+                        );
+                    }
+                }
+                // kept up to date without subscribers, so it is complete once subscribed
+                let reduction = config.spectrum_marginal_reduction;
+                let marginal = spectrum::marginal(spectrum.view(), Axis(0), reduction);
+                let history = spectrogram.push(marginal, config.spectrogram_frames.into());
+                if spectrogram_image.has_subscribers() {
+                    let mut image = colormap::spectrum_image(history.view(), &config);
+                    if config.spectrum_flip_y {
+                        imageops::flip_vertical_in_place(&mut image);
+                    }
+                    if let Err(err) = colormap::encode(&image, &config, &mut encoded) {
+                        ros_err!("error encoding spectrogram image {err}");
+                    } else {
+                        log_error!(
+                            spectrogram_image.send(msgs::CompressedImage {
+                                header: header.clone(),
+                                format: config.spectrum_format.to_string(),
+                                data: encoded.clone(),
+                            }),
+                            "error sending spectrogram image {err}"
+                        );
+                    }
+                }
+                let azimuth_subbed =
+                    config.spectrum_marginal_azimuth && azimuth_marginal.has_subscribers();
+                let elevation_subbed =
+                    config.spectrum_marginal_elevation && elevation_marginal.has_subscribers();
+                if spectrum_array.has_subscribers() || azimuth_subbed || elevation_subbed {
+                    let resampled;
+                    let spectrum = if let Some((elevations, azimuths)) = config.spectrum_grid() {
+                        resampled = spectrum::resample(
+                            spectrum.view(),
+                            &config.grid(),
+                            elevations,
+                            azimuths,
+                        );
+                        resampled.view()
+                    } else {
+                        spectrum.view()
+                    };
+                    let (rows, cols) = spectrum.dim();
+                    if spectrum_array.has_subscribers() {
+                        log_error!(
+                            spectrum_array.send(msgs::Float32MultiArray {
+                                layout: msgs::MultiArrayLayout {
+                                    dim: vec![
+                                        msgs::MultiArrayDimension {
+                                            label: "elevation".to_owned(),
+                                            size: rows as u32,
+                                            stride: (rows * cols) as u32,
+                                        },
+                                        msgs::MultiArrayDimension {
+                                            label: "azimuth".to_owned(),
+                                            size: cols as u32,
+                                            stride: cols as u32,
+                                        },
+                                    ],
+                                    data_offset: 0,
+                                },
+                                data: spectrum.iter().map(|&v| v as f32).collect(),
+                            }),
+                            "error sending spectrum array {err}"
+                        );
+                    }
+                    let reduction = config.spectrum_marginal_reduction;
+                    if azimuth_subbed {
+                        log_error!(
+                            azimuth_marginal.send(marginal_array(
+                                "azimuth",
+                                spectrum::marginal(spectrum, Axis(0), reduction)
+                            )),
+                            "error sending azimuth marginal {err}"
+                        );
+                    }
+                    if elevation_subbed {
+                        log_error!(
+                            elevation_marginal.send(marginal_array(
+                                "elevation",
+                                spectrum::marginal(spectrum, Axis(1), reduction)
+                            )),
+                            "error sending elevation marginal {err}"
+                        );
+                    }
                 }
 
                 let subbed =
@@ -548,16 +1627,22 @@ fn ssloc(
                     || odas_unit_sphere_ssl.has_subscribers()
                     || odas_unit_sphere_ssl_points.has_subscribers();
                 if subbed {
-                    let locations = mbss.spectrum(spectrum.view(), config.mbss_ssl_threshold);
+                    let locations = mbss
+                        .spectrum(spectrum.view(), config.mbss_ssl_threshold)
+                        .into_iter()
+                        .map(|(direction, p)| (config.output_direction(direction), p))
+                        .collect_vec();
+                    // empty arrays serve as heartbeat
+                    let publish = config.publish_empty || !locations.is_empty();
 
-                    if unit_sphere_ssl.has_subscribers() {
+                    if publish && unit_sphere_ssl.has_subscribers() {
                         log_error!(
                             unit_sphere_ssl.send(msgs::SslArray {
                                 header: header.clone(),
                                 sources: locations
                                     .iter()
                                     .map(|(direction, p)| {
-                                        let position = direction.to_unit_vec();
+                                        let position = config.unit_vec(*direction);
                                         msgs::Ssl {
                                             x: position.x,
                                             y: position.y,
@@ -572,15 +1657,15 @@ fn ssloc(
                             "error sending unit sphere ssl {err}"
                         );
                     }
-                    if unit_sphere_ssl.has_subscribers() {
-                        #[cfg(feature = "odas-msgs")]
+                    #[cfg(feature = "odas-msgs")]
+                    if publish && odas_unit_sphere_ssl.has_subscribers() {
                         log_error!(
                             odas_unit_sphere_ssl.send(msgs::OdasSslArrayStamped {
                                 header: header.clone(),
                                 sources: locations
                                     .iter()
                                     .map(|(direction, e)| {
-                                        let position = direction.to_unit_vec();
+                                        let position = config.unit_vec(*direction);
                                         msgs::OdasSsl {
                                             x: position.x,
                                             y: position.y,
@@ -594,7 +1679,11 @@ fn ssloc(
                         );
                     }
 
-                    if unit_sphere_ssl_points.has_subscribers() {
+                    let points_subbed = unit_sphere_ssl_points.has_subscribers();
+                    #[cfg(feature = "odas-msgs")]
+                    let points_subbed =
+                        points_subbed || odas_unit_sphere_ssl_points.has_subscribers();
+                    if publish && points_subbed {
                         let msg = msgs::PointCloud2 {
                             header: header.clone(),
                             fields: vec![
@@ -628,7 +1717,7 @@ fn ssloc(
                             data: locations
                                 .iter()
                                 .flat_map(|(direction, e)| {
-                                    let position = direction.to_unit_vec();
+                                    let position = config.unit_vec(*direction);
                                     [position.x, position.y, position.z, *e]
                                         .into_iter()
                                         .flat_map(|c| (c as f32).to_le_bytes())
@@ -636,7 +1725,7 @@ fn ssloc(
                                 .collect(),
                             height: 1,
                             width: locations.len() as u32,
-                            row_step: locations.len() as u32,
+                            row_step: locations.len() as u32 * 16,
                             is_dense: true,
                         };
                         #[cfg(feature = "odas-msgs")]
@@ -651,9 +1740,80 @@ fn ssloc(
                     }
                 }
 
+                // masked directions cannot take the place of in range sources
+                let masked = config
+                    .masking()
+                    .then(|| {
+                        spectrum::mask(spectrum.view(), &config.grid(), |direction| {
+                            config.unmasked(direction)
+                        })
+                    })
+                    .flatten();
+                let searched = masked.as_ref().map_or(spectrum.view(), Array2::view);
+
+                if config.debug_candidates {
+                    let found = config.select_sources(spectrum::suppress_non_maxima(
+                        searched,
+                        &config.grid(),
+                        mbss.find_sources(searched, config.max_sources.into()),
+                        config.nms_radius,
+                    ));
+                    let peaks = candidates::classify(
+                        spectrum::local_maxima(spectrum.view(), &config.grid())
+                            .into_iter()
+                            .map(|(direction, strength)| {
+                                (direction, config.strength(spectrum.view(), direction, strength))
+                            })
+                            .collect(),
+                        &found,
+                        &config,
+                    );
+                    ros_debug_throttle!(
+                        1.,
+                        "candidate peaks: {}",
+                        peaks
+                            .iter()
+                            .map(|(direction, strength, verdict)| format!(
+                                "(az {:.3}, el {:.3}, {strength:.0}: {verdict})",
+                                direction.azimuth, direction.elevation
+                            ))
+                            .join(", ")
+                    );
+                    if candidates.has_subscribers() {
+                        log_error!(
+                            candidates.send(msgs::SslArray {
+                                header: header.clone(),
+                                sources: peaks
+                                    .iter()
+                                    .map(|&(direction, strength, _)| {
+                                        let direction = config.output_direction(direction);
+                                        let position = config.unit_vec(direction);
+                                        msgs::Ssl {
+                                            x: position.x,
+                                            y: position.y,
+                                            z: position.z,
+                                            azimuth: direction.azimuth,
+                                            elevation: direction.elevation,
+                                            P: strength,
+                                        }
+                                    })
+                                    .collect(),
+                            }),
+                            "error sending candidate peaks {err}"
+                        );
+                    }
+                }
+
                 let subbed = arrow_markers.has_subscribers()
+                    || heading.has_subscribers()
+                    || dominant_source.has_subscribers()
+                    || (config.aggregate_output && detections.has_subscribers())
                     || unit_sphere_sst.has_subscribers()
-                    || unit_sphere_sst_poses.has_subscribers();
+                    || unit_sphere_sst_poses.has_subscribers()
+                    || source_poses.has_subscribers()
+                    || (config.tf_enabled && tf.has_subscribers())
+                    || (config.beamformed && beamformed_audio.has_subscribers())
+                    || (config.ssl_map && ssl_map.has_subscribers());
                 #[cfg(feature = "odas-msgs")]
                 let subbed = subbed
                     || odas_unit_sphere_sst.has_subscribers()
@@ -663,98 +1823,77 @@ fn ssloc(
                 #[cfg(feature = "audio_common_msgs-stamped")]
                 let sss_subbed = sss_subbed || sss_audio_stamped_topic.has_subscribers();
 
-                if subbed || sss_subbed {
-                    let mut last_tracks = last_tracks
-                        .try_lock_for(Duration::from_secs(1))
-                        .expect("should not deadlock");
-                    if last_tracks.iter().any(|track| track.stamp > stamp) {
+                if subbed || sss_subbed || source_count.has_subscribers() {
+                    if tracker.out_of_order(stamp) {
                         ros_info!("skipping publish of out of order poses");
+                        count(0);
+                        beat(seq, &config.frame_id);
                         continue;
                     }
-
-                    let mut new_tracks = Vec::new();
-
-                    for (direction, intensity) in mbss
-                        .find_sources(spectrum.view(), config.max_sources.into())
-                        .into_iter()
-                        .filter(|(.., strength)| *strength > config.mbss_ssl_threshold)
-                    {
-                        let neighboors: Vec<_> = last_tracks
-                            .iter()
-                            .enumerate()
-                            .filter(|(_, track)| {
-                                angular_distance(track.direction, direction) < config.mbss.min_angle
-                            })
-                            .collect();
-                        match neighboors.as_slice() {
-                            [] => new_tracks.push(Track {
-                                direction,
-                                stamp,
-                                power: intensity,
-                                id: track_index.fetch_add(1, atomic::Ordering::SeqCst),
-                                channel: None,
-                            }),
-                            multiple => {
-                                // first one has highest intensity
-                                let neighboor = multiple[0].1;
-                                new_tracks.push(Track {
-                                    direction,
-                                    stamp,
-                                    power: (neighboor.power * 0.8).max(intensity),
-                                    id: neighboor.id,
-                                    channel: neighboor.channel,
-                                });
-                                let idxs = multiple.iter().rev().map(|(i, _)| *i).collect_vec();
-                                for i in idxs {
-                                    last_tracks.remove(i);
-                                }
-                            }
+                    let search = Instant::now();
+                    let found = config.select_sources(spectrum::suppress_non_maxima(
+                        searched,
+                        &config.grid(),
+                        mbss.find_sources(searched, config.max_sources.into()),
+                        config.nms_radius,
+                    ));
+                    analysis += search.elapsed();
+                    // the picker falls back to masked cells without peaks in range
+                    let found =
+                        found.into_iter().filter(|&(direction, _)| config.unmasked(direction));
+                    if config.ssl_map && ssl_map.has_subscribers() {
+                        let mut image = colormap::spectrum_image(spectrum.view(), &config);
+                        colormap::mark_peaks(
+                            &mut image,
+                            &config.grid(),
+                            found.clone().map(|(direction, _)| direction),
+                        );
+                        if config.spectrum_flip_x {
+                            imageops::flip_horizontal_in_place(&mut image);
                         }
-                    }
-                    let mut sources: Vec<_> = new_tracks
-                        .into_iter()
-                        .chain(
-                            mem::take::<Vec<_>>(last_tracks.as_mut())
-                                .into_iter()
-                                .filter(|track| {
-                                    track.stamp.seconds() + config.tracking_persistence
-                                        >= stamp.seconds()
+                        if config.spectrum_flip_y {
+                            imageops::flip_vertical_in_place(&mut image);
+                        }
+                        if let Err(err) = colormap::encode(&image, &config, &mut encoded) {
+                            ros_err!("error encoding ssl map {err}");
+                        } else {
+                            log_error!(
+                                ssl_map.send(msgs::CompressedImage {
+                                    header: header.clone(),
+                                    format: config.spectrum_format.to_string(),
+                                    data: encoded.clone(),
                                 }),
-                        )
-                        .collect();
-                    sources.sort_unstable_by(|a, b| b.power.total_cmp(&a.power));
-                    sources = if sources.len() > config.max_sources.into() {
-                        sources[0..config.max_sources.into()].to_vec()
-                    } else {
-                        sources
-                    };
-                    // Assign channels to tracks without channels
-                    let mut min = 0;
-                    let mut sources = sources
-                        .iter()
-                        .map(|&(mut t)| {
-                            if t.channel.is_some() {
-                                t
-                            } else {
-                                min = (min..)
-                                    .find(|&c| !sources.iter().any(|t| t.channel == Some(c)))
-                                    .expect("there should be a usize that is not taken");
-                                t.channel = Some(min);
-                                min += 1;
-                                t
-                            }
-                        })
-                        .collect_vec();
-                    *last_tracks = sources.clone();
-                    drop(last_tracks);
-                    if unit_sphere_sst.has_subscribers() {
+                                "error sending ssl map {err}"
+                            );
+                        }
+                    }
+                    let found = found
+                        .filter_map(|(direction, strength)| {
+                            let strength = config.strength(spectrum.view(), direction, strength);
+                            Some((
+                                config.output_direction(direction),
+                                config.accept_strength(strength)?,
+                                if config.snr {
+                                    spectrum::snr(spectrum.view(), &config.grid(), direction)
+                                } else {
+                                    0.
+                                },
+                            ))
+                        });
+                    let mut sources = tracker.update(&config, stamp, found);
+                    // stale markers of dropped sources are deleted below
+                    sources.retain(|track| track.power >= config.min_source_strength);
+                    count(sources.len());
+                    // empty arrays serve as heartbeat
+                    let publish = config.publish_empty || !sources.is_empty();
+                    if publish && unit_sphere_sst.has_subscribers() {
                         log_error!(
                             unit_sphere_sst.send(msgs::SstArray {
                                 header: header.clone(),
                                 sources: sources
                                     .iter()
                                     .map(|track| {
-                                        let position = track.direction.to_unit_vec();
+                                        let position = config.unit_vec(track.direction);
                                         msgs::Sst {
                                             id: track.id,
                                             P: track.power,
@@ -771,14 +1910,14 @@ fn ssloc(
                         );
                     }
                     #[cfg(feature = "odas-msgs")]
-                    if odas_unit_sphere_sst.has_subscribers() {
+                    if publish && odas_unit_sphere_sst.has_subscribers() {
                         log_error!(
                             odas_unit_sphere_sst.send(msgs::OdasSstArrayStamped {
                                 header: header.clone(),
                                 sources: sources
                                     .iter()
                                     .map(|track| {
-                                        let position = track.direction.to_unit_vec();
+                                        let position = config.unit_vec(track.direction);
                                         msgs::OdasSst {
                                             id: track.id,
                                             activity: track.power,
@@ -793,18 +1932,21 @@ fn ssloc(
                         );
                     }
 
-                    let sst_poses_subbed = unit_sphere_sst_poses.has_subscribers();
+                    let sst_poses_subbed =
+                        unit_sphere_sst_poses.has_subscribers()
+                            || source_poses.has_subscribers()
+                            || source_strengths.has_subscribers();
                     #[cfg(feature = "odas-msgs")]
                     let sst_poses_subbed =
                         sst_poses_subbed || odas_unit_sphere_sst_poses.has_subscribers();
 
-                    if sst_poses_subbed {
+                    if publish && sst_poses_subbed {
                         let poses = msgs::PoseArray {
                             header: header.clone(),
                             poses: sources
                                 .iter()
                                 .map(|track| {
-                                    let quaternion = track.direction.to_quaternion().coords;
+                                    let quaternion = config.orientation(track.smoothed).coords;
                                     msgs::Pose {
                                         orientation: msgs::Quaternion {
                                             x: quaternion.x,
@@ -824,6 +1966,28 @@ fn ssloc(
                                 "error sending the odas unit sphere sst message: {err}"
                             );
                         }
+                        if source_poses.has_subscribers() {
+                            log_error!(
+                                source_poses.send(poses.clone()),
+                                "error sending the source poses: {err}"
+                            );
+                        }
+                        if source_strengths.has_subscribers() {
+                            log_error!(
+                                source_strengths.send(msgs::Float64MultiArray {
+                                    layout: msgs::MultiArrayLayout {
+                                        dim: vec![msgs::MultiArrayDimension {
+                                            label: "source".to_owned(),
+                                            size: sources.len() as u32,
+                                            stride: sources.len() as u32,
+                                        }],
+                                        data_offset: 0,
+                                    },
+                                    data: sources.iter().map(|track| track.power).collect(),
+                                }),
+                                "error sending the source strengths: {err}"
+                            );
+                        }
                         if unit_sphere_sst_poses.has_subscribers() {
                             log_error!(
                                 unit_sphere_sst_poses.send(poses),
@@ -832,13 +1996,22 @@ fn ssloc(
                         }
                     }
                     if arrow_markers.has_subscribers() {
-                        for (idx, track) in sources.iter().enumerate() {
-                            let rotation = track.direction.to_quaternion().coords;
+                        // sources are sorted by power
+                        let max_power = sources.first().map(|track| track.power);
+                        for track in &sources {
+                            let rotation = config.orientation(track.smoothed).coords;
+                            let level = match max_power {
+                                Some(max) if max > 0. => (track.power / max).clamp(0., 1.),
+                                _ => 1.,
+                            };
+                            let alpha =
+                                if config.marker_alpha_by_confidence { level as f32 } else { 1. };
+                            let [r, g, b] = config.marker_colors.color(track.id, level);
                             log_error!(
                                 arrow_markers.send(msgs::Marker {
                                     header: header.clone(),
                                     ns: "sslocate".to_string(),
-                                    id: idx as i32 + 1,
+                                    id: marker_id(track.id),
                                     type_: msgs::Marker::ARROW as i32,
                                     pose: msgs::Pose {
                                         position: msgs::Point {
@@ -853,29 +2026,175 @@ fn ssloc(
                                             w: rotation.w,
                                         },
                                     },
-                                    color: msgs::ColorRGBA {
-                                        r: 1.,
-                                        a: 1.,
-                                        ..Default::default()
-                                    },
+                                    color: msgs::ColorRGBA { r, g, b, a: alpha },
                                     scale: msgs::Vector3 {
-                                        x: 1. * track.power / 8000.,
+                                        x: config.marker_length(track.power),
                                         y: 0.1,
                                         z: 0.1,
                                     },
                                     action: msgs::Marker::ADD as i32,
-                                    lifetime: rosrust::Duration::from_seconds(1),
+                                    lifetime: rosrust::Duration::from_nanos(
+                                        (config.marker_lifetime * 1e9) as i64,
+                                    ),
                                     ..Default::default()
                                 }),
                                 "error sending marker {err}"
                             );
                         }
+                        for &id in marker_ids
+                            .iter()
+                            .filter(|&&id| !sources.iter().any(|track| marker_id(track.id) == id))
+                        {
+                            log_error!(
+                                arrow_markers.send(msgs::Marker {
+                                    header: header.clone(),
+                                    ns: "sslocate".to_string(),
+                                    id,
+                                    action: msgs::Marker::DELETE as i32,
+                                    ..Default::default()
+                                }),
+                                "error deleting marker {err}"
+                            );
+                        }
+                        marker_ids = sources.iter().map(|track| marker_id(track.id)).collect();
+                    }
+                    // TF has no deletion, frames of lost sources just stop being updated
+                    if config.tf_enabled && !sources.is_empty() && tf.has_subscribers() {
+                        log_error!(
+                            tf.send(msgs::TFMessage {
+                                transforms: sources
+                                    .iter()
+                                    .map(|track| {
+                                        let rotation = config.orientation(track.smoothed).coords;
+                                        let translation =
+                                            config.unit_vec(track.smoothed) * config.tf_distance;
+                                        msgs::TransformStamped {
+                                            header: header.clone(),
+                                            child_frame_id: format!(
+                                                "{}{}",
+                                                config.tf_prefix, track.id
+                                            ),
+                                            transform: msgs::Transform {
+                                                translation: msgs::Vector3 {
+                                                    x: translation.x,
+                                                    y: translation.y,
+                                                    z: translation.z,
+                                                },
+                                                rotation: msgs::Quaternion {
+                                                    x: rotation.x,
+                                                    y: rotation.y,
+                                                    z: rotation.z,
+                                                    w: rotation.w,
+                                                },
+                                            },
+                                        }
+                                    })
+                                    .collect(),
+                            }),
+                            "error sending source transforms {err}"
+                        );
+                    }
+                    if heading.has_subscribers() {
+                        if let Some(track) =
+                            follow(&sources, config.heading_source, &mut heading_id)
+                        {
+                            let azimuth = track.smoothed.azimuth;
+                            log_error!(
+                                heading.send(msgs::Float32 {
+                                    data: if config.heading_degrees {
+                                        azimuth.to_degrees()
+                                    } else {
+                                        azimuth
+                                    } as f32,
+                                }),
+                                "error sending heading {err}"
+                            );
+                        }
+                    }
+                    if dominant_source.has_subscribers() {
+                        if let Some(track) =
+                            follow(&sources, config.dominant_source, &mut dominant_id)
+                        {
+                            let quaternion = config.orientation(track.smoothed).coords;
+                            log_error!(
+                                dominant_source.send(msgs::PoseStamped {
+                                    header: header.clone(),
+                                    pose: msgs::Pose {
+                                        orientation: msgs::Quaternion {
+                                            x: quaternion.x,
+                                            y: quaternion.y,
+                                            z: quaternion.z,
+                                            w: quaternion.w,
+                                        },
+                                        ..Default::default()
+                                    },
+                                }),
+                                "error sending dominant source {err}"
+                            );
+                        }
+                    }
+                    if config.aggregate_output && detections.has_subscribers() {
+                        // sources are sorted by power
+                        let max_power = sources.first().map_or(0., |track| track.power);
+                        log_error!(
+                            detections.send(msgs::Detections {
+                                header: header.clone(),
+                                count: sources.len() as u32,
+                                sources: sources
+                                    .iter()
+                                    .map(|track| msgs::Detection {
+                                        azimuth: track.direction.azimuth,
+                                        elevation: track.direction.elevation,
+                                        strength: track.power,
+                                        confidence: if max_power > 0. {
+                                            track.power / max_power
+                                        } else {
+                                            0.
+                                        },
+                                        snr: track.snr,
+                                        id: track.id,
+                                    })
+                                    .collect(),
+                                processing_time: received.elapsed().as_secs_f64(),
+                                latency: (rosrust::now() - stamp).seconds(),
+                            }),
+                            "error sending detections {err}"
+                        );
+                    }
+                    if config.beamformed && beamformed_audio.has_subscribers() {
+                        // keeps steering to the last source while none is detected
+                        if let Some(track) = sources.first() {
+                            beam_direction = Some(track.direction);
+                        }
+                        if let Some(direction) = beam_direction {
+                            let data = das.beam_form(direction, &audio).collect_vec();
+                            let data = &data[data.len().saturating_sub(recorded)..];
+                            let beam = Audio::from_interleaved(
+                                audio.sample_rate(),
+                                1,
+                                data.iter().copied(),
+                            );
+                            let info = (config.rate, config.audio_publish_format);
+                            if beamformed_info != Some(info) {
+                                beamformed_info = Some(info);
+                                log_error!(
+                                    beamformed_audio_info.send(audio_info(1, info.0, info.1)),
+                                    "error sending beamformed audio info message {err}"
+                                );
+                            }
+                            log_error!(
+                                beamformed_audio.send(msgs::AudioData {
+                                    data: beam.encode(config.audio_publish_format),
+                                }),
+                                "error sending beamformed audio message {err}"
+                            );
+                        }
                     }
                     if sss_subbed {
                         let mut channels = Vec::new();
                         let mut mapping = Vec::new();
                         sources.sort_by_key(|t| t.channel.expect("all channels are set"));
-                        let length = das.expected_len(&audio);
+                        let length = das.expected_len(&audio).min(recorded);
                         for track in &sources {
                             let channel = track.channel.unwrap();
                             assert!(
@@ -884,10 +2203,11 @@ fn ssloc(
                             );
                             // insert empty data for unused channels
                             channels.extend(
-                                iter::repeat(vec![0.0; length]).take(channel - channels.len()),
+                                iter::repeat_n(vec![0.0; length], channel - channels.len()),
                             );
-                            mapping.extend(iter::repeat(-1).take(channels.len() - channel));
-                            let data = das.beam_form(track.direction, &audio).collect_vec();
+                            mapping.extend(iter::repeat_n(-1, channels.len() - channel));
+                            let mut data = das.beam_form(track.direction, &audio).collect_vec();
+                            data.drain(..data.len().saturating_sub(recorded));
                             // TODO reintroduce if possible
                             // assert_eq!(data.len(), length);
                             channels.push(data);
@@ -895,18 +2215,15 @@ fn ssloc(
                         }
 
                         let audio = Audio::from_channels(audio.sample_rate(), channels);
-                        // TODO consider supporting more than one output format.
                         let audio = msgs::AudioData {
-                            data: audio.to_interleaved().flat_map(f32::to_le_bytes).collect(),
+                            data: audio.encode(config.audio_publish_format),
                         };
                         log_error!(
-                            sss_audio_info_topic.send(msgs::AudioInfo {
-                                channels: config.channels as u8,
-                                sample_rate: config.rate.into(),
-                                sample_format: "FLOAT32LE".into(),
-                                bitrate: (size_of::<f32>() as u32) * 8 * config.rate as u32,
-                                coding_format: "wave".into(),
-                            }),
+                            sss_audio_info_topic.send(audio_info(
+                                config.channels,
+                                config.rate,
+                                config.audio_publish_format
+                            )),
                             "error sending sss audio info message {err}"
                         );
                         log_error!(
@@ -930,6 +2247,20 @@ fn ssloc(
                         );
                     }
                 }
+                diagnostics.analyzed(analysis, (rosrust::now() - stamp).seconds());
+                if mbss_ms.has_subscribers() {
+                    log_error!(
+                        mbss_ms.send(msgs::Float64 { data: analysis.as_secs_f64() * 1000. }),
+                        "error sending processing time {err}"
+                    );
+                }
+                if queue_depth.has_subscribers() {
+                    log_error!(
+                        queue_depth.send(msgs::UInt32 { data: audio_channel_recv.len() as u32 }),
+                        "error sending queue depth {err}"
+                    );
+                }
+                beat(seq, &config.frame_id);
             }
         }
         Ok(())
@@ -1103,6 +1103,327 @@ pub mod geometry_msgs {
             })
         }
     }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct PoseStamped {
+        pub header: super::std_msgs::Header,
+        pub pose: Pose,
+    }
+    impl PoseStamped {}
+    impl std::convert::From<PoseStamped> for rosrust::MsgValue {
+        fn from(src: PoseStamped) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<PoseStamped> for rosrust::MsgMessage {
+        fn from(src: PoseStamped) -> Self {
+            let mut output = Self::new();
+            output.insert("header".into(), src.header.into());
+            output.insert("pose".into(), src.pose.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for PoseStamped {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for PoseStamped {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                header: src.remove("header").ok_or(())?.try_into()?,
+                pose: src.remove("pose").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for PoseStamped {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.header == other.header && self.pose == other.pose
+        }
+    }
+    impl std::fmt::Debug for PoseStamped {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(PoseStamped))
+                .field(stringify!(header), &self.header)
+                .field(stringify!(pose), &self.pose)
+                .finish()
+        }
+    }
+    impl Default for PoseStamped {
+        fn default() -> Self {
+            Self {
+                header: Default::default(),
+                pose: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for PoseStamped {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "# A Pose with reference coordinate frame and timestamp\nHeader header\nPose pose\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "d3812c3cbc69362b77dc0b19b345f8f5".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "geometry_msgs/PoseStamped".into()
+        }
+
+        fn set_header(
+            &mut self,
+            clock: &::std::sync::Arc<dyn rosrust::Clock>,
+            seq: &::std::sync::Arc<::std::sync::atomic::AtomicUsize>,
+        ) {
+            if self.header.seq == 0 {
+                self.header.seq = seq.fetch_add(1, ::std::sync::atomic::Ordering::SeqCst) as u32;
+            }
+            if self.header.stamp.nanos() == 0 {
+                self.header.stamp = clock.now();
+            }
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for PoseStamped {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.header.encode(w.by_ref())?;
+            self.pose.encode(w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                header: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                pose: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct TransformStamped {
+        pub header: super::std_msgs::Header,
+        pub child_frame_id: ::std::string::String,
+        pub transform: Transform,
+    }
+    impl TransformStamped {}
+    impl std::convert::From<TransformStamped> for rosrust::MsgValue {
+        fn from(src: TransformStamped) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<TransformStamped> for rosrust::MsgMessage {
+        fn from(src: TransformStamped) -> Self {
+            let mut output = Self::new();
+            output.insert("header".into(), src.header.into());
+            output.insert("child_frame_id".into(), src.child_frame_id.into());
+            output.insert("transform".into(), src.transform.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for TransformStamped {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for TransformStamped {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                header: src.remove("header").ok_or(())?.try_into()?,
+                child_frame_id: src.remove("child_frame_id").ok_or(())?.try_into()?,
+                transform: src.remove("transform").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for TransformStamped {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.header == other.header
+                && self.child_frame_id == other.child_frame_id
+                && self.transform == other.transform
+        }
+    }
+    impl std::fmt::Debug for TransformStamped {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(TransformStamped))
+                .field(stringify!(header), &self.header)
+                .field(stringify!(child_frame_id), &self.child_frame_id)
+                .field(stringify!(transform), &self.transform)
+                .finish()
+        }
+    }
+    impl Default for TransformStamped {
+        fn default() -> Self {
+            Self {
+                header: Default::default(),
+                child_frame_id: Default::default(),
+                transform: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for TransformStamped {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "# This expresses a transform from coordinate frame header.frame_id\n# to the coordinate frame child_frame_id\n#\n# This message is mostly used by the \n# <a href=\"http://wiki.ros.org/tf\">tf</a> package. \n# See its documentation for more information.\n\nHeader header\nstring child_frame_id # the frame id of the child frame\nTransform transform\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Transform\n# This represents the transform between two coordinate frames in free space.\n\nVector3 translation\nQuaternion rotation\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "b5764a33bfeb3588febc2682852579b0".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "geometry_msgs/TransformStamped".into()
+        }
+
+        fn set_header(
+            &mut self,
+            clock: &::std::sync::Arc<dyn rosrust::Clock>,
+            seq: &::std::sync::Arc<::std::sync::atomic::AtomicUsize>,
+        ) {
+            if self.header.seq == 0 {
+                self.header.seq = seq.fetch_add(1, ::std::sync::atomic::Ordering::SeqCst) as u32;
+            }
+            if self.header.stamp.nanos() == 0 {
+                self.header.stamp = clock.now();
+            }
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for TransformStamped {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.header.encode(w.by_ref())?;
+            self.child_frame_id.encode(w.by_ref())?;
+            self.transform.encode(w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                header: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                child_frame_id: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                transform: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct Transform {
+        pub translation: Vector3,
+        pub rotation: Quaternion,
+    }
+    impl Transform {}
+    impl std::convert::From<Transform> for rosrust::MsgValue {
+        fn from(src: Transform) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<Transform> for rosrust::MsgMessage {
+        fn from(src: Transform) -> Self {
+            let mut output = Self::new();
+            output.insert("translation".into(), src.translation.into());
+            output.insert("rotation".into(), src.rotation.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for Transform {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for Transform {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                translation: src.remove("translation").ok_or(())?.try_into()?,
+                rotation: src.remove("rotation").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for Transform {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.translation == other.translation && self.rotation == other.rotation
+        }
+    }
+    impl std::fmt::Debug for Transform {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(Transform))
+                .field(stringify!(translation), &self.translation)
+                .field(stringify!(rotation), &self.rotation)
+                .finish()
+        }
+    }
+    impl Default for Transform {
+        fn default() -> Self {
+            Self {
+                translation: Default::default(),
+                rotation: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for Transform {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "# This represents the transform between two coordinate frames in free \
+             space.\n\nVector3 translation\nQuaternion \
+             rotation\n\\
+             n================================================================================\\
+             nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only \
+             meant to represent a direction. Therefore, it does not\n# make sense to apply a \
+             translation to it (e.g., when applying a \n# generic rigid transformation to a \
+             Vector3, tf2 will only apply the\n# rotation). If you want your data to be \
+             translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 \
+             x\nfloat64 y\nfloat64 \
+             z\n\n================================================================================\\
+             nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in \
+             quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n"
+                .into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "ac9eff44abf714214112b05d54a3cf9b".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "geometry_msgs/Transform".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for Transform {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.translation.encode(w.by_ref())?;
+            self.rotation.encode(w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                translation: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                rotation: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
 }
 pub mod odas_ros {
     #[allow(dead_code, non_camel_case_types, non_snake_case)]
@@ -1754,30 +2075,29 @@ pub mod std_msgs {
             })
         }
     }
-}
-pub mod audio_common_msgs {
     #[allow(dead_code, non_camel_case_types, non_snake_case)]
     #[derive(Clone)]
-    pub struct AudioDataStamped {
-        pub header: super::std_msgs::Header,
-        pub audio: super::audio_common_msgs::AudioData,
-    }
-    impl AudioDataStamped {}
-
-    impl std::convert::From<AudioDataStamped> for rosrust::MsgValue {
-        fn from(src: AudioDataStamped) -> Self {
+    pub struct MultiArrayDimension {
+        pub label: ::std::string::String,
+        pub size: u32,
+        pub stride: u32,
+    }
+    impl MultiArrayDimension {}
+    impl std::convert::From<MultiArrayDimension> for rosrust::MsgValue {
+        fn from(src: MultiArrayDimension) -> Self {
             rosrust::MsgValue::Message(src.into())
         }
     }
-    impl std::convert::From<AudioDataStamped> for rosrust::MsgMessage {
-        fn from(src: AudioDataStamped) -> Self {
+    impl std::convert::From<MultiArrayDimension> for rosrust::MsgMessage {
+        fn from(src: MultiArrayDimension) -> Self {
             let mut output = Self::new();
-            output.insert("header".into(), src.header.into());
-            output.insert("audio".into(), src.audio.into());
+            output.insert("label".into(), src.label.into());
+            output.insert("size".into(), src.size.into());
+            output.insert("stride".into(), src.stride.into());
             output
         }
     }
-    impl std::convert::TryFrom<rosrust::MsgValue> for AudioDataStamped {
+    impl std::convert::TryFrom<rosrust::MsgValue> for MultiArrayDimension {
         type Error = ();
 
         fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
@@ -1786,101 +2106,98 @@ pub mod audio_common_msgs {
             message.try_into()
         }
     }
-    impl std::convert::TryFrom<rosrust::MsgMessage> for AudioDataStamped {
+    impl std::convert::TryFrom<rosrust::MsgMessage> for MultiArrayDimension {
         type Error = ();
 
         fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
             use std::convert::TryInto;
             Ok(Self {
-                header: src.remove("header").ok_or(())?.try_into()?,
-                audio: src.remove("audio").ok_or(())?.try_into()?,
+                label: src.remove("label").ok_or(())?.try_into()?,
+                size: src.remove("size").ok_or(())?.try_into()?,
+                stride: src.remove("stride").ok_or(())?.try_into()?,
             })
         }
     }
-    impl std::cmp::PartialEq<Self> for AudioDataStamped {
+    impl std::cmp::PartialEq<Self> for MultiArrayDimension {
         fn eq(&self, other: &Self) -> bool {
-            true && self.header == other.header && self.audio == other.audio
+            true && self.label == other.label
+                && self.size == other.size
+                && self.stride == other.stride
         }
     }
-    impl std::fmt::Debug for AudioDataStamped {
+    impl std::fmt::Debug for MultiArrayDimension {
         fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-            f.debug_struct(stringify!(AudioDataStamped))
-                .field(stringify!(header), &self.header)
-                .field(stringify!(audio), &self.audio)
+            f.debug_struct(stringify!(MultiArrayDimension))
+                .field(stringify!(label), &self.label)
+                .field(stringify!(size), &self.size)
+                .field(stringify!(stride), &self.stride)
                 .finish()
         }
     }
-    impl Default for AudioDataStamped {
+    impl Default for MultiArrayDimension {
         fn default() -> Self {
             Self {
-                header: Default::default(),
-                audio: Default::default(),
+                label: Default::default(),
+                size: Default::default(),
+                stride: Default::default(),
             }
         }
     }
-    impl rosrust::Message for AudioDataStamped {
+    impl rosrust::Message for MultiArrayDimension {
         #[inline]
         fn msg_definition() -> ::std::string::String {
-            "std_msgs/Header header\naudio_common_msgs/AudioData audio\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\nstring frame_id\n\n================================================================================\nMSG: audio_common_msgs/AudioData\nuint8[] data\n".into()
+            "string label   # label of given dimension\nuint32 size    # size of given dimension \
+             (in type units)\nuint32 stride  # stride of given dimension\n"
+                .into()
         }
 
         #[inline]
         fn md5sum() -> ::std::string::String {
-            "3cdd84a06846af0dca4d0434908f9d96".into()
+            "4cd0c83a8683deae40ecdac60e53bfa8".into()
         }
 
         #[inline]
         fn msg_type() -> ::std::string::String {
-            "audio_common_msgs/AudioDataStamped".into()
-        }
-
-        fn set_header(
-            &mut self,
-            clock: &::std::sync::Arc<dyn rosrust::Clock>,
-            seq: &::std::sync::Arc<::std::sync::atomic::AtomicUsize>,
-        ) {
-            if self.header.seq == 0 {
-                self.header.seq = seq.fetch_add(1, ::std::sync::atomic::Ordering::SeqCst) as u32;
-            }
-            if self.header.stamp.nanos() == 0 {
-                self.header.stamp = clock.now();
-            }
+            "std_msgs/MultiArrayDimension".into()
         }
     }
-    impl rosrust::rosmsg::RosMsg for AudioDataStamped {
+    impl rosrust::rosmsg::RosMsg for MultiArrayDimension {
         fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
-            self.header.encode(w.by_ref())?;
-            self.audio.encode(w.by_ref())?;
+            self.label.encode(w.by_ref())?;
+            self.size.encode(w.by_ref())?;
+            self.stride.encode(w.by_ref())?;
             Ok(())
         }
 
         fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
             Ok(Self {
-                header: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                audio: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                label: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                size: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                stride: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
             })
         }
     }
     #[allow(dead_code, non_camel_case_types, non_snake_case)]
     #[derive(Clone)]
-    pub struct AudioData {
-        pub data: Vec<u8>,
+    pub struct MultiArrayLayout {
+        pub dim: Vec<MultiArrayDimension>,
+        pub data_offset: u32,
     }
-    impl AudioData {}
-
-    impl std::convert::From<AudioData> for rosrust::MsgValue {
-        fn from(src: AudioData) -> Self {
+    impl MultiArrayLayout {}
+    impl std::convert::From<MultiArrayLayout> for rosrust::MsgValue {
+        fn from(src: MultiArrayLayout) -> Self {
             rosrust::MsgValue::Message(src.into())
         }
     }
-    impl std::convert::From<AudioData> for rosrust::MsgMessage {
-        fn from(src: AudioData) -> Self {
+    impl std::convert::From<MultiArrayLayout> for rosrust::MsgMessage {
+        fn from(src: MultiArrayLayout) -> Self {
             let mut output = Self::new();
-            output.insert("data".into(), src.data.into());
+            output.insert("dim".into(), src.dim.into());
+            output.insert("data_offset".into(), src.data_offset.into());
             output
         }
     }
-    impl std::convert::TryFrom<rosrust::MsgValue> for AudioData {
+    impl std::convert::TryFrom<rosrust::MsgValue> for MultiArrayLayout {
         type Error = ();
 
         fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
@@ -1889,91 +2206,106 @@ pub mod audio_common_msgs {
             message.try_into()
         }
     }
-    impl std::convert::TryFrom<rosrust::MsgMessage> for AudioData {
+    impl std::convert::TryFrom<rosrust::MsgMessage> for MultiArrayLayout {
         type Error = ();
 
         fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
             use std::convert::TryInto;
             Ok(Self {
-                data: src.remove("data").ok_or(())?.try_into()?,
+                dim: src.remove("dim").ok_or(())?.try_into()?,
+                data_offset: src.remove("data_offset").ok_or(())?.try_into()?,
             })
         }
     }
-    impl std::cmp::PartialEq<Self> for AudioData {
+    impl std::cmp::PartialEq<Self> for MultiArrayLayout {
         fn eq(&self, other: &Self) -> bool {
-            true && self.data == other.data
+            true && self.dim == other.dim && self.data_offset == other.data_offset
         }
     }
-    impl std::fmt::Debug for AudioData {
+    impl std::fmt::Debug for MultiArrayLayout {
         fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-            f.debug_struct(stringify!(AudioData))
-                .field(stringify!(data), &self.data)
+            f.debug_struct(stringify!(MultiArrayLayout))
+                .field(stringify!(dim), &self.dim)
+                .field(stringify!(data_offset), &self.data_offset)
                 .finish()
         }
     }
-    impl Default for AudioData {
+    impl Default for MultiArrayLayout {
         fn default() -> Self {
             Self {
-                data: Default::default(),
+                dim: Default::default(),
+                data_offset: Default::default(),
             }
         }
     }
-    impl rosrust::Message for AudioData {
+    impl rosrust::Message for MultiArrayLayout {
         #[inline]
         fn msg_definition() -> ::std::string::String {
-            "uint8[] data\n".into()
+            "# The multiarray declares a generic multi-dimensional array of a\n# particular data \
+             type.  Dimensions are ordered from outer most\n# to inner \
+             most.\n\nMultiArrayDimension[] dim # Array of dimension properties\nuint32 \
+             data_offset        # padding elements at front of data\n\n# Accessors should ALWAYS \
+             be written in terms of dimension stride\n# and specified outer-most dimension \
+             first.\n# \n# multiarray(i,j,k) = data[data_offset + dim_stride[1]*i + \
+             dim_stride[2]*j + k]\n#\n# A standard, 3-channel 640x480 image with interleaved color \
+             channels\n# would be specified as:\n#\n# dim[0].label  = \"height\"\n# dim[0].size   \
+             = 480\n# dim[0].stride = 3*640*480 = 921600  (note dim[0] stride is just size of \
+             image)\n# dim[1].label  = \"width\"\n# dim[1].size   = 640\n# dim[1].stride = 3*640 = \
+             1920\n# dim[2].label  = \"channel\"\n# dim[2].size   = 3\n# dim[2].stride = 3\n#\n# \
+             multiarray(i,j,k) refers to the ith row, jth column, and kth \
+             channel.\n\\
+             n================================================================================\\
+             nMSG: std_msgs/MultiArrayDimension\nstring label   # label of given dimension\nuint32 \
+             size    # size of given dimension (in type units)\nuint32 stride  # stride of given \
+             dimension\n"
+                .into()
         }
 
         #[inline]
         fn md5sum() -> ::std::string::String {
-            "f43a8e1b362b75baa741461b46adc7e0".into()
+            "0fed2a11c13e11c5571b4e2a995a91a3".into()
         }
 
         #[inline]
         fn msg_type() -> ::std::string::String {
-            "audio_common_msgs/AudioData".into()
+            "std_msgs/MultiArrayLayout".into()
         }
     }
-    impl rosrust::rosmsg::RosMsg for AudioData {
+    impl rosrust::rosmsg::RosMsg for MultiArrayLayout {
         fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
-            rosrust::rosmsg::encode_variable_primitive_slice(&self.data, w.by_ref())?;
+            rosrust::rosmsg::encode_variable_slice(&self.dim, w.by_ref())?;
+            self.data_offset.encode(w.by_ref())?;
             Ok(())
         }
 
         fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
             Ok(Self {
-                data: rosrust::rosmsg::decode_variable_primitive_vec(r.by_ref())?,
+                dim: rosrust::rosmsg::decode_variable_vec(r.by_ref())?,
+                data_offset: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
             })
         }
     }
     #[allow(dead_code, non_camel_case_types, non_snake_case)]
     #[derive(Clone)]
-    pub struct AudioInfo {
-        pub channels: u8,
-        pub sample_rate: u32,
-        pub sample_format: ::std::string::String,
-        pub bitrate: u32,
-        pub coding_format: ::std::string::String,
+    pub struct Float32MultiArray {
+        pub layout: MultiArrayLayout,
+        pub data: Vec<f32>,
     }
-    impl AudioInfo {}
-
-    impl std::convert::From<AudioInfo> for rosrust::MsgValue {
-        fn from(src: AudioInfo) -> Self {
+    impl Float32MultiArray {}
+    impl std::convert::From<Float32MultiArray> for rosrust::MsgValue {
+        fn from(src: Float32MultiArray) -> Self {
             rosrust::MsgValue::Message(src.into())
         }
     }
-    impl std::convert::From<AudioInfo> for rosrust::MsgMessage {
-        fn from(src: AudioInfo) -> Self {
+    impl std::convert::From<Float32MultiArray> for rosrust::MsgMessage {
+        fn from(src: Float32MultiArray) -> Self {
             let mut output = Self::new();
-            output.insert("channels".into(), src.channels.into());
-            output.insert("sample_rate".into(), src.sample_rate.into());
-            output.insert("sample_format".into(), src.sample_format.into());
-            output.insert("bitrate".into(), src.bitrate.into());
-            output.insert("coding_format".into(), src.coding_format.into());
+            output.insert("layout".into(), src.layout.into());
+            output.insert("data".into(), src.data.into());
             output
         }
     }
-    impl std::convert::TryFrom<rosrust::MsgValue> for AudioInfo {
+    impl std::convert::TryFrom<rosrust::MsgValue> for Float32MultiArray {
         type Error = ();
 
         fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
@@ -1982,176 +2314,87 @@ pub mod audio_common_msgs {
             message.try_into()
         }
     }
-    impl std::convert::TryFrom<rosrust::MsgMessage> for AudioInfo {
+    impl std::convert::TryFrom<rosrust::MsgMessage> for Float32MultiArray {
         type Error = ();
 
         fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
             use std::convert::TryInto;
             Ok(Self {
-                channels: src.remove("channels").ok_or(())?.try_into()?,
-                sample_rate: src.remove("sample_rate").ok_or(())?.try_into()?,
-                sample_format: src.remove("sample_format").ok_or(())?.try_into()?,
-                bitrate: src.remove("bitrate").ok_or(())?.try_into()?,
-                coding_format: src.remove("coding_format").ok_or(())?.try_into()?,
+                layout: src.remove("layout").ok_or(())?.try_into()?,
+                data: src.remove("data").ok_or(())?.try_into()?,
             })
         }
     }
-    impl std::cmp::PartialEq<Self> for AudioInfo {
+    impl std::cmp::PartialEq<Self> for Float32MultiArray {
         fn eq(&self, other: &Self) -> bool {
-            true && self.channels == other.channels
-                && self.sample_rate == other.sample_rate
-                && self.sample_format == other.sample_format
-                && self.bitrate == other.bitrate
-                && self.coding_format == other.coding_format
+            true && self.layout == other.layout && self.data == other.data
         }
     }
-    impl std::fmt::Debug for AudioInfo {
+    impl std::fmt::Debug for Float32MultiArray {
         fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-            f.debug_struct(stringify!(AudioInfo))
-                .field(stringify!(channels), &self.channels)
-                .field(stringify!(sample_rate), &self.sample_rate)
-                .field(stringify!(sample_format), &self.sample_format)
-                .field(stringify!(bitrate), &self.bitrate)
-                .field(stringify!(coding_format), &self.coding_format)
+            f.debug_struct(stringify!(Float32MultiArray))
+                .field(stringify!(layout), &self.layout)
+                .field(stringify!(data), &self.data)
                 .finish()
         }
     }
-    impl Default for AudioInfo {
+    impl Default for Float32MultiArray {
         fn default() -> Self {
             Self {
-                channels: Default::default(),
-                sample_rate: Default::default(),
-                sample_format: Default::default(),
-                bitrate: Default::default(),
-                coding_format: Default::default(),
+                layout: Default::default(),
+                data: Default::default(),
             }
         }
     }
-    impl rosrust::Message for AudioInfo {
+    impl rosrust::Message for Float32MultiArray {
         #[inline]
         fn msg_definition() -> ::std::string::String {
-            "# This message contains the audio meta data\n\n# Number of channels\nuint8 \
-             channels\n# Sampling rate [Hz]\nuint32 sample_rate\n# Audio format (e.g. \
-             S16LE)\nstring sample_format\n# Amount of audio data per second [bits/s]\nuint32 \
-             bitrate\n# Audio coding format (e.g. WAVE, MP3)\nstring coding_format\n"
-                .into()
+            "# Please look at the MultiArrayLayout message definition for\n# documentation on all multiarrays.\n\nMultiArrayLayout  layout        # specification of data layout\nfloat32[]         data          # array of data\n\n================================================================================\nMSG: std_msgs/MultiArrayLayout\n# The multiarray declares a generic multi-dimensional array of a\n# particular data type.  Dimensions are ordered from outer most\n# to inner most.\n\nMultiArrayDimension[] dim # Array of dimension properties\nuint32 data_offset        # padding elements at front of data\n\n# Accessors should ALWAYS be written in terms of dimension stride\n# and specified outer-most dimension first.\n# \n# multiarray(i,j,k) = data[data_offset + dim_stride[1]*i + dim_stride[2]*j + k]\n#\n# A standard, 3-channel 640x480 image with interleaved color channels\n# would be specified as:\n#\n# dim[0].label  = \"height\"\n# dim[0].size   = 480\n# dim[0].stride = 3*640*480 = 921600  (note dim[0] stride is just size of image)\n# dim[1].label  = \"width\"\n# dim[1].size   = 640\n# dim[1].stride = 3*640 = 1920\n# dim[2].label  = \"channel\"\n# dim[2].size   = 3\n# dim[2].stride = 3\n#\n# multiarray(i,j,k) refers to the ith row, jth column, and kth channel.\n\n================================================================================\nMSG: std_msgs/MultiArrayDimension\nstring label   # label of given dimension\nuint32 size    # size of given dimension (in type units)\nuint32 stride  # stride of given dimension\n".into()
         }
 
         #[inline]
         fn md5sum() -> ::std::string::String {
-            "9413d9b7029680d3b1db6ed0ae535f88".into()
+            "6a40e0ffa6a17a503ac3f8616991b1f6".into()
         }
 
         #[inline]
         fn msg_type() -> ::std::string::String {
-            "audio_common_msgs/AudioInfo".into()
+            "std_msgs/Float32MultiArray".into()
         }
     }
-    impl rosrust::rosmsg::RosMsg for AudioInfo {
+    impl rosrust::rosmsg::RosMsg for Float32MultiArray {
         fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
-            self.channels.encode(w.by_ref())?;
-            self.sample_rate.encode(w.by_ref())?;
-            self.sample_format.encode(w.by_ref())?;
-            self.bitrate.encode(w.by_ref())?;
-            self.coding_format.encode(w.by_ref())?;
+            self.layout.encode(w.by_ref())?;
+            rosrust::rosmsg::encode_variable_primitive_slice(&self.data, w.by_ref())?;
             Ok(())
         }
 
         fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
             Ok(Self {
-                channels: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                sample_rate: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                sample_format: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                bitrate: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                coding_format: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                layout: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                data: rosrust::rosmsg::decode_variable_primitive_vec(r.by_ref())?,
             })
         }
     }
-}
-pub mod visualization_msgs {
     #[allow(dead_code, non_camel_case_types, non_snake_case)]
     #[derive(Clone)]
-    pub struct Marker {
-        pub header: super::std_msgs::Header,
-        pub ns: ::std::string::String,
-        pub id: i32,
-        pub type_: i32,
-        pub action: i32,
-        pub pose: super::geometry_msgs::Pose,
-        pub scale: super::geometry_msgs::Vector3,
-        pub color: super::std_msgs::ColorRGBA,
-        pub lifetime: rosrust::Duration,
-        pub frame_locked: bool,
-        pub points: Vec<super::geometry_msgs::Point>,
-        pub colors: Vec<super::std_msgs::ColorRGBA>,
-        pub text: ::std::string::String,
-        pub mesh_resource: ::std::string::String,
-        pub mesh_use_embedded_materials: bool,
-    }
-    impl Marker {
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const ADD: u8 = 0u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const ARROW: u8 = 0u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const CUBE: u8 = 1u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const CUBE_LIST: u8 = 6u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const CYLINDER: u8 = 3u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const DELETE: u8 = 2u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const DELETEALL: u8 = 3u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const LINE_LIST: u8 = 5u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const LINE_STRIP: u8 = 4u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const MESH_RESOURCE: u8 = 10u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const MODIFY: u8 = 0u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const POINTS: u8 = 8u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const SPHERE: u8 = 2u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const SPHERE_LIST: u8 = 7u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const TEXT_VIEW_FACING: u8 = 9u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const TRIANGLE_LIST: u8 = 11u8 as u8;
+    pub struct Float32 {
+        pub data: f32,
     }
-    impl std::convert::From<Marker> for rosrust::MsgValue {
-        fn from(src: Marker) -> Self {
+    impl Float32 {}
+    impl std::convert::From<Float32> for rosrust::MsgValue {
+        fn from(src: Float32) -> Self {
             rosrust::MsgValue::Message(src.into())
         }
     }
-    impl std::convert::From<Marker> for rosrust::MsgMessage {
-        fn from(src: Marker) -> Self {
+    impl std::convert::From<Float32> for rosrust::MsgMessage {
+        fn from(src: Float32) -> Self {
             let mut output = Self::new();
-            output.insert("header".into(), src.header.into());
-            output.insert("ns".into(), src.ns.into());
-            output.insert("id".into(), src.id.into());
-            output.insert("type".into(), src.type_.into());
-            output.insert("action".into(), src.action.into());
-            output.insert("pose".into(), src.pose.into());
-            output.insert("scale".into(), src.scale.into());
-            output.insert("color".into(), src.color.into());
-            output.insert("lifetime".into(), src.lifetime.into());
-            output.insert("frame_locked".into(), src.frame_locked.into());
-            output.insert("points".into(), src.points.into());
-            output.insert("colors".into(), src.colors.into());
-            output.insert("text".into(), src.text.into());
-            output.insert("mesh_resource".into(), src.mesh_resource.into());
-            output.insert(
-                "mesh_use_embedded_materials".into(),
-                src.mesh_use_embedded_materials.into(),
-            );
+            output.insert("data".into(), src.data.into());
             output
         }
     }
-    impl std::convert::TryFrom<rosrust::MsgValue> for Marker {
+    impl std::convert::TryFrom<rosrust::MsgValue> for Float32 {
         type Error = ();
 
         fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
@@ -2160,192 +2403,2691 @@ pub mod visualization_msgs {
             message.try_into()
         }
     }
-    impl std::convert::TryFrom<rosrust::MsgMessage> for Marker {
+    impl std::convert::TryFrom<rosrust::MsgMessage> for Float32 {
         type Error = ();
 
         fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
             use std::convert::TryInto;
             Ok(Self {
-                header: src.remove("header").ok_or(())?.try_into()?,
-                ns: src.remove("ns").ok_or(())?.try_into()?,
-                id: src.remove("id").ok_or(())?.try_into()?,
-                type_: src.remove("type").ok_or(())?.try_into()?,
-                action: src.remove("action").ok_or(())?.try_into()?,
-                pose: src.remove("pose").ok_or(())?.try_into()?,
-                scale: src.remove("scale").ok_or(())?.try_into()?,
-                color: src.remove("color").ok_or(())?.try_into()?,
-                lifetime: src.remove("lifetime").ok_or(())?.try_into()?,
-                frame_locked: src.remove("frame_locked").ok_or(())?.try_into()?,
-                points: src.remove("points").ok_or(())?.try_into()?,
-                colors: src.remove("colors").ok_or(())?.try_into()?,
-                text: src.remove("text").ok_or(())?.try_into()?,
-                mesh_resource: src.remove("mesh_resource").ok_or(())?.try_into()?,
-                mesh_use_embedded_materials: src
-                    .remove("mesh_use_embedded_materials")
-                    .ok_or(())?
-                    .try_into()?,
+                data: src.remove("data").ok_or(())?.try_into()?,
             })
         }
     }
-    impl std::cmp::PartialEq<Self> for Marker {
+    impl std::cmp::PartialEq<Self> for Float32 {
         fn eq(&self, other: &Self) -> bool {
-            true && self.header == other.header
-                && self.ns == other.ns
-                && self.id == other.id
-                && self.type_ == other.type_
-                && self.action == other.action
-                && self.pose == other.pose
-                && self.scale == other.scale
-                && self.color == other.color
-                && self.lifetime == other.lifetime
-                && self.frame_locked == other.frame_locked
-                && self.points == other.points
-                && self.colors == other.colors
-                && self.text == other.text
-                && self.mesh_resource == other.mesh_resource
-                && self.mesh_use_embedded_materials == other.mesh_use_embedded_materials
+            true && self.data == other.data
         }
     }
-    impl std::fmt::Debug for Marker {
+    impl std::fmt::Debug for Float32 {
         fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-            f.debug_struct(stringify!(Marker))
-                .field(stringify!(header), &self.header)
-                .field(stringify!(ns), &self.ns)
-                .field(stringify!(id), &self.id)
-                .field(stringify!(type_), &self.type_)
-                .field(stringify!(action), &self.action)
-                .field(stringify!(pose), &self.pose)
-                .field(stringify!(scale), &self.scale)
-                .field(stringify!(color), &self.color)
-                .field(stringify!(lifetime), &self.lifetime)
-                .field(stringify!(frame_locked), &self.frame_locked)
-                .field(stringify!(points), &self.points)
-                .field(stringify!(colors), &self.colors)
-                .field(stringify!(text), &self.text)
-                .field(stringify!(mesh_resource), &self.mesh_resource)
-                .field(
-                    stringify!(mesh_use_embedded_materials),
-                    &self.mesh_use_embedded_materials,
-                )
+            f.debug_struct(stringify!(Float32))
+                .field(stringify!(data), &self.data)
                 .finish()
         }
     }
-    impl Default for Marker {
+    impl Default for Float32 {
         fn default() -> Self {
             Self {
-                header: Default::default(),
-                ns: Default::default(),
-                id: Default::default(),
-                type_: Default::default(),
-                action: Default::default(),
-                pose: Default::default(),
-                scale: Default::default(),
-                color: Default::default(),
-                lifetime: Default::default(),
-                frame_locked: Default::default(),
-                points: Default::default(),
-                colors: Default::default(),
-                text: Default::default(),
-                mesh_resource: Default::default(),
-                mesh_use_embedded_materials: Default::default(),
+                data: Default::default(),
             }
         }
     }
-    impl rosrust::Message for Marker {
+    impl rosrust::Message for Float32 {
         #[inline]
         fn msg_definition() -> ::std::string::String {
-            "# See http://www.ros.org/wiki/rviz/DisplayTypes/Marker and http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes for more information on using this message with rviz\n\nuint8 ARROW=0\nuint8 CUBE=1\nuint8 SPHERE=2\nuint8 CYLINDER=3\nuint8 LINE_STRIP=4\nuint8 LINE_LIST=5\nuint8 CUBE_LIST=6\nuint8 SPHERE_LIST=7\nuint8 POINTS=8\nuint8 TEXT_VIEW_FACING=9\nuint8 MESH_RESOURCE=10\nuint8 TRIANGLE_LIST=11\n\nuint8 ADD=0\nuint8 MODIFY=0\nuint8 DELETE=2\nuint8 DELETEALL=3\n\nHeader header                        # header for time/frame information\nstring ns                            # Namespace to place this object in... used in conjunction with id to create a unique name for the object\nint32 id \t\t                         # object ID useful in conjunction with the namespace for manipulating and deleting the object later\nint32 type \t\t                       # Type of object\nint32 action \t                       # 0 add/modify an object, 1 (deprecated), 2 deletes an object, 3 deletes all objects\ngeometry_msgs/Pose pose                 # Pose of the object\ngeometry_msgs/Vector3 scale             # Scale of the object 1,1,1 means default (usually 1 meter square)\nstd_msgs/ColorRGBA color             # Color [0.0-1.0]\nduration lifetime                    # How long the object should last before being automatically deleted.  0 means forever\nbool frame_locked                    # If this marker should be frame-locked, i.e. retransformed into its frame every timestep\n\n#Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, ...)\ngeometry_msgs/Point[] points\n#Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, ...)\n#number of colors must either be 0 or equal to the number of points\n#NOTE: alpha is not yet used\nstd_msgs/ColorRGBA[] colors\n\n# NOTE: only used for text markers\nstring text\n\n# NOTE: only used for MESH_RESOURCE markers\nstring mesh_resource\nbool mesh_use_embedded_materials\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: std_msgs/ColorRGBA\nfloat32 r\nfloat32 g\nfloat32 b\nfloat32 a\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n".into()
+            "float32 data\n".into()
         }
 
         #[inline]
         fn md5sum() -> ::std::string::String {
-            "4048c9de2a16f4ae8e0538085ebf1b97".into()
+            "73fcbf46b49191e672908e50842a83d4".into()
         }
 
         #[inline]
         fn msg_type() -> ::std::string::String {
-            "visualization_msgs/Marker".into()
+            "std_msgs/Float32".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for Float32 {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.data.encode(w.by_ref())?;
+            Ok(())
         }
 
-        fn set_header(
-            &mut self,
-            clock: &::std::sync::Arc<dyn rosrust::Clock>,
-            seq: &::std::sync::Arc<::std::sync::atomic::AtomicUsize>,
-        ) {
-            if self.header.seq == 0 {
-                self.header.seq = seq.fetch_add(1, ::std::sync::atomic::Ordering::SeqCst) as u32;
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                data: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct Float64MultiArray {
+        pub layout: MultiArrayLayout,
+        pub data: Vec<f64>,
+    }
+    impl Float64MultiArray {}
+    impl std::convert::From<Float64MultiArray> for rosrust::MsgValue {
+        fn from(src: Float64MultiArray) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<Float64MultiArray> for rosrust::MsgMessage {
+        fn from(src: Float64MultiArray) -> Self {
+            let mut output = Self::new();
+            output.insert("layout".into(), src.layout.into());
+            output.insert("data".into(), src.data.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for Float64MultiArray {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for Float64MultiArray {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                layout: src.remove("layout").ok_or(())?.try_into()?,
+                data: src.remove("data").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for Float64MultiArray {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.layout == other.layout && self.data == other.data
+        }
+    }
+    impl std::fmt::Debug for Float64MultiArray {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(Float64MultiArray))
+                .field(stringify!(layout), &self.layout)
+                .field(stringify!(data), &self.data)
+                .finish()
+        }
+    }
+    impl Default for Float64MultiArray {
+        fn default() -> Self {
+            Self {
+                layout: Default::default(),
+                data: Default::default(),
             }
-            if self.header.stamp.nanos() == 0 {
-                self.header.stamp = clock.now();
+        }
+    }
+    impl rosrust::Message for Float64MultiArray {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "# Please look at the MultiArrayLayout message definition for\n# documentation on all multiarrays.\n\nMultiArrayLayout  layout        # specification of data layout\nfloat64[]         data          # array of data\n\n================================================================================\nMSG: std_msgs/MultiArrayLayout\n# The multiarray declares a generic multi-dimensional array of a\n# particular data type.  Dimensions are ordered from outer most\n# to inner most.\n\nMultiArrayDimension[] dim # Array of dimension properties\nuint32 data_offset        # padding elements at front of data\n\n# Accessors should ALWAYS be written in terms of dimension stride\n# and specified outer-most dimension first.\n# \n# multiarray(i,j,k) = data[data_offset + dim_stride[1]*i + dim_stride[2]*j + k]\n#\n# A standard, 3-channel 640x480 image with interleaved color channels\n# would be specified as:\n#\n# dim[0].label  = \"height\"\n# dim[0].size   = 480\n# dim[0].stride = 3*640*480 = 921600  (note dim[0] stride is just size of image)\n# dim[1].label  = \"width\"\n# dim[1].size   = 640\n# dim[1].stride = 3*640 = 1920\n# dim[2].label  = \"channel\"\n# dim[2].size   = 3\n# dim[2].stride = 3\n#\n# multiarray(i,j,k) refers to the ith row, jth column, and kth channel.\n\n================================================================================\nMSG: std_msgs/MultiArrayDimension\nstring label   # label of given dimension\nuint32 size    # size of given dimension (in type units)\nuint32 stride  # stride of given dimension\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "4b7d974086d4060e7db4613a7e6c3ba4".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "std_msgs/Float64MultiArray".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for Float64MultiArray {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.layout.encode(w.by_ref())?;
+            rosrust::rosmsg::encode_variable_primitive_slice(&self.data, w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                layout: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                data: rosrust::rosmsg::decode_variable_primitive_vec(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct UInt32 {
+        pub data: u32,
+    }
+    impl UInt32 {}
+    impl std::convert::From<UInt32> for rosrust::MsgValue {
+        fn from(src: UInt32) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<UInt32> for rosrust::MsgMessage {
+        fn from(src: UInt32) -> Self {
+            let mut output = Self::new();
+            output.insert("data".into(), src.data.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for UInt32 {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for UInt32 {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                data: src.remove("data").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for UInt32 {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.data == other.data
+        }
+    }
+    impl std::fmt::Debug for UInt32 {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(UInt32))
+                .field(stringify!(data), &self.data)
+                .finish()
+        }
+    }
+    impl Default for UInt32 {
+        fn default() -> Self {
+            Self {
+                data: Default::default(),
             }
         }
     }
-    impl rosrust::rosmsg::RosMsg for Marker {
+    impl rosrust::Message for UInt32 {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "uint32 data\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "304a39449588c7f8ce2df6e8001c5fce".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "std_msgs/UInt32".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for UInt32 {
         fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
-            self.header.encode(w.by_ref())?;
-            self.ns.encode(w.by_ref())?;
-            self.id.encode(w.by_ref())?;
-            self.type_.encode(w.by_ref())?;
-            self.action.encode(w.by_ref())?;
-            self.pose.encode(w.by_ref())?;
-            self.scale.encode(w.by_ref())?;
-            self.color.encode(w.by_ref())?;
-            self.lifetime.encode(w.by_ref())?;
-            self.frame_locked.encode(w.by_ref())?;
-            rosrust::rosmsg::encode_variable_slice(&self.points, w.by_ref())?;
-            rosrust::rosmsg::encode_variable_slice(&self.colors, w.by_ref())?;
-            self.text.encode(w.by_ref())?;
-            self.mesh_resource.encode(w.by_ref())?;
-            self.mesh_use_embedded_materials.encode(w.by_ref())?;
+            self.data.encode(w.by_ref())?;
             Ok(())
         }
 
         fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
             Ok(Self {
-                header: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                ns: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                id: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                type_: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                action: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                pose: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                scale: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                color: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                lifetime: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                frame_locked: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                points: rosrust::rosmsg::decode_variable_vec(r.by_ref())?,
-                colors: rosrust::rosmsg::decode_variable_vec(r.by_ref())?,
-                text: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                mesh_resource: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                mesh_use_embedded_materials: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                data: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct Float64 {
+        pub data: f64,
+    }
+    impl Float64 {}
+    impl std::convert::From<Float64> for rosrust::MsgValue {
+        fn from(src: Float64) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<Float64> for rosrust::MsgMessage {
+        fn from(src: Float64) -> Self {
+            let mut output = Self::new();
+            output.insert("data".into(), src.data.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for Float64 {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for Float64 {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                data: src.remove("data").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for Float64 {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.data == other.data
+        }
+    }
+    impl std::fmt::Debug for Float64 {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(Float64))
+                .field(stringify!(data), &self.data)
+                .finish()
+        }
+    }
+    impl Default for Float64 {
+        fn default() -> Self {
+            Self {
+                data: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for Float64 {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "float64 data\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "fdb28210bfa9d7c91146260178d9a584".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "std_msgs/Float64".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for Float64 {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.data.encode(w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                data: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+}
+pub mod audio_common_msgs {
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct AudioDataStamped {
+        pub header: super::std_msgs::Header,
+        pub audio: super::audio_common_msgs::AudioData,
+    }
+    impl AudioDataStamped {}
+
+    impl std::convert::From<AudioDataStamped> for rosrust::MsgValue {
+        fn from(src: AudioDataStamped) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<AudioDataStamped> for rosrust::MsgMessage {
+        fn from(src: AudioDataStamped) -> Self {
+            let mut output = Self::new();
+            output.insert("header".into(), src.header.into());
+            output.insert("audio".into(), src.audio.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for AudioDataStamped {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for AudioDataStamped {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                header: src.remove("header").ok_or(())?.try_into()?,
+                audio: src.remove("audio").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for AudioDataStamped {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.header == other.header && self.audio == other.audio
+        }
+    }
+    impl std::fmt::Debug for AudioDataStamped {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(AudioDataStamped))
+                .field(stringify!(header), &self.header)
+                .field(stringify!(audio), &self.audio)
+                .finish()
+        }
+    }
+    impl Default for AudioDataStamped {
+        fn default() -> Self {
+            Self {
+                header: Default::default(),
+                audio: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for AudioDataStamped {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "std_msgs/Header header\naudio_common_msgs/AudioData audio\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\nstring frame_id\n\n================================================================================\nMSG: audio_common_msgs/AudioData\nuint8[] data\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "3cdd84a06846af0dca4d0434908f9d96".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "audio_common_msgs/AudioDataStamped".into()
+        }
+
+        fn set_header(
+            &mut self,
+            clock: &::std::sync::Arc<dyn rosrust::Clock>,
+            seq: &::std::sync::Arc<::std::sync::atomic::AtomicUsize>,
+        ) {
+            if self.header.seq == 0 {
+                self.header.seq = seq.fetch_add(1, ::std::sync::atomic::Ordering::SeqCst) as u32;
+            }
+            if self.header.stamp.nanos() == 0 {
+                self.header.stamp = clock.now();
+            }
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for AudioDataStamped {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.header.encode(w.by_ref())?;
+            self.audio.encode(w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                header: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                audio: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct AudioData {
+        pub data: Vec<u8>,
+    }
+    impl AudioData {}
+
+    impl std::convert::From<AudioData> for rosrust::MsgValue {
+        fn from(src: AudioData) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<AudioData> for rosrust::MsgMessage {
+        fn from(src: AudioData) -> Self {
+            let mut output = Self::new();
+            output.insert("data".into(), src.data.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for AudioData {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for AudioData {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                data: src.remove("data").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for AudioData {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.data == other.data
+        }
+    }
+    impl std::fmt::Debug for AudioData {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(AudioData))
+                .field(stringify!(data), &self.data)
+                .finish()
+        }
+    }
+    impl Default for AudioData {
+        fn default() -> Self {
+            Self {
+                data: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for AudioData {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "uint8[] data\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "f43a8e1b362b75baa741461b46adc7e0".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "audio_common_msgs/AudioData".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for AudioData {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            rosrust::rosmsg::encode_variable_primitive_slice(&self.data, w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                data: rosrust::rosmsg::decode_variable_primitive_vec(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct AudioInfo {
+        pub channels: u8,
+        pub sample_rate: u32,
+        pub sample_format: ::std::string::String,
+        pub bitrate: u32,
+        pub coding_format: ::std::string::String,
+    }
+    impl AudioInfo {}
+
+    impl std::convert::From<AudioInfo> for rosrust::MsgValue {
+        fn from(src: AudioInfo) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<AudioInfo> for rosrust::MsgMessage {
+        fn from(src: AudioInfo) -> Self {
+            let mut output = Self::new();
+            output.insert("channels".into(), src.channels.into());
+            output.insert("sample_rate".into(), src.sample_rate.into());
+            output.insert("sample_format".into(), src.sample_format.into());
+            output.insert("bitrate".into(), src.bitrate.into());
+            output.insert("coding_format".into(), src.coding_format.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for AudioInfo {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for AudioInfo {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                channels: src.remove("channels").ok_or(())?.try_into()?,
+                sample_rate: src.remove("sample_rate").ok_or(())?.try_into()?,
+                sample_format: src.remove("sample_format").ok_or(())?.try_into()?,
+                bitrate: src.remove("bitrate").ok_or(())?.try_into()?,
+                coding_format: src.remove("coding_format").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for AudioInfo {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.channels == other.channels
+                && self.sample_rate == other.sample_rate
+                && self.sample_format == other.sample_format
+                && self.bitrate == other.bitrate
+                && self.coding_format == other.coding_format
+        }
+    }
+    impl std::fmt::Debug for AudioInfo {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(AudioInfo))
+                .field(stringify!(channels), &self.channels)
+                .field(stringify!(sample_rate), &self.sample_rate)
+                .field(stringify!(sample_format), &self.sample_format)
+                .field(stringify!(bitrate), &self.bitrate)
+                .field(stringify!(coding_format), &self.coding_format)
+                .finish()
+        }
+    }
+    impl Default for AudioInfo {
+        fn default() -> Self {
+            Self {
+                channels: Default::default(),
+                sample_rate: Default::default(),
+                sample_format: Default::default(),
+                bitrate: Default::default(),
+                coding_format: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for AudioInfo {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "# This message contains the audio meta data\n\n# Number of channels\nuint8 \
+             channels\n# Sampling rate [Hz]\nuint32 sample_rate\n# Audio format (e.g. \
+             S16LE)\nstring sample_format\n# Amount of audio data per second [bits/s]\nuint32 \
+             bitrate\n# Audio coding format (e.g. WAVE, MP3)\nstring coding_format\n"
+                .into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "9413d9b7029680d3b1db6ed0ae535f88".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "audio_common_msgs/AudioInfo".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for AudioInfo {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.channels.encode(w.by_ref())?;
+            self.sample_rate.encode(w.by_ref())?;
+            self.sample_format.encode(w.by_ref())?;
+            self.bitrate.encode(w.by_ref())?;
+            self.coding_format.encode(w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                channels: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                sample_rate: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                sample_format: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                bitrate: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                coding_format: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+}
+pub mod visualization_msgs {
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct Marker {
+        pub header: super::std_msgs::Header,
+        pub ns: ::std::string::String,
+        pub id: i32,
+        pub type_: i32,
+        pub action: i32,
+        pub pose: super::geometry_msgs::Pose,
+        pub scale: super::geometry_msgs::Vector3,
+        pub color: super::std_msgs::ColorRGBA,
+        pub lifetime: rosrust::Duration,
+        pub frame_locked: bool,
+        pub points: Vec<super::geometry_msgs::Point>,
+        pub colors: Vec<super::std_msgs::ColorRGBA>,
+        pub text: ::std::string::String,
+        pub mesh_resource: ::std::string::String,
+        pub mesh_use_embedded_materials: bool,
+    }
+    impl Marker {
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const ADD: u8 = 0u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const ARROW: u8 = 0u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const CUBE: u8 = 1u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const CUBE_LIST: u8 = 6u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const CYLINDER: u8 = 3u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const DELETE: u8 = 2u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const DELETEALL: u8 = 3u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const LINE_LIST: u8 = 5u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const LINE_STRIP: u8 = 4u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const MESH_RESOURCE: u8 = 10u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const MODIFY: u8 = 0u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const POINTS: u8 = 8u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const SPHERE: u8 = 2u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const SPHERE_LIST: u8 = 7u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const TEXT_VIEW_FACING: u8 = 9u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const TRIANGLE_LIST: u8 = 11u8 as u8;
+    }
+    impl std::convert::From<Marker> for rosrust::MsgValue {
+        fn from(src: Marker) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<Marker> for rosrust::MsgMessage {
+        fn from(src: Marker) -> Self {
+            let mut output = Self::new();
+            output.insert("header".into(), src.header.into());
+            output.insert("ns".into(), src.ns.into());
+            output.insert("id".into(), src.id.into());
+            output.insert("type".into(), src.type_.into());
+            output.insert("action".into(), src.action.into());
+            output.insert("pose".into(), src.pose.into());
+            output.insert("scale".into(), src.scale.into());
+            output.insert("color".into(), src.color.into());
+            output.insert("lifetime".into(), src.lifetime.into());
+            output.insert("frame_locked".into(), src.frame_locked.into());
+            output.insert("points".into(), src.points.into());
+            output.insert("colors".into(), src.colors.into());
+            output.insert("text".into(), src.text.into());
+            output.insert("mesh_resource".into(), src.mesh_resource.into());
+            output.insert(
+                "mesh_use_embedded_materials".into(),
+                src.mesh_use_embedded_materials.into(),
+            );
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for Marker {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for Marker {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                header: src.remove("header").ok_or(())?.try_into()?,
+                ns: src.remove("ns").ok_or(())?.try_into()?,
+                id: src.remove("id").ok_or(())?.try_into()?,
+                type_: src.remove("type").ok_or(())?.try_into()?,
+                action: src.remove("action").ok_or(())?.try_into()?,
+                pose: src.remove("pose").ok_or(())?.try_into()?,
+                scale: src.remove("scale").ok_or(())?.try_into()?,
+                color: src.remove("color").ok_or(())?.try_into()?,
+                lifetime: src.remove("lifetime").ok_or(())?.try_into()?,
+                frame_locked: src.remove("frame_locked").ok_or(())?.try_into()?,
+                points: src.remove("points").ok_or(())?.try_into()?,
+                colors: src.remove("colors").ok_or(())?.try_into()?,
+                text: src.remove("text").ok_or(())?.try_into()?,
+                mesh_resource: src.remove("mesh_resource").ok_or(())?.try_into()?,
+                mesh_use_embedded_materials: src
+                    .remove("mesh_use_embedded_materials")
+                    .ok_or(())?
+                    .try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for Marker {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.header == other.header
+                && self.ns == other.ns
+                && self.id == other.id
+                && self.type_ == other.type_
+                && self.action == other.action
+                && self.pose == other.pose
+                && self.scale == other.scale
+                && self.color == other.color
+                && self.lifetime == other.lifetime
+                && self.frame_locked == other.frame_locked
+                && self.points == other.points
+                && self.colors == other.colors
+                && self.text == other.text
+                && self.mesh_resource == other.mesh_resource
+                && self.mesh_use_embedded_materials == other.mesh_use_embedded_materials
+        }
+    }
+    impl std::fmt::Debug for Marker {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(Marker))
+                .field(stringify!(header), &self.header)
+                .field(stringify!(ns), &self.ns)
+                .field(stringify!(id), &self.id)
+                .field(stringify!(type_), &self.type_)
+                .field(stringify!(action), &self.action)
+                .field(stringify!(pose), &self.pose)
+                .field(stringify!(scale), &self.scale)
+                .field(stringify!(color), &self.color)
+                .field(stringify!(lifetime), &self.lifetime)
+                .field(stringify!(frame_locked), &self.frame_locked)
+                .field(stringify!(points), &self.points)
+                .field(stringify!(colors), &self.colors)
+                .field(stringify!(text), &self.text)
+                .field(stringify!(mesh_resource), &self.mesh_resource)
+                .field(
+                    stringify!(mesh_use_embedded_materials),
+                    &self.mesh_use_embedded_materials,
+                )
+                .finish()
+        }
+    }
+    impl Default for Marker {
+        fn default() -> Self {
+            Self {
+                header: Default::default(),
+                ns: Default::default(),
+                id: Default::default(),
+                type_: Default::default(),
+                action: Default::default(),
+                pose: Default::default(),
+                scale: Default::default(),
+                color: Default::default(),
+                lifetime: Default::default(),
+                frame_locked: Default::default(),
+                points: Default::default(),
+                colors: Default::default(),
+                text: Default::default(),
+                mesh_resource: Default::default(),
+                mesh_use_embedded_materials: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for Marker {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "# See http://www.ros.org/wiki/rviz/DisplayTypes/Marker and http://www.ros.org/wiki/rviz/Tutorials/Markers%3A%20Basic%20Shapes for more information on using this message with rviz\n\nuint8 ARROW=0\nuint8 CUBE=1\nuint8 SPHERE=2\nuint8 CYLINDER=3\nuint8 LINE_STRIP=4\nuint8 LINE_LIST=5\nuint8 CUBE_LIST=6\nuint8 SPHERE_LIST=7\nuint8 POINTS=8\nuint8 TEXT_VIEW_FACING=9\nuint8 MESH_RESOURCE=10\nuint8 TRIANGLE_LIST=11\n\nuint8 ADD=0\nuint8 MODIFY=0\nuint8 DELETE=2\nuint8 DELETEALL=3\n\nHeader header                        # header for time/frame information\nstring ns                            # Namespace to place this object in... used in conjunction with id to create a unique name for the object\nint32 id \t\t                         # object ID useful in conjunction with the namespace for manipulating and deleting the object later\nint32 type \t\t                       # Type of object\nint32 action \t                       # 0 add/modify an object, 1 (deprecated), 2 deletes an object, 3 deletes all objects\ngeometry_msgs/Pose pose                 # Pose of the object\ngeometry_msgs/Vector3 scale             # Scale of the object 1,1,1 means default (usually 1 meter square)\nstd_msgs/ColorRGBA color             # Color [0.0-1.0]\nduration lifetime                    # How long the object should last before being automatically deleted.  0 means forever\nbool frame_locked                    # If this marker should be frame-locked, i.e. retransformed into its frame every timestep\n\n#Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, ...)\ngeometry_msgs/Point[] points\n#Only used if the type specified has some use for them (eg. POINTS, LINE_STRIP, ...)\n#number of colors must either be 0 or equal to the number of points\n#NOTE: alpha is not yet used\nstd_msgs/ColorRGBA[] colors\n\n# NOTE: only used for text markers\nstring text\n\n# NOTE: only used for MESH_RESOURCE markers\nstring mesh_resource\nbool mesh_use_embedded_materials\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Pose\n# A representation of pose in free space, composed of position and orientation. \nPoint position\nQuaternion orientation\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: std_msgs/ColorRGBA\nfloat32 r\nfloat32 g\nfloat32 b\nfloat32 a\n\n================================================================================\nMSG: geometry_msgs/Point\n# This contains the position of a point in free space\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "4048c9de2a16f4ae8e0538085ebf1b97".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "visualization_msgs/Marker".into()
+        }
+
+        fn set_header(
+            &mut self,
+            clock: &::std::sync::Arc<dyn rosrust::Clock>,
+            seq: &::std::sync::Arc<::std::sync::atomic::AtomicUsize>,
+        ) {
+            if self.header.seq == 0 {
+                self.header.seq = seq.fetch_add(1, ::std::sync::atomic::Ordering::SeqCst) as u32;
+            }
+            if self.header.stamp.nanos() == 0 {
+                self.header.stamp = clock.now();
+            }
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for Marker {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.header.encode(w.by_ref())?;
+            self.ns.encode(w.by_ref())?;
+            self.id.encode(w.by_ref())?;
+            self.type_.encode(w.by_ref())?;
+            self.action.encode(w.by_ref())?;
+            self.pose.encode(w.by_ref())?;
+            self.scale.encode(w.by_ref())?;
+            self.color.encode(w.by_ref())?;
+            self.lifetime.encode(w.by_ref())?;
+            self.frame_locked.encode(w.by_ref())?;
+            rosrust::rosmsg::encode_variable_slice(&self.points, w.by_ref())?;
+            rosrust::rosmsg::encode_variable_slice(&self.colors, w.by_ref())?;
+            self.text.encode(w.by_ref())?;
+            self.mesh_resource.encode(w.by_ref())?;
+            self.mesh_use_embedded_materials.encode(w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                header: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                ns: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                id: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                type_: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                action: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                pose: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                scale: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                color: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                lifetime: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                frame_locked: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                points: rosrust::rosmsg::decode_variable_vec(r.by_ref())?,
+                colors: rosrust::rosmsg::decode_variable_vec(r.by_ref())?,
+                text: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                mesh_resource: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                mesh_use_embedded_materials: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+}
+pub mod sensor_msgs {
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct CompressedImage {
+        pub header: super::std_msgs::Header,
+        pub format: ::std::string::String,
+        pub data: Vec<u8>,
+    }
+    impl CompressedImage {}
+
+    impl std::convert::From<CompressedImage> for rosrust::MsgValue {
+        fn from(src: CompressedImage) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<CompressedImage> for rosrust::MsgMessage {
+        fn from(src: CompressedImage) -> Self {
+            let mut output = Self::new();
+            output.insert("header".into(), src.header.into());
+            output.insert("format".into(), src.format.into());
+            output.insert("data".into(), src.data.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for CompressedImage {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for CompressedImage {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                header: src.remove("header").ok_or(())?.try_into()?,
+                format: src.remove("format").ok_or(())?.try_into()?,
+                data: src.remove("data").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for CompressedImage {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.header == other.header
+                && self.format == other.format
+                && self.data == other.data
+        }
+    }
+    impl std::fmt::Debug for CompressedImage {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(CompressedImage))
+                .field(stringify!(header), &self.header)
+                .field(stringify!(format), &self.format)
+                .field(stringify!(data), &self.data)
+                .finish()
+        }
+    }
+    impl Default for CompressedImage {
+        fn default() -> Self {
+            Self {
+                header: Default::default(),
+                format: Default::default(),
+                data: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for CompressedImage {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "# This message contains a compressed image\n\nHeader header        # Header timestamp should be acquisition time of image\n                     # Header frame_id should be optical frame of camera\n                     # origin of frame should be optical center of camera\n                     # +x should point to the right in the image\n                     # +y should point down in the image\n                     # +z should point into to plane of the image\n\nstring format        # Specifies the format of the data\n                     #   Acceptable values:\n                     #     jpeg, png\nuint8[] data         # Compressed image buffer\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\nstring frame_id\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "8f7a12909da2c9d3332d540a0977563f".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "sensor_msgs/CompressedImage".into()
+        }
+
+        fn set_header(
+            &mut self,
+            clock: &::std::sync::Arc<dyn rosrust::Clock>,
+            seq: &::std::sync::Arc<::std::sync::atomic::AtomicUsize>,
+        ) {
+            if self.header.seq == 0 {
+                self.header.seq = seq.fetch_add(1, ::std::sync::atomic::Ordering::SeqCst) as u32;
+            }
+            if self.header.stamp.nanos() == 0 {
+                self.header.stamp = clock.now();
+            }
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for CompressedImage {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.header.encode(w.by_ref())?;
+            self.format.encode(w.by_ref())?;
+            rosrust::rosmsg::encode_variable_primitive_slice(&self.data, w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                header: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                format: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                data: rosrust::rosmsg::decode_variable_primitive_vec(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct PointField {
+        pub name: ::std::string::String,
+        pub offset: u32,
+        pub datatype: u8,
+        pub count: u32,
+    }
+    impl PointField {
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const FLOAT32: u8 = 7u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const FLOAT64: u8 = 8u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const INT16: u8 = 3u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const INT32: u8 = 5u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const INT8: u8 = 1u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const UINT16: u8 = 4u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const UINT32: u8 = 6u8 as u8;
+        #[allow(dead_code, non_upper_case_globals)]
+        pub const UINT8: u8 = 2u8 as u8;
+    }
+    impl std::convert::From<PointField> for rosrust::MsgValue {
+        fn from(src: PointField) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<PointField> for rosrust::MsgMessage {
+        fn from(src: PointField) -> Self {
+            let mut output = Self::new();
+            output.insert("name".into(), src.name.into());
+            output.insert("offset".into(), src.offset.into());
+            output.insert("datatype".into(), src.datatype.into());
+            output.insert("count".into(), src.count.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for PointField {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for PointField {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                name: src.remove("name").ok_or(())?.try_into()?,
+                offset: src.remove("offset").ok_or(())?.try_into()?,
+                datatype: src.remove("datatype").ok_or(())?.try_into()?,
+                count: src.remove("count").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for PointField {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.name == other.name
+                && self.offset == other.offset
+                && self.datatype == other.datatype
+                && self.count == other.count
+        }
+    }
+    impl std::fmt::Debug for PointField {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(PointField))
+                .field(stringify!(name), &self.name)
+                .field(stringify!(offset), &self.offset)
+                .field(stringify!(datatype), &self.datatype)
+                .field(stringify!(count), &self.count)
+                .finish()
+        }
+    }
+    impl Default for PointField {
+        fn default() -> Self {
+            Self {
+                name: Default::default(),
+                offset: Default::default(),
+                datatype: Default::default(),
+                count: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for PointField {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "# This message holds the description of one point entry in the\n# PointCloud2 message \
+             format.\nuint8 INT8    = 1\nuint8 UINT8   = 2\nuint8 INT16   = 3\nuint8 UINT16  = \
+             4\nuint8 INT32   = 5\nuint8 UINT32  = 6\nuint8 FLOAT32 = 7\nuint8 FLOAT64 = \
+             8\n\nstring name      # Name of field\nuint32 offset    # Offset from start of point \
+             struct\nuint8  datatype  # Datatype enumeration, see above\nuint32 count     # How \
+             many elements in the field\n"
+                .into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "268eacb2962780ceac86cbd17e328150".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "sensor_msgs/PointField".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for PointField {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.name.encode(w.by_ref())?;
+            self.offset.encode(w.by_ref())?;
+            self.datatype.encode(w.by_ref())?;
+            self.count.encode(w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                name: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                offset: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                datatype: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                count: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct PointCloud2 {
+        pub header: super::std_msgs::Header,
+        pub height: u32,
+        pub width: u32,
+        pub fields: Vec<PointField>,
+        pub is_bigendian: bool,
+        pub point_step: u32,
+        pub row_step: u32,
+        pub data: Vec<u8>,
+        pub is_dense: bool,
+    }
+    impl PointCloud2 {}
+
+    impl std::convert::From<PointCloud2> for rosrust::MsgValue {
+        fn from(src: PointCloud2) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<PointCloud2> for rosrust::MsgMessage {
+        fn from(src: PointCloud2) -> Self {
+            let mut output = Self::new();
+            output.insert("header".into(), src.header.into());
+            output.insert("height".into(), src.height.into());
+            output.insert("width".into(), src.width.into());
+            output.insert("fields".into(), src.fields.into());
+            output.insert("is_bigendian".into(), src.is_bigendian.into());
+            output.insert("point_step".into(), src.point_step.into());
+            output.insert("row_step".into(), src.row_step.into());
+            output.insert("data".into(), src.data.into());
+            output.insert("is_dense".into(), src.is_dense.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for PointCloud2 {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for PointCloud2 {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                header: src.remove("header").ok_or(())?.try_into()?,
+                height: src.remove("height").ok_or(())?.try_into()?,
+                width: src.remove("width").ok_or(())?.try_into()?,
+                fields: src.remove("fields").ok_or(())?.try_into()?,
+                is_bigendian: src.remove("is_bigendian").ok_or(())?.try_into()?,
+                point_step: src.remove("point_step").ok_or(())?.try_into()?,
+                row_step: src.remove("row_step").ok_or(())?.try_into()?,
+                data: src.remove("data").ok_or(())?.try_into()?,
+                is_dense: src.remove("is_dense").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for PointCloud2 {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.header == other.header
+                && self.height == other.height
+                && self.width == other.width
+                && self.fields == other.fields
+                && self.is_bigendian == other.is_bigendian
+                && self.point_step == other.point_step
+                && self.row_step == other.row_step
+                && self.data == other.data
+                && self.is_dense == other.is_dense
+        }
+    }
+    impl std::fmt::Debug for PointCloud2 {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(PointCloud2))
+                .field(stringify!(header), &self.header)
+                .field(stringify!(height), &self.height)
+                .field(stringify!(width), &self.width)
+                .field(stringify!(fields), &self.fields)
+                .field(stringify!(is_bigendian), &self.is_bigendian)
+                .field(stringify!(point_step), &self.point_step)
+                .field(stringify!(row_step), &self.row_step)
+                .field(stringify!(data), &self.data)
+                .field(stringify!(is_dense), &self.is_dense)
+                .finish()
+        }
+    }
+    impl Default for PointCloud2 {
+        fn default() -> Self {
+            Self {
+                header: Default::default(),
+                height: Default::default(),
+                width: Default::default(),
+                fields: Default::default(),
+                is_bigendian: Default::default(),
+                point_step: Default::default(),
+                row_step: Default::default(),
+                data: Default::default(),
+                is_dense: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for PointCloud2 {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "# This message holds a collection of N-dimensional points, which may\n# contain additional information such as normals, intensity, etc. The\n# point data is stored as a binary blob, its layout described by the\n# contents of the \"fields\" array.\n\n# The point cloud data may be organized 2d (image-like) or 1d\n# (unordered). Point clouds organized as 2d images may be produced by\n# camera depth sensors such as stereo or time-of-flight.\n\n# Time of sensor data acquisition, and the coordinate frame ID (for 3d\n# points).\nHeader header\n\n# 2D structure of the point cloud. If the cloud is unordered, height is\n# 1 and width is the length of the point cloud.\nuint32 height\nuint32 width\n\n# Describes the channels and their layout in the binary data blob.\nPointField[] fields\n\nbool    is_bigendian # Is this data bigendian?\nuint32  point_step   # Length of a point in bytes\nuint32  row_step     # Length of a row in bytes\nuint8[] data         # Actual point data, size is (row_step*height)\n\nbool is_dense        # True if there are no invalid points\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\nstring frame_id\n\n================================================================================\nMSG: sensor_msgs/PointField\n# This message holds the description of one point entry in the\n# PointCloud2 message format.\nuint8 INT8    = 1\nuint8 UINT8   = 2\nuint8 INT16   = 3\nuint8 UINT16  = 4\nuint8 INT32   = 5\nuint8 UINT32  = 6\nuint8 FLOAT32 = 7\nuint8 FLOAT64 = 8\n\nstring name      # Name of field\nuint32 offset    # Offset from start of point struct\nuint8  datatype  # Datatype enumeration, see above\nuint32 count     # How many elements in the field\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "1158d486dd51d683ce2f1be655c3c181".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "sensor_msgs/PointCloud2".into()
+        }
+
+        fn set_header(
+            &mut self,
+            clock: &::std::sync::Arc<dyn rosrust::Clock>,
+            seq: &::std::sync::Arc<::std::sync::atomic::AtomicUsize>,
+        ) {
+            if self.header.seq == 0 {
+                self.header.seq = seq.fetch_add(1, ::std::sync::atomic::Ordering::SeqCst) as u32;
+            }
+            if self.header.stamp.nanos() == 0 {
+                self.header.stamp = clock.now();
+            }
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for PointCloud2 {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.header.encode(w.by_ref())?;
+            self.height.encode(w.by_ref())?;
+            self.width.encode(w.by_ref())?;
+            rosrust::rosmsg::encode_variable_slice(&self.fields, w.by_ref())?;
+            self.is_bigendian.encode(w.by_ref())?;
+            self.point_step.encode(w.by_ref())?;
+            self.row_step.encode(w.by_ref())?;
+            rosrust::rosmsg::encode_variable_primitive_slice(&self.data, w.by_ref())?;
+            self.is_dense.encode(w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                header: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                height: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                width: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                fields: rosrust::rosmsg::decode_variable_vec(r.by_ref())?,
+                is_bigendian: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                point_step: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                row_step: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                data: rosrust::rosmsg::decode_variable_primitive_vec(r.by_ref())?,
+                is_dense: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct Image {
+        pub header: super::std_msgs::Header,
+        pub height: u32,
+        pub width: u32,
+        pub encoding: ::std::string::String,
+        pub is_bigendian: u8,
+        pub step: u32,
+        pub data: Vec<u8>,
+    }
+    impl Image {}
+    impl std::convert::From<Image> for rosrust::MsgValue {
+        fn from(src: Image) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<Image> for rosrust::MsgMessage {
+        fn from(src: Image) -> Self {
+            let mut output = Self::new();
+            output.insert("header".into(), src.header.into());
+            output.insert("height".into(), src.height.into());
+            output.insert("width".into(), src.width.into());
+            output.insert("encoding".into(), src.encoding.into());
+            output.insert("is_bigendian".into(), src.is_bigendian.into());
+            output.insert("step".into(), src.step.into());
+            output.insert("data".into(), src.data.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for Image {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for Image {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                header: src.remove("header").ok_or(())?.try_into()?,
+                height: src.remove("height").ok_or(())?.try_into()?,
+                width: src.remove("width").ok_or(())?.try_into()?,
+                encoding: src.remove("encoding").ok_or(())?.try_into()?,
+                is_bigendian: src.remove("is_bigendian").ok_or(())?.try_into()?,
+                step: src.remove("step").ok_or(())?.try_into()?,
+                data: src.remove("data").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for Image {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.header == other.header
+                && self.height == other.height
+                && self.width == other.width
+                && self.encoding == other.encoding
+                && self.is_bigendian == other.is_bigendian
+                && self.step == other.step
+                && self.data == other.data
+        }
+    }
+    impl std::fmt::Debug for Image {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(Image))
+                .field(stringify!(header), &self.header)
+                .field(stringify!(height), &self.height)
+                .field(stringify!(width), &self.width)
+                .field(stringify!(encoding), &self.encoding)
+                .field(stringify!(is_bigendian), &self.is_bigendian)
+                .field(stringify!(step), &self.step)
+                .field(stringify!(data), &self.data)
+                .finish()
+        }
+    }
+    impl Default for Image {
+        fn default() -> Self {
+            Self {
+                header: Default::default(),
+                height: Default::default(),
+                width: Default::default(),
+                encoding: Default::default(),
+                is_bigendian: Default::default(),
+                step: Default::default(),
+                data: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for Image {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "# This message contains an uncompressed image\n# (0, 0) is at top-left corner of image\n#\n\nHeader header        # Header timestamp should be acquisition time of image\n                     # Header frame_id should be optical frame of camera\n                     # origin of frame should be optical center of camera\n                     # +x should point to the right in the image\n                     # +y should point down in the image\n                     # +z should point into to plane of the image\n                     # If the frame_id here and the frame_id of the CameraInfo\n                     # message associated with the image conflict\n                     # the behavior is undefined\n\nuint32 height         # image height, that is, number of rows\nuint32 width          # image width, that is, number of columns\n\n# The legal values for encoding are in file src/image_encodings.cpp\n# If you want to standardize a new string format, join\n# ros-users@lists.sourceforge.net and send an email proposing a new encoding.\n\nstring encoding       # Encoding of pixels -- channel meaning, ordering, size\n                      # taken from the list of strings in include/sensor_msgs/image_encodings.h\n\nuint8 is_bigendian    # is this data bigendian?\nuint32 step           # Full row length in bytes\nuint8[] data          # actual matrix data, size is (step * rows)\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\nstring frame_id\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "060021388200f6f0f447d0fcd9c64743".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "sensor_msgs/Image".into()
+        }
+
+        fn set_header(
+            &mut self,
+            clock: &::std::sync::Arc<dyn rosrust::Clock>,
+            seq: &::std::sync::Arc<::std::sync::atomic::AtomicUsize>,
+        ) {
+            if self.header.seq == 0 {
+                self.header.seq = seq.fetch_add(1, ::std::sync::atomic::Ordering::SeqCst) as u32;
+            }
+            if self.header.stamp.nanos() == 0 {
+                self.header.stamp = clock.now();
+            }
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for Image {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.header.encode(w.by_ref())?;
+            self.height.encode(w.by_ref())?;
+            self.width.encode(w.by_ref())?;
+            self.encoding.encode(w.by_ref())?;
+            self.is_bigendian.encode(w.by_ref())?;
+            self.step.encode(w.by_ref())?;
+            rosrust::rosmsg::encode_variable_primitive_slice(&self.data, w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                header: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                height: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                width: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                encoding: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                is_bigendian: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                step: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                data: rosrust::rosmsg::decode_variable_primitive_vec(r.by_ref())?,
+            })
+        }
+    }
+}
+pub mod std_srvs {
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct TriggerReq {}
+    impl TriggerReq {}
+    impl std::convert::From<TriggerReq> for rosrust::MsgValue {
+        fn from(src: TriggerReq) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<TriggerReq> for rosrust::MsgMessage {
+        fn from(src: TriggerReq) -> Self {
+            let mut output = Self::new();
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for TriggerReq {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for TriggerReq {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {})
+        }
+    }
+    impl std::cmp::PartialEq<Self> for TriggerReq {
+        fn eq(&self, other: &Self) -> bool {
+            true
+        }
+    }
+    impl std::fmt::Debug for TriggerReq {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(TriggerReq)).finish()
+        }
+    }
+    impl Default for TriggerReq {
+        fn default() -> Self {
+            Self {}
+        }
+    }
+    impl rosrust::Message for TriggerReq {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "d41d8cd98f00b204e9800998ecf8427e".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "std_srvs/TriggerReq".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for TriggerReq {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {})
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct TriggerRes {
+        pub success: bool,
+        pub message: ::std::string::String,
+    }
+    impl TriggerRes {}
+    impl std::convert::From<TriggerRes> for rosrust::MsgValue {
+        fn from(src: TriggerRes) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<TriggerRes> for rosrust::MsgMessage {
+        fn from(src: TriggerRes) -> Self {
+            let mut output = Self::new();
+            output.insert("success".into(), src.success.into());
+            output.insert("message".into(), src.message.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for TriggerRes {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for TriggerRes {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                success: src.remove("success").ok_or(())?.try_into()?,
+                message: src.remove("message").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for TriggerRes {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.success == other.success && self.message == other.message
+        }
+    }
+    impl std::fmt::Debug for TriggerRes {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(TriggerRes))
+                .field(stringify!(success), &self.success)
+                .field(stringify!(message), &self.message)
+                .finish()
+        }
+    }
+    impl Default for TriggerRes {
+        fn default() -> Self {
+            Self {
+                success: Default::default(),
+                message: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for TriggerRes {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "bool success   # indicate successful run of triggered service\nstring message # \
+             informational, e.g. for error messages\n"
+                .into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "937c9679a518e3a18d831e57125ea522".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "std_srvs/TriggerRes".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for TriggerRes {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.success.encode(w.by_ref())?;
+            self.message.encode(w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                success: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                message: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug, Default, PartialEq)]
+    pub struct Trigger;
+    impl rosrust::Message for Trigger {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            String::new()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "937c9679a518e3a18d831e57125ea522".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "std_srvs/Trigger".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for Trigger {
+        fn encode<W: ::std::io::Write>(&self, _w: W) -> ::std::io::Result<()> {
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(_r: R) -> ::std::io::Result<Self> {
+            Ok(Self {})
+        }
+    }
+    impl rosrust::ServicePair for Trigger {
+        type Request = TriggerReq;
+        type Response = TriggerRes;
+    }
+}
+pub mod ssloc_ros {
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct Detection {
+        pub azimuth: f64,
+        pub elevation: f64,
+        pub strength: f64,
+        pub confidence: f64,
+        pub snr: f64,
+        pub id: i64,
+    }
+    impl Detection {}
+    impl std::convert::From<Detection> for rosrust::MsgValue {
+        fn from(src: Detection) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<Detection> for rosrust::MsgMessage {
+        fn from(src: Detection) -> Self {
+            let mut output = Self::new();
+            output.insert("azimuth".into(), src.azimuth.into());
+            output.insert("elevation".into(), src.elevation.into());
+            output.insert("strength".into(), src.strength.into());
+            output.insert("confidence".into(), src.confidence.into());
+            output.insert("snr".into(), src.snr.into());
+            output.insert("id".into(), src.id.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for Detection {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for Detection {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                azimuth: src.remove("azimuth").ok_or(())?.try_into()?,
+                elevation: src.remove("elevation").ok_or(())?.try_into()?,
+                strength: src.remove("strength").ok_or(())?.try_into()?,
+                confidence: src.remove("confidence").ok_or(())?.try_into()?,
+                snr: src.remove("snr").ok_or(())?.try_into()?,
+                id: src.remove("id").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for Detection {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.azimuth == other.azimuth
+                && self.elevation == other.elevation
+                && self.strength == other.strength
+                && self.confidence == other.confidence
+                && self.snr == other.snr
+                && self.id == other.id
+        }
+    }
+    impl std::fmt::Debug for Detection {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(Detection))
+                .field(stringify!(azimuth), &self.azimuth)
+                .field(stringify!(elevation), &self.elevation)
+                .field(stringify!(strength), &self.strength)
+                .field(stringify!(confidence), &self.confidence)
+                .field(stringify!(snr), &self.snr)
+                .field(stringify!(id), &self.id)
+                .finish()
+        }
+    }
+    impl Default for Detection {
+        fn default() -> Self {
+            Self {
+                azimuth: Default::default(),
+                elevation: Default::default(),
+                strength: Default::default(),
+                confidence: Default::default(),
+                snr: Default::default(),
+                id: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for Detection {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "# direction in radians\nfloat64 azimuth\nfloat64 elevation\nfloat64 strength\n# \
+             strength relative to the strongest source of the cycle\nfloat64 confidence\n# peak \
+             over the surrounding background of the angular spectrum in dB, 0 unless\n# \
+             `output/snr` is enabled\nfloat64 snr\nint64 id\n"
+                .into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "de5434a9b7893fba2f99b0d867996c11".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "ssloc_ros/Detection".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for Detection {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.azimuth.encode(w.by_ref())?;
+            self.elevation.encode(w.by_ref())?;
+            self.strength.encode(w.by_ref())?;
+            self.confidence.encode(w.by_ref())?;
+            self.snr.encode(w.by_ref())?;
+            self.id.encode(w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                azimuth: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                elevation: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                strength: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                confidence: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                snr: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                id: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct Detections {
+        pub header: super::std_msgs::Header,
+        pub count: u32,
+        pub sources: Vec<Detection>,
+        pub processing_time: f64,
+        pub latency: f64,
+    }
+    impl Detections {}
+    impl std::convert::From<Detections> for rosrust::MsgValue {
+        fn from(src: Detections) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<Detections> for rosrust::MsgMessage {
+        fn from(src: Detections) -> Self {
+            let mut output = Self::new();
+            output.insert("header".into(), src.header.into());
+            output.insert("count".into(), src.count.into());
+            output.insert("sources".into(), src.sources.into());
+            output.insert("processing_time".into(), src.processing_time.into());
+            output.insert("latency".into(), src.latency.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for Detections {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for Detections {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                header: src.remove("header").ok_or(())?.try_into()?,
+                count: src.remove("count").ok_or(())?.try_into()?,
+                sources: src.remove("sources").ok_or(())?.try_into()?,
+                processing_time: src.remove("processing_time").ok_or(())?.try_into()?,
+                latency: src.remove("latency").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for Detections {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.header == other.header
+                && self.count == other.count
+                && self.sources == other.sources
+                && self.processing_time == other.processing_time
+                && self.latency == other.latency
+        }
+    }
+    impl std::fmt::Debug for Detections {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(Detections))
+                .field(stringify!(header), &self.header)
+                .field(stringify!(count), &self.count)
+                .field(stringify!(sources), &self.sources)
+                .field(stringify!(processing_time), &self.processing_time)
+                .field(stringify!(latency), &self.latency)
+                .finish()
+        }
+    }
+    impl Default for Detections {
+        fn default() -> Self {
+            Self {
+                header: Default::default(),
+                count: Default::default(),
+                sources: Default::default(),
+                processing_time: Default::default(),
+                latency: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for Detections {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "# all detections of one localization cycle\nHeader header\nuint32 count\nDetection[] sources\n# seconds spent analyzing the recording\nfloat64 processing_time\n# seconds between the recording stamp and publishing\nfloat64 latency\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\nstring frame_id\n\n================================================================================\nMSG: ssloc_ros/Detection\n# direction in radians\nfloat64 azimuth\nfloat64 elevation\nfloat64 strength\n# strength relative to the strongest source of the cycle\nfloat64 confidence\n# peak over the surrounding background of the angular spectrum in dB, 0 unless\n# `output/snr` is enabled\nfloat64 snr\nint64 id\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "6a73ff37d477856510e70d196d2578c9".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "ssloc_ros/Detections".into()
+        }
+
+        fn set_header(
+            &mut self,
+            clock: &::std::sync::Arc<dyn rosrust::Clock>,
+            seq: &::std::sync::Arc<::std::sync::atomic::AtomicUsize>,
+        ) {
+            if self.header.seq == 0 {
+                self.header.seq = seq.fetch_add(1, ::std::sync::atomic::Ordering::SeqCst) as u32;
+            }
+            if self.header.stamp.nanos() == 0 {
+                self.header.stamp = clock.now();
+            }
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for Detections {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.header.encode(w.by_ref())?;
+            self.count.encode(w.by_ref())?;
+            rosrust::rosmsg::encode_variable_slice(&self.sources, w.by_ref())?;
+            self.processing_time.encode(w.by_ref())?;
+            self.latency.encode(w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                header: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                count: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                sources: rosrust::rosmsg::decode_variable_vec(r.by_ref())?,
+                processing_time: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                latency: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug, Default, PartialEq)]
+    pub struct Capture;
+    impl rosrust::Message for Capture {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            String::new()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "62b8b89515bbf32b45b9fb76106af45d".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "ssloc_ros/Capture".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for Capture {
+        fn encode<W: ::std::io::Write>(&self, _w: W) -> ::std::io::Result<()> {
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(_r: R) -> ::std::io::Result<Self> {
+            Ok(Self {})
+        }
+    }
+    impl rosrust::ServicePair for Capture {
+        type Request = CaptureReq;
+        type Response = CaptureRes;
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct CaptureReq {
+        pub duration: f64,
+    }
+    impl CaptureReq {}
+    impl std::convert::From<CaptureReq> for rosrust::MsgValue {
+        fn from(src: CaptureReq) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<CaptureReq> for rosrust::MsgMessage {
+        fn from(src: CaptureReq) -> Self {
+            let mut output = Self::new();
+            output.insert("duration".into(), src.duration.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for CaptureReq {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for CaptureReq {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                duration: src.remove("duration").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for CaptureReq {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.duration == other.duration
+        }
+    }
+    impl std::fmt::Debug for CaptureReq {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(CaptureReq))
+                .field(stringify!(duration), &self.duration)
+                .finish()
+        }
+    }
+    impl Default for CaptureReq {
+        fn default() -> Self {
+            Self {
+                duration: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for CaptureReq {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "float64 duration # seconds of audio to record\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "efce4fd63555f8abdd034fb55c87e5aa".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "ssloc_ros/CaptureReq".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for CaptureReq {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.duration.encode(w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                duration: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct CaptureRes {
+        pub success: bool,
+        pub message: ::std::string::String,
+        pub audio: Vec<u8>,
+    }
+    impl CaptureRes {}
+    impl std::convert::From<CaptureRes> for rosrust::MsgValue {
+        fn from(src: CaptureRes) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<CaptureRes> for rosrust::MsgMessage {
+        fn from(src: CaptureRes) -> Self {
+            let mut output = Self::new();
+            output.insert("success".into(), src.success.into());
+            output.insert("message".into(), src.message.into());
+            output.insert("audio".into(), src.audio.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for CaptureRes {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for CaptureRes {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                success: src.remove("success").ok_or(())?.try_into()?,
+                message: src.remove("message").ok_or(())?.try_into()?,
+                audio: src.remove("audio").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for CaptureRes {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.success == other.success
+                && self.message == other.message
+                && self.audio == other.audio
+        }
+    }
+    impl std::fmt::Debug for CaptureRes {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(CaptureRes))
+                .field(stringify!(success), &self.success)
+                .field(stringify!(message), &self.message)
+                .field(stringify!(audio), &self.audio)
+                .finish()
+        }
+    }
+    impl Default for CaptureRes {
+        fn default() -> Self {
+            Self {
+                success: Default::default(),
+                message: Default::default(),
+                audio: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for CaptureRes {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "bool success   # whether the audio was recorded\nstring message # informational, e.g. \
+             for error messages\nuint8[] audio  # WAV file with 32 bit float samples\n"
+                .into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "4cbb39ed8b816fb8e2f60f81f7c3409f".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "ssloc_ros/CaptureRes".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for CaptureRes {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.success.encode(w.by_ref())?;
+            self.message.encode(w.by_ref())?;
+            rosrust::rosmsg::encode_variable_primitive_slice(&self.audio, w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                success: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                message: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                audio: rosrust::rosmsg::decode_variable_primitive_vec(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct Device {
+        pub name: ::std::string::String,
+        pub description: ::std::string::String,
+        pub min_channels: u16,
+        pub max_channels: u16,
+        pub min_rate: u32,
+        pub max_rate: u32,
+        pub formats: Vec<::std::string::String>,
+    }
+    impl Device {}
+    impl std::convert::From<Device> for rosrust::MsgValue {
+        fn from(src: Device) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<Device> for rosrust::MsgMessage {
+        fn from(src: Device) -> Self {
+            let mut output = Self::new();
+            output.insert("name".into(), src.name.into());
+            output.insert("description".into(), src.description.into());
+            output.insert("min_channels".into(), src.min_channels.into());
+            output.insert("max_channels".into(), src.max_channels.into());
+            output.insert("min_rate".into(), src.min_rate.into());
+            output.insert("max_rate".into(), src.max_rate.into());
+            output.insert("formats".into(), src.formats.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for Device {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for Device {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                name: src.remove("name").ok_or(())?.try_into()?,
+                description: src.remove("description").ok_or(())?.try_into()?,
+                min_channels: src.remove("min_channels").ok_or(())?.try_into()?,
+                max_channels: src.remove("max_channels").ok_or(())?.try_into()?,
+                min_rate: src.remove("min_rate").ok_or(())?.try_into()?,
+                max_rate: src.remove("max_rate").ok_or(())?.try_into()?,
+                formats: src.remove("formats").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for Device {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.name == other.name
+                && self.description == other.description
+                && self.min_channels == other.min_channels
+                && self.max_channels == other.max_channels
+                && self.min_rate == other.min_rate
+                && self.max_rate == other.max_rate
+                && self.formats == other.formats
+        }
+    }
+    impl std::fmt::Debug for Device {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(Device))
+                .field(stringify!(name), &self.name)
+                .field(stringify!(description), &self.description)
+                .field(stringify!(min_channels), &self.min_channels)
+                .field(stringify!(max_channels), &self.max_channels)
+                .field(stringify!(min_rate), &self.min_rate)
+                .field(stringify!(max_rate), &self.max_rate)
+                .field(stringify!(formats), &self.formats)
+                .finish()
+        }
+    }
+    impl Default for Device {
+        fn default() -> Self {
+            Self {
+                name: Default::default(),
+                description: Default::default(),
+                min_channels: Default::default(),
+                max_channels: Default::default(),
+                min_rate: Default::default(),
+                max_rate: Default::default(),
+                formats: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for Device {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "# ALSA name, as expected by `recording/device`\nstring name\nstring \
+             description\nuint16 min_channels\nuint16 max_channels\nuint32 min_rate\nuint32 \
+             max_rate\n# supported sample formats, e.g. S16 or F32\nstring[] formats\n"
+                .into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "2c740cdda73a6441638f393a33e4ef7f".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "ssloc_ros/Device".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for Device {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.name.encode(w.by_ref())?;
+            self.description.encode(w.by_ref())?;
+            self.min_channels.encode(w.by_ref())?;
+            self.max_channels.encode(w.by_ref())?;
+            self.min_rate.encode(w.by_ref())?;
+            self.max_rate.encode(w.by_ref())?;
+            rosrust::rosmsg::encode_variable_slice(&self.formats, w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                name: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                description: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                min_channels: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                max_channels: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                min_rate: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                max_rate: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                formats: rosrust::rosmsg::decode_variable_vec(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug, Default, PartialEq)]
+    pub struct ListDevices;
+    impl rosrust::Message for ListDevices {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            String::new()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "fece6cd517a12a3c18ff221d83ff923d".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "ssloc_ros/ListDevices".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for ListDevices {
+        fn encode<W: ::std::io::Write>(&self, _w: W) -> ::std::io::Result<()> {
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(_r: R) -> ::std::io::Result<Self> {
+            Ok(Self {})
+        }
+    }
+    impl rosrust::ServicePair for ListDevices {
+        type Request = ListDevicesReq;
+        type Response = ListDevicesRes;
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct ListDevicesReq {}
+    impl ListDevicesReq {}
+    impl std::convert::From<ListDevicesReq> for rosrust::MsgValue {
+        fn from(src: ListDevicesReq) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<ListDevicesReq> for rosrust::MsgMessage {
+        fn from(src: ListDevicesReq) -> Self {
+            let mut output = Self::new();
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for ListDevicesReq {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for ListDevicesReq {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {})
+        }
+    }
+    impl std::cmp::PartialEq<Self> for ListDevicesReq {
+        fn eq(&self, other: &Self) -> bool {
+            true
+        }
+    }
+    impl std::fmt::Debug for ListDevicesReq {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(ListDevicesReq)).finish()
+        }
+    }
+    impl Default for ListDevicesReq {
+        fn default() -> Self {
+            Self {}
+        }
+    }
+    impl rosrust::Message for ListDevicesReq {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "d41d8cd98f00b204e9800998ecf8427e".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "ssloc_ros/ListDevicesReq".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for ListDevicesReq {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {})
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct ListDevicesRes {
+        pub success: bool,
+        pub message: ::std::string::String,
+        pub devices: Vec<Device>,
+    }
+    impl ListDevicesRes {}
+    impl std::convert::From<ListDevicesRes> for rosrust::MsgValue {
+        fn from(src: ListDevicesRes) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<ListDevicesRes> for rosrust::MsgMessage {
+        fn from(src: ListDevicesRes) -> Self {
+            let mut output = Self::new();
+            output.insert("success".into(), src.success.into());
+            output.insert("message".into(), src.message.into());
+            output.insert("devices".into(), src.devices.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for ListDevicesRes {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for ListDevicesRes {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                success: src.remove("success").ok_or(())?.try_into()?,
+                message: src.remove("message").ok_or(())?.try_into()?,
+                devices: src.remove("devices").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for ListDevicesRes {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.success == other.success
+                && self.message == other.message
+                && self.devices == other.devices
+        }
+    }
+    impl std::fmt::Debug for ListDevicesRes {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(ListDevicesRes))
+                .field(stringify!(success), &self.success)
+                .field(stringify!(message), &self.message)
+                .field(stringify!(devices), &self.devices)
+                .finish()
+        }
+    }
+    impl Default for ListDevicesRes {
+        fn default() -> Self {
+            Self {
+                success: Default::default(),
+                message: Default::default(),
+                devices: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for ListDevicesRes {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "bool success     # whether the devices could be enumerated\nstring message   # informational, e.g. for error messages\nDevice[] devices # capture devices\n\n================================================================================\nMSG: ssloc_ros/Device\n# ALSA name, as expected by `recording/device`\nstring name\nstring description\nuint16 min_channels\nuint16 max_channels\nuint32 min_rate\nuint32 max_rate\n# supported sample formats, e.g. S16 or F32\nstring[] formats\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "fece6cd517a12a3c18ff221d83ff923d".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "ssloc_ros/ListDevicesRes".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for ListDevicesRes {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.success.encode(w.by_ref())?;
+            self.message.encode(w.by_ref())?;
+            rosrust::rosmsg::encode_variable_slice(&self.devices, w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                success: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                message: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                devices: rosrust::rosmsg::decode_variable_vec(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug, Default, PartialEq)]
+    pub struct ValidateConfig;
+    impl rosrust::Message for ValidateConfig {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            String::new()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "63d49a350fc7deb9f871fabe40ea4048".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "ssloc_ros/ValidateConfig".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for ValidateConfig {
+        fn encode<W: ::std::io::Write>(&self, _w: W) -> ::std::io::Result<()> {
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(_r: R) -> ::std::io::Result<Self> {
+            Ok(Self {})
+        }
+    }
+    impl rosrust::ServicePair for ValidateConfig {
+        type Request = ValidateConfigReq;
+        type Response = ValidateConfigRes;
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct ValidateConfigReq {
+        pub config_file: ::std::string::String,
+    }
+    impl ValidateConfigReq {}
+    impl std::convert::From<ValidateConfigReq> for rosrust::MsgValue {
+        fn from(src: ValidateConfigReq) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<ValidateConfigReq> for rosrust::MsgMessage {
+        fn from(src: ValidateConfigReq) -> Self {
+            let mut output = Self::new();
+            output.insert("config_file".into(), src.config_file.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for ValidateConfigReq {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for ValidateConfigReq {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                config_file: src.remove("config_file").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for ValidateConfigReq {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.config_file == other.config_file
+        }
+    }
+    impl std::fmt::Debug for ValidateConfigReq {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(ValidateConfigReq))
+                .field(stringify!(config_file), &self.config_file)
+                .finish()
+        }
+    }
+    impl Default for ValidateConfigReq {
+        fn default() -> Self {
+            Self {
+                config_file: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for ValidateConfigReq {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "string config_file # YAML file applied to the current configuration, empty to \
+             validate it as is\n"
+                .into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "90949894c75d4db440cc7a08c4bf47dd".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "ssloc_ros/ValidateConfigReq".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for ValidateConfigReq {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.config_file.encode(w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                config_file: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct ValidateConfigRes {
+        pub success: bool,
+        pub message: ::std::string::String,
+    }
+    impl ValidateConfigRes {}
+    impl std::convert::From<ValidateConfigRes> for rosrust::MsgValue {
+        fn from(src: ValidateConfigRes) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<ValidateConfigRes> for rosrust::MsgMessage {
+        fn from(src: ValidateConfigRes) -> Self {
+            let mut output = Self::new();
+            output.insert("success".into(), src.success.into());
+            output.insert("message".into(), src.message.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for ValidateConfigRes {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for ValidateConfigRes {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                success: src.remove("success").ok_or(())?.try_into()?,
+                message: src.remove("message").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for ValidateConfigRes {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.success == other.success && self.message == other.message
+        }
+    }
+    impl std::fmt::Debug for ValidateConfigRes {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(ValidateConfigRes))
+                .field(stringify!(success), &self.success)
+                .field(stringify!(message), &self.message)
+                .finish()
+        }
+    }
+    impl Default for ValidateConfigRes {
+        fn default() -> Self {
+            Self {
+                success: Default::default(),
+                message: Default::default(),
+            }
+        }
+    }
+    impl rosrust::Message for ValidateConfigRes {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "bool success   # whether the configuration is valid\nstring message # every problem \
+             found, one per line\n"
+                .into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "937c9679a518e3a18d831e57125ea522".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "ssloc_ros/ValidateConfigRes".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for ValidateConfigRes {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.success.encode(w.by_ref())?;
+            self.message.encode(w.by_ref())?;
+            Ok(())
+        }
+
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                success: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                message: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
             })
         }
     }
 }
-pub mod sensor_msgs {
+pub mod diagnostic_msgs {
     #[allow(dead_code, non_camel_case_types, non_snake_case)]
     #[derive(Clone)]
-    pub struct CompressedImage {
+    pub struct DiagnosticArray {
         pub header: super::std_msgs::Header,
-        pub format: ::std::string::String,
-        pub data: Vec<u8>,
+        pub status: Vec<DiagnosticStatus>,
     }
-    impl CompressedImage {}
-
-    impl std::convert::From<CompressedImage> for rosrust::MsgValue {
-        fn from(src: CompressedImage) -> Self {
+    impl DiagnosticArray {}
+    impl std::convert::From<DiagnosticArray> for rosrust::MsgValue {
+        fn from(src: DiagnosticArray) -> Self {
             rosrust::MsgValue::Message(src.into())
         }
     }
-    impl std::convert::From<CompressedImage> for rosrust::MsgMessage {
-        fn from(src: CompressedImage) -> Self {
+    impl std::convert::From<DiagnosticArray> for rosrust::MsgMessage {
+        fn from(src: DiagnosticArray) -> Self {
             let mut output = Self::new();
             output.insert("header".into(), src.header.into());
-            output.insert("format".into(), src.format.into());
-            output.insert("data".into(), src.data.into());
+            output.insert("status".into(), src.status.into());
             output
         }
     }
-    impl std::convert::TryFrom<rosrust::MsgValue> for CompressedImage {
+    impl std::convert::TryFrom<rosrust::MsgValue> for DiagnosticArray {
         type Error = ();
 
         fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
@@ -2354,57 +5096,52 @@ pub mod sensor_msgs {
             message.try_into()
         }
     }
-    impl std::convert::TryFrom<rosrust::MsgMessage> for CompressedImage {
+    impl std::convert::TryFrom<rosrust::MsgMessage> for DiagnosticArray {
         type Error = ();
 
         fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
             use std::convert::TryInto;
             Ok(Self {
                 header: src.remove("header").ok_or(())?.try_into()?,
-                format: src.remove("format").ok_or(())?.try_into()?,
-                data: src.remove("data").ok_or(())?.try_into()?,
+                status: src.remove("status").ok_or(())?.try_into()?,
             })
         }
     }
-    impl std::cmp::PartialEq<Self> for CompressedImage {
+    impl std::cmp::PartialEq<Self> for DiagnosticArray {
         fn eq(&self, other: &Self) -> bool {
-            true && self.header == other.header
-                && self.format == other.format
-                && self.data == other.data
+            true && self.header == other.header && self.status == other.status
         }
     }
-    impl std::fmt::Debug for CompressedImage {
+    impl std::fmt::Debug for DiagnosticArray {
         fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-            f.debug_struct(stringify!(CompressedImage))
+            f.debug_struct(stringify!(DiagnosticArray))
                 .field(stringify!(header), &self.header)
-                .field(stringify!(format), &self.format)
-                .field(stringify!(data), &self.data)
+                .field(stringify!(status), &self.status)
                 .finish()
         }
     }
-    impl Default for CompressedImage {
+    impl Default for DiagnosticArray {
         fn default() -> Self {
             Self {
                 header: Default::default(),
-                format: Default::default(),
-                data: Default::default(),
+                status: Default::default(),
             }
         }
     }
-    impl rosrust::Message for CompressedImage {
+    impl rosrust::Message for DiagnosticArray {
         #[inline]
         fn msg_definition() -> ::std::string::String {
-            "# This message contains a compressed image\n\nHeader header        # Header timestamp should be acquisition time of image\n                     # Header frame_id should be optical frame of camera\n                     # origin of frame should be optical center of camera\n                     # +x should point to the right in the image\n                     # +y should point down in the image\n                     # +z should point into to plane of the image\n\nstring format        # Specifies the format of the data\n                     #   Acceptable values:\n                     #     jpeg, png\nuint8[] data         # Compressed image buffer\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\nstring frame_id\n".into()
+            "# This message is used to send diagnostic information about the state of the robot\nHeader header #for timestamp\nDiagnosticStatus[] status # an array of components being reported on\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\nstring frame_id\n\n================================================================================\nMSG: diagnostic_msgs/DiagnosticStatus\n# This message holds the status of an individual component of the robot.\n# \n\n# Possible levels of operations\nbyte OK=0\nbyte WARN=1\nbyte ERROR=2\nbyte STALE=3\n\nbyte level # level of operation enumerated above \nstring name # a description of the test/component reporting\nstring message # a description of the status\nstring hardware_id # a hardware unique string\nKeyValue[] values # an array of values associated with the status\n\n================================================================================\nMSG: diagnostic_msgs/KeyValue\nstring key # what to label this value when viewing\nstring value # a value to track over time\n".into()
         }
 
         #[inline]
         fn md5sum() -> ::std::string::String {
-            "8f7a12909da2c9d3332d540a0977563f".into()
+            "60810da900de1dd6ddd437c3503511da".into()
         }
 
         #[inline]
         fn msg_type() -> ::std::string::String {
-            "sensor_msgs/CompressedImage".into()
+            "diagnostic_msgs/DiagnosticArray".into()
         }
 
         fn set_header(
@@ -2420,64 +5157,56 @@ pub mod sensor_msgs {
             }
         }
     }
-    impl rosrust::rosmsg::RosMsg for CompressedImage {
+    impl rosrust::rosmsg::RosMsg for DiagnosticArray {
         fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
             self.header.encode(w.by_ref())?;
-            self.format.encode(w.by_ref())?;
-            rosrust::rosmsg::encode_variable_primitive_slice(&self.data, w.by_ref())?;
+            rosrust::rosmsg::encode_variable_slice(&self.status, w.by_ref())?;
             Ok(())
         }
 
         fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
             Ok(Self {
                 header: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                format: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                data: rosrust::rosmsg::decode_variable_primitive_vec(r.by_ref())?,
+                status: rosrust::rosmsg::decode_variable_vec(r.by_ref())?,
             })
         }
     }
     #[allow(dead_code, non_camel_case_types, non_snake_case)]
     #[derive(Clone)]
-    pub struct PointField {
+    pub struct DiagnosticStatus {
+        pub level: i8,
         pub name: ::std::string::String,
-        pub offset: u32,
-        pub datatype: u8,
-        pub count: u32,
+        pub message: ::std::string::String,
+        pub hardware_id: ::std::string::String,
+        pub values: Vec<KeyValue>,
     }
-    impl PointField {
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const FLOAT32: u8 = 7u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const FLOAT64: u8 = 8u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const INT16: u8 = 3u8 as u8;
-        #[allow(dead_code, non_upper_case_globals)]
-        pub const INT32: u8 = 5u8 as u8;
+    impl DiagnosticStatus {
         #[allow(dead_code, non_upper_case_globals)]
-        pub const INT8: u8 = 1u8 as u8;
+        pub const ERROR: i8 = 2i8 as i8;
         #[allow(dead_code, non_upper_case_globals)]
-        pub const UINT16: u8 = 4u8 as u8;
+        pub const OK: i8 = 0i8 as i8;
         #[allow(dead_code, non_upper_case_globals)]
-        pub const UINT32: u8 = 6u8 as u8;
+        pub const STALE: i8 = 3i8 as i8;
         #[allow(dead_code, non_upper_case_globals)]
-        pub const UINT8: u8 = 2u8 as u8;
+        pub const WARN: i8 = 1i8 as i8;
     }
-    impl std::convert::From<PointField> for rosrust::MsgValue {
-        fn from(src: PointField) -> Self {
+    impl std::convert::From<DiagnosticStatus> for rosrust::MsgValue {
+        fn from(src: DiagnosticStatus) -> Self {
             rosrust::MsgValue::Message(src.into())
         }
     }
-    impl std::convert::From<PointField> for rosrust::MsgMessage {
-        fn from(src: PointField) -> Self {
+    impl std::convert::From<DiagnosticStatus> for rosrust::MsgMessage {
+        fn from(src: DiagnosticStatus) -> Self {
             let mut output = Self::new();
+            output.insert("level".into(), src.level.into());
             output.insert("name".into(), src.name.into());
-            output.insert("offset".into(), src.offset.into());
-            output.insert("datatype".into(), src.datatype.into());
-            output.insert("count".into(), src.count.into());
+            output.insert("message".into(), src.message.into());
+            output.insert("hardware_id".into(), src.hardware_id.into());
+            output.insert("values".into(), src.values.into());
             output
         }
     }
-    impl std::convert::TryFrom<rosrust::MsgValue> for PointField {
+    impl std::convert::TryFrom<rosrust::MsgValue> for DiagnosticStatus {
         type Error = ();
 
         fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
@@ -2486,123 +5215,108 @@ pub mod sensor_msgs {
             message.try_into()
         }
     }
-    impl std::convert::TryFrom<rosrust::MsgMessage> for PointField {
+    impl std::convert::TryFrom<rosrust::MsgMessage> for DiagnosticStatus {
         type Error = ();
 
         fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
             use std::convert::TryInto;
             Ok(Self {
+                level: src.remove("level").ok_or(())?.try_into()?,
                 name: src.remove("name").ok_or(())?.try_into()?,
-                offset: src.remove("offset").ok_or(())?.try_into()?,
-                datatype: src.remove("datatype").ok_or(())?.try_into()?,
-                count: src.remove("count").ok_or(())?.try_into()?,
+                message: src.remove("message").ok_or(())?.try_into()?,
+                hardware_id: src.remove("hardware_id").ok_or(())?.try_into()?,
+                values: src.remove("values").ok_or(())?.try_into()?,
             })
         }
     }
-    impl std::cmp::PartialEq<Self> for PointField {
+    impl std::cmp::PartialEq<Self> for DiagnosticStatus {
         fn eq(&self, other: &Self) -> bool {
-            true && self.name == other.name
-                && self.offset == other.offset
-                && self.datatype == other.datatype
-                && self.count == other.count
+            true && self.level == other.level
+                && self.name == other.name
+                && self.message == other.message
+                && self.hardware_id == other.hardware_id
+                && self.values == other.values
         }
     }
-    impl std::fmt::Debug for PointField {
+    impl std::fmt::Debug for DiagnosticStatus {
         fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-            f.debug_struct(stringify!(PointField))
+            f.debug_struct(stringify!(DiagnosticStatus))
+                .field(stringify!(level), &self.level)
                 .field(stringify!(name), &self.name)
-                .field(stringify!(offset), &self.offset)
-                .field(stringify!(datatype), &self.datatype)
-                .field(stringify!(count), &self.count)
+                .field(stringify!(message), &self.message)
+                .field(stringify!(hardware_id), &self.hardware_id)
+                .field(stringify!(values), &self.values)
                 .finish()
         }
     }
-    impl Default for PointField {
+    impl Default for DiagnosticStatus {
         fn default() -> Self {
             Self {
+                level: Default::default(),
                 name: Default::default(),
-                offset: Default::default(),
-                datatype: Default::default(),
-                count: Default::default(),
+                message: Default::default(),
+                hardware_id: Default::default(),
+                values: Default::default(),
             }
         }
     }
-    impl rosrust::Message for PointField {
+    impl rosrust::Message for DiagnosticStatus {
         #[inline]
         fn msg_definition() -> ::std::string::String {
-            "# This message holds the description of one point entry in the\n# PointCloud2 message \
-             format.\nuint8 INT8    = 1\nuint8 UINT8   = 2\nuint8 INT16   = 3\nuint8 UINT16  = \
-             4\nuint8 INT32   = 5\nuint8 UINT32  = 6\nuint8 FLOAT32 = 7\nuint8 FLOAT64 = \
-             8\n\nstring name      # Name of field\nuint32 offset    # Offset from start of point \
-             struct\nuint8  datatype  # Datatype enumeration, see above\nuint32 count     # How \
-             many elements in the field\n"
-                .into()
+            "# This message holds the status of an individual component of the robot.\n# \n\n# Possible levels of operations\nbyte OK=0\nbyte WARN=1\nbyte ERROR=2\nbyte STALE=3\n\nbyte level # level of operation enumerated above \nstring name # a description of the test/component reporting\nstring message # a description of the status\nstring hardware_id # a hardware unique string\nKeyValue[] values # an array of values associated with the status\n\n================================================================================\nMSG: diagnostic_msgs/KeyValue\nstring key # what to label this value when viewing\nstring value # a value to track over time\n".into()
         }
 
         #[inline]
         fn md5sum() -> ::std::string::String {
-            "268eacb2962780ceac86cbd17e328150".into()
+            "d0ce08bc6e5ba34c7754f563a9cabaf1".into()
         }
 
         #[inline]
         fn msg_type() -> ::std::string::String {
-            "sensor_msgs/PointField".into()
+            "diagnostic_msgs/DiagnosticStatus".into()
         }
     }
-    impl rosrust::rosmsg::RosMsg for PointField {
+    impl rosrust::rosmsg::RosMsg for DiagnosticStatus {
         fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.level.encode(w.by_ref())?;
             self.name.encode(w.by_ref())?;
-            self.offset.encode(w.by_ref())?;
-            self.datatype.encode(w.by_ref())?;
-            self.count.encode(w.by_ref())?;
+            self.message.encode(w.by_ref())?;
+            self.hardware_id.encode(w.by_ref())?;
+            rosrust::rosmsg::encode_variable_slice(&self.values, w.by_ref())?;
             Ok(())
         }
 
         fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
             Ok(Self {
+                level: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
                 name: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                offset: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                datatype: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                count: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                message: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                hardware_id: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                values: rosrust::rosmsg::decode_variable_vec(r.by_ref())?,
             })
         }
     }
     #[allow(dead_code, non_camel_case_types, non_snake_case)]
     #[derive(Clone)]
-    pub struct PointCloud2 {
-        pub header: super::std_msgs::Header,
-        pub height: u32,
-        pub width: u32,
-        pub fields: Vec<PointField>,
-        pub is_bigendian: bool,
-        pub point_step: u32,
-        pub row_step: u32,
-        pub data: Vec<u8>,
-        pub is_dense: bool,
+    pub struct KeyValue {
+        pub key: ::std::string::String,
+        pub value: ::std::string::String,
     }
-    impl PointCloud2 {}
-
-    impl std::convert::From<PointCloud2> for rosrust::MsgValue {
-        fn from(src: PointCloud2) -> Self {
+    impl KeyValue {}
+    impl std::convert::From<KeyValue> for rosrust::MsgValue {
+        fn from(src: KeyValue) -> Self {
             rosrust::MsgValue::Message(src.into())
         }
     }
-    impl std::convert::From<PointCloud2> for rosrust::MsgMessage {
-        fn from(src: PointCloud2) -> Self {
+    impl std::convert::From<KeyValue> for rosrust::MsgMessage {
+        fn from(src: KeyValue) -> Self {
             let mut output = Self::new();
-            output.insert("header".into(), src.header.into());
-            output.insert("height".into(), src.height.into());
-            output.insert("width".into(), src.width.into());
-            output.insert("fields".into(), src.fields.into());
-            output.insert("is_bigendian".into(), src.is_bigendian.into());
-            output.insert("point_step".into(), src.point_step.into());
-            output.insert("row_step".into(), src.row_step.into());
-            output.insert("data".into(), src.data.into());
-            output.insert("is_dense".into(), src.is_dense.into());
+            output.insert("key".into(), src.key.into());
+            output.insert("value".into(), src.value.into());
             output
         }
     }
-    impl std::convert::TryFrom<rosrust::MsgValue> for PointCloud2 {
+    impl std::convert::TryFrom<rosrust::MsgValue> for KeyValue {
         type Error = ();
 
         fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
@@ -2611,129 +5325,173 @@ pub mod sensor_msgs {
             message.try_into()
         }
     }
-    impl std::convert::TryFrom<rosrust::MsgMessage> for PointCloud2 {
+    impl std::convert::TryFrom<rosrust::MsgMessage> for KeyValue {
         type Error = ();
 
         fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
             use std::convert::TryInto;
             Ok(Self {
-                header: src.remove("header").ok_or(())?.try_into()?,
-                height: src.remove("height").ok_or(())?.try_into()?,
-                width: src.remove("width").ok_or(())?.try_into()?,
-                fields: src.remove("fields").ok_or(())?.try_into()?,
-                is_bigendian: src.remove("is_bigendian").ok_or(())?.try_into()?,
-                point_step: src.remove("point_step").ok_or(())?.try_into()?,
-                row_step: src.remove("row_step").ok_or(())?.try_into()?,
-                data: src.remove("data").ok_or(())?.try_into()?,
-                is_dense: src.remove("is_dense").ok_or(())?.try_into()?,
+                key: src.remove("key").ok_or(())?.try_into()?,
+                value: src.remove("value").ok_or(())?.try_into()?,
             })
         }
     }
-    impl std::cmp::PartialEq<Self> for PointCloud2 {
+    impl std::cmp::PartialEq<Self> for KeyValue {
         fn eq(&self, other: &Self) -> bool {
-            true && self.header == other.header
-                && self.height == other.height
-                && self.width == other.width
-                && self.fields == other.fields
-                && self.is_bigendian == other.is_bigendian
-                && self.point_step == other.point_step
-                && self.row_step == other.row_step
-                && self.data == other.data
-                && self.is_dense == other.is_dense
+            true && self.key == other.key && self.value == other.value
         }
     }
-    impl std::fmt::Debug for PointCloud2 {
+    impl std::fmt::Debug for KeyValue {
         fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-            f.debug_struct(stringify!(PointCloud2))
-                .field(stringify!(header), &self.header)
-                .field(stringify!(height), &self.height)
-                .field(stringify!(width), &self.width)
-                .field(stringify!(fields), &self.fields)
-                .field(stringify!(is_bigendian), &self.is_bigendian)
-                .field(stringify!(point_step), &self.point_step)
-                .field(stringify!(row_step), &self.row_step)
-                .field(stringify!(data), &self.data)
-                .field(stringify!(is_dense), &self.is_dense)
+            f.debug_struct(stringify!(KeyValue))
+                .field(stringify!(key), &self.key)
+                .field(stringify!(value), &self.value)
                 .finish()
         }
     }
-    impl Default for PointCloud2 {
+    impl Default for KeyValue {
         fn default() -> Self {
             Self {
-                header: Default::default(),
-                height: Default::default(),
-                width: Default::default(),
-                fields: Default::default(),
-                is_bigendian: Default::default(),
-                point_step: Default::default(),
-                row_step: Default::default(),
-                data: Default::default(),
-                is_dense: Default::default(),
+                key: Default::default(),
+                value: Default::default(),
             }
         }
     }
-    impl rosrust::Message for PointCloud2 {
+    impl rosrust::Message for KeyValue {
         #[inline]
         fn msg_definition() -> ::std::string::String {
-            "# This message holds a collection of N-dimensional points, which may\n# contain additional information such as normals, intensity, etc. The\n# point data is stored as a binary blob, its layout described by the\n# contents of the \"fields\" array.\n\n# The point cloud data may be organized 2d (image-like) or 1d\n# (unordered). Point clouds organized as 2d images may be produced by\n# camera depth sensors such as stereo or time-of-flight.\n\n# Time of sensor data acquisition, and the coordinate frame ID (for 3d\n# points).\nHeader header\n\n# 2D structure of the point cloud. If the cloud is unordered, height is\n# 1 and width is the length of the point cloud.\nuint32 height\nuint32 width\n\n# Describes the channels and their layout in the binary data blob.\nPointField[] fields\n\nbool    is_bigendian # Is this data bigendian?\nuint32  point_step   # Length of a point in bytes\nuint32  row_step     # Length of a row in bytes\nuint8[] data         # Actual point data, size is (row_step*height)\n\nbool is_dense        # True if there are no invalid points\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\nstring frame_id\n\n================================================================================\nMSG: sensor_msgs/PointField\n# This message holds the description of one point entry in the\n# PointCloud2 message format.\nuint8 INT8    = 1\nuint8 UINT8   = 2\nuint8 INT16   = 3\nuint8 UINT16  = 4\nuint8 INT32   = 5\nuint8 UINT32  = 6\nuint8 FLOAT32 = 7\nuint8 FLOAT64 = 8\n\nstring name      # Name of field\nuint32 offset    # Offset from start of point struct\nuint8  datatype  # Datatype enumeration, see above\nuint32 count     # How many elements in the field\n".into()
+            "string key # what to label this value when viewing\nstring value # a value to track \
+             over time\n"
+                .into()
         }
 
         #[inline]
         fn md5sum() -> ::std::string::String {
-            "1158d486dd51d683ce2f1be655c3c181".into()
+            "cf57fdc6617a881a88c16e768132149c".into()
         }
 
         #[inline]
         fn msg_type() -> ::std::string::String {
-            "sensor_msgs/PointCloud2".into()
+            "diagnostic_msgs/KeyValue".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for KeyValue {
+        fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
+            self.key.encode(w.by_ref())?;
+            self.value.encode(w.by_ref())?;
+            Ok(())
         }
 
-        fn set_header(
-            &mut self,
-            clock: &::std::sync::Arc<dyn rosrust::Clock>,
-            seq: &::std::sync::Arc<::std::sync::atomic::AtomicUsize>,
-        ) {
-            if self.header.seq == 0 {
-                self.header.seq = seq.fetch_add(1, ::std::sync::atomic::Ordering::SeqCst) as u32;
-            }
-            if self.header.stamp.nanos() == 0 {
-                self.header.stamp = clock.now();
+        fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
+            Ok(Self {
+                key: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                value: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+            })
+        }
+    }
+}
+pub mod tf2_msgs {
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct TFMessage {
+        pub transforms: Vec<super::geometry_msgs::TransformStamped>,
+    }
+    impl TFMessage {}
+    impl std::convert::From<TFMessage> for rosrust::MsgValue {
+        fn from(src: TFMessage) -> Self {
+            rosrust::MsgValue::Message(src.into())
+        }
+    }
+    impl std::convert::From<TFMessage> for rosrust::MsgMessage {
+        fn from(src: TFMessage) -> Self {
+            let mut output = Self::new();
+            output.insert("transforms".into(), src.transforms.into());
+            output
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgValue> for TFMessage {
+        type Error = ();
+
+        fn try_from(src: rosrust::MsgValue) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            let message: rosrust::MsgMessage = src.try_into()?;
+            message.try_into()
+        }
+    }
+    impl std::convert::TryFrom<rosrust::MsgMessage> for TFMessage {
+        type Error = ();
+
+        fn try_from(mut src: rosrust::MsgMessage) -> Result<Self, ()> {
+            use std::convert::TryInto;
+            Ok(Self {
+                transforms: src.remove("transforms").ok_or(())?.try_into()?,
+            })
+        }
+    }
+    impl std::cmp::PartialEq<Self> for TFMessage {
+        fn eq(&self, other: &Self) -> bool {
+            true && self.transforms == other.transforms
+        }
+    }
+    impl std::fmt::Debug for TFMessage {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(stringify!(TFMessage))
+                .field(stringify!(transforms), &self.transforms)
+                .finish()
+        }
+    }
+    impl Default for TFMessage {
+        fn default() -> Self {
+            Self {
+                transforms: Default::default(),
             }
         }
     }
-    impl rosrust::rosmsg::RosMsg for PointCloud2 {
+    impl rosrust::Message for TFMessage {
+        #[inline]
+        fn msg_definition() -> ::std::string::String {
+            "geometry_msgs/TransformStamped[] transforms\n\n================================================================================\nMSG: geometry_msgs/TransformStamped\n# This expresses a transform from coordinate frame header.frame_id\n# to the coordinate frame child_frame_id\n#\n# This message is mostly used by the \n# <a href=\"http://wiki.ros.org/tf\">tf</a> package. \n# See its documentation for more information.\n\nHeader header\nstring child_frame_id # the frame id of the child frame\nTransform transform\n\n================================================================================\nMSG: std_msgs/Header\n# Standard metadata for higher-level stamped data types.\n# This is generally used to communicate timestamped data \n# in a particular coordinate frame.\n# \n# sequence ID: consecutively increasing ID \nuint32 seq\n#Two-integer timestamp that is expressed as:\n# * stamp.sec: seconds (stamp_secs) since epoch (in Python the variable is called 'secs')\n# * stamp.nsec: nanoseconds since stamp_secs (in Python the variable is called 'nsecs')\n# time-handling sugar is provided by the client library\ntime stamp\n#Frame this data is associated with\nstring frame_id\n\n================================================================================\nMSG: geometry_msgs/Transform\n# This represents the transform between two coordinate frames in free space.\n\nVector3 translation\nQuaternion rotation\n\n================================================================================\nMSG: geometry_msgs/Vector3\n# This represents a vector in free space. \n# It is only meant to represent a direction. Therefore, it does not\n# make sense to apply a translation to it (e.g., when applying a \n# generic rigid transformation to a Vector3, tf2 will only apply the\n# rotation). If you want your data to be translatable too, use the\n# geometry_msgs/Point message instead.\n\nfloat64 x\nfloat64 y\nfloat64 z\n\n================================================================================\nMSG: geometry_msgs/Quaternion\n# This represents an orientation in free space in quaternion form.\n\nfloat64 x\nfloat64 y\nfloat64 z\nfloat64 w\n".into()
+        }
+
+        #[inline]
+        fn md5sum() -> ::std::string::String {
+            "94810edda583a504dfda3829e70d7eec".into()
+        }
+
+        #[inline]
+        fn msg_type() -> ::std::string::String {
+            "tf2_msgs/TFMessage".into()
+        }
+    }
+    impl rosrust::rosmsg::RosMsg for TFMessage {
         fn encode<W: ::std::io::Write>(&self, mut w: W) -> ::std::io::Result<()> {
-            self.header.encode(w.by_ref())?;
-            self.height.encode(w.by_ref())?;
-            self.width.encode(w.by_ref())?;
-            rosrust::rosmsg::encode_variable_slice(&self.fields, w.by_ref())?;
-            self.is_bigendian.encode(w.by_ref())?;
-            self.point_step.encode(w.by_ref())?;
-            self.row_step.encode(w.by_ref())?;
-            rosrust::rosmsg::encode_variable_primitive_slice(&self.data, w.by_ref())?;
-            self.is_dense.encode(w.by_ref())?;
+            rosrust::rosmsg::encode_variable_slice(&self.transforms, w.by_ref())?;
             Ok(())
         }
 
         fn decode<R: ::std::io::Read>(mut r: R) -> ::std::io::Result<Self> {
             Ok(Self {
-                header: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                height: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                width: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                fields: rosrust::rosmsg::decode_variable_vec(r.by_ref())?,
-                is_bigendian: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                point_step: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                row_step: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
-                data: rosrust::rosmsg::decode_variable_primitive_vec(r.by_ref())?,
-                is_dense: rosrust::rosmsg::RosMsg::decode(r.by_ref())?,
+                transforms: rosrust::rosmsg::decode_variable_vec(r.by_ref())?,
             })
         }
     }
 }
 pub use audio_common_msgs::{AudioData, AudioDataStamped, AudioInfo};
-pub use geometry_msgs::{Point, Pose, PoseArray, Quaternion, Vector3};
+pub use diagnostic_msgs::{DiagnosticArray, DiagnosticStatus, KeyValue};
+pub use geometry_msgs::{
+    Point, Pose, PoseArray, PoseStamped, Quaternion, Transform, TransformStamped, Vector3,
+};
 pub use odas_ros::{OdasSsl, OdasSslArrayStamped, OdasSst, OdasSstArrayStamped};
-pub use sensor_msgs::{CompressedImage, PointCloud2, PointField};
+pub use sensor_msgs::{CompressedImage, Image, PointCloud2, PointField};
+pub use ssloc_ros::{
+    Capture, CaptureRes, Detection, Detections, Device, ListDevices, ListDevicesRes, ValidateConfig,
+    ValidateConfigReq, ValidateConfigRes,
+};
 pub use ssloc_ros_msgs::{Ssl, SslArray, SssMapping, Sst, SstArray};
-pub use std_msgs::{ColorRGBA, Header};
+pub use std_msgs::{
+    ColorRGBA, Float32, Float32MultiArray, Float64, Float64MultiArray, Header, MultiArrayDimension,
+    MultiArrayLayout, UInt32,
+};
+pub use std_srvs::{Trigger, TriggerRes};
+pub use tf2_msgs::TFMessage;
 pub use visualization_msgs::Marker;
@@ -0,0 +1,264 @@
+//! Sources the recorder takes frames from, the ALSA device, a [`FileReplay`]
+//! of a WAV file or a [`MockRecorder`] replaying synthesized recordings, so
+//! the analysis can be run without any audio hardware.
+use std::convert::Infallible;
+use std::f64::consts::TAU;
+use std::fmt::{self, Display};
+
+use ssloc::{Audio, AudioRecorder, Direction, Position, F};
+
+use crate::audio::AudioExt;
+use crate::wav::Wav;
+
+pub trait AudioSource {
+    type Error: Display;
+
+    /// Records the next frame.
+    fn record(&mut self) -> Result<Audio, Self::Error>;
+}
+
+macro_rules! audio_recorder {
+    ($($format:ty),*) => {$(
+        impl AudioSource for AudioRecorder<$format> {
+            type Error = alsa::Error;
+
+            fn record(&mut self) -> Result<Audio, Self::Error> {
+                AudioRecorder::record(self)
+            }
+        }
+    )*};
+}
+audio_recorder!(i8, u8, i16, u16, i32, u32, f32, f64);
+
+/// Sources replayed paced to real time instead of recorded.
+pub enum Replay {
+    File(FileReplay),
+    Mock(MockRecorder),
+}
+
+impl AudioSource for Replay {
+    type Error = ReplayEnd;
+
+    fn record(&mut self) -> Result<Audio, Self::Error> {
+        match self {
+            Self::File(file) => file.record(),
+            Self::Mock(mock) => mock.record().map_err(|never| match never {}),
+        }
+    }
+}
+
+/// Why a [`FileReplay`] has no further frames.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ReplayEnd {
+    /// Reached the end of the file without looping.
+    Finished,
+    /// Looping a file shorter than one frame.
+    TooShort,
+}
+
+impl Display for ReplayEnd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Finished => write!(f, "reached the end of the file"),
+            Self::TooShort => write!(f, "the file is shorter than one frame"),
+        }
+    }
+}
+
+/// Replays a WAV file in frames of a fixed length, dropping a shorter rest
+/// at its end.
+pub struct FileReplay {
+    wav: Wav,
+    frame: usize,
+    repeat: bool,
+    position: usize,
+}
+
+impl FileReplay {
+    /// Replays `wav` in frames of `frame` samples, restarting at its end if
+    /// `repeat`.
+    pub fn new(wav: Wav, frame: usize, repeat: bool) -> Self {
+        Self {
+            wav,
+            frame: frame.max(1),
+            repeat,
+            position: 0,
+        }
+    }
+}
+
+impl AudioSource for FileReplay {
+    type Error = ReplayEnd;
+
+    fn record(&mut self) -> Result<Audio, Self::Error> {
+        if self.position + self.frame > self.wav.samples() {
+            if !self.repeat {
+                return Err(ReplayEnd::Finished);
+            }
+            if self.frame > self.wav.samples() {
+                return Err(ReplayEnd::TooShort);
+            }
+            self.position = 0;
+        }
+        let audio = self.wav.audio(self.position, self.frame);
+        self.position += self.frame;
+        Ok(audio)
+    }
+}
+
+/// Replays a fixed sequence of frames, starting over after the last one.
+pub struct MockRecorder {
+    frames: Vec<Audio>,
+    next: usize,
+}
+
+impl MockRecorder {
+    /// Lowest frequency of [`Self::tone`], all others are multiples of it.
+    pub const FUNDAMENTAL: F = 250.;
+    /// Highest frequency of [`Self::tone`], below the spatial aliasing of
+    /// typical arrays.
+    pub const MAX_FREQUENCY: F = 4000.;
+
+    /// Replays `frames` in order.
+    ///
+    /// # Panics
+    /// If `frames` is empty.
+    pub fn new(frames: Vec<Audio>) -> Self {
+        assert!(!frames.is_empty(), "nothing to replay");
+        Self { frames, next: 0 }
+    }
+
+    /// `count` consecutive frames of `samples` samples at `rate` of a plane
+    /// wave arriving at `mics` from `direction`.
+    ///
+    /// The wave is a sum of harmonics of [`Self::FUNDAMENTAL`] with fixed
+    /// pseudo random phases, i.e. periodic and broadband enough for the
+    /// GCC-PHAT to resolve the delays between the mics.
+    pub fn tone(
+        mics: &[Position],
+        rate: u32,
+        speed_of_sound: F,
+        direction: Direction,
+        samples: usize,
+        count: usize,
+    ) -> Self {
+        let rate = F::from(rate);
+        let harmonics = (Self::MAX_FREQUENCY.min(rate / 2.) / Self::FUNDAMENTAL) as usize;
+        let harmonics: Vec<(F, F)> = (1..=harmonics)
+            .map(|harmonic| {
+                // golden ratio phases avoid a peaky sum of the harmonics
+                let phase = (harmonic as F * 0.618_033_988_75).fract() * TAU;
+                (harmonic as F * Self::FUNDAMENTAL, phase)
+            })
+            .collect();
+        let amplitude = 0.5 / (harmonics.len().max(1) as F).sqrt();
+        let towards = direction.to_unit_vec();
+        let frames = (0..count)
+            .map(|frame| {
+                let start = frame * samples;
+                Audio::from_channels(
+                    rate,
+                    mics.iter().map(|mic| {
+                        // mics closer to the source receive the wave earlier
+                        let lead = mic.dot(&towards) / speed_of_sound;
+                        let harmonics = &harmonics;
+                        (start..start + samples).map(move |sample| {
+                            let time = sample as F / rate + lead;
+                            harmonics
+                                .iter()
+                                .map(|(frequency, phase)| (TAU * frequency * time + phase).sin())
+                                .sum::<F>()
+                                * amplitude
+                        })
+                    }),
+                )
+            })
+            .collect();
+        Self::new(frames)
+    }
+}
+
+impl AudioSource for MockRecorder {
+    type Error = Infallible;
+
+    fn record(&mut self) -> Result<Audio, Self::Error> {
+        let frame = self.frames[self.next].duplicate();
+        self.next = (self.next + 1) % self.frames.len();
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::vector;
+    use ssloc::{Audio, Direction, MbssConfig, Position, F};
+
+    use super::{AudioSource, FileReplay, MockRecorder, ReplayEnd};
+    use crate::audio::AudioExt;
+    use crate::spectrum;
+    use crate::wav::{self, Wav};
+
+    const RATE: u32 = 16_000;
+
+    /// Direction of the strongest source `mbss` finds in a tone from
+    /// `direction`, recorded by a tetrahedron of mics 20 cm across.
+    fn locate(mbss: MbssConfig, direction: Direction) -> Direction {
+        let mics: Vec<Position> = [[1., 1., 1.], [1., -1., -1.], [-1., 1., -1.], [-1., -1., 1.]]
+            .iter()
+            .map(|&[x, y, z]| vector![x, y, z] * 0.1 / F::sqrt(2.))
+            .collect();
+        let mut recorder =
+            MockRecorder::tone(&mics, RATE, mbss.speed_of_sound, direction, 4096, 1);
+        let Ok(audio) = recorder.record();
+        let mbss = mbss.create(mics);
+        let spectrum = mbss.analyze_spectrum(&audio);
+        mbss.find_sources(spectrum.view(), 1)[0].0
+    }
+
+    #[test]
+    fn locates_the_mock_tone() {
+        let mbss = MbssConfig::default();
+        for (azimuth, elevation) in [(60., 20.), (-120., 0.), (170., -30.), (-10., 45.)] {
+            let direction = Direction::new(F::to_radians(azimuth), F::to_radians(elevation));
+            let found = locate(mbss, direction);
+            // the array resolves the elevation only coarsely, the azimuth
+            // within the resolution of the pairwise angles
+            let error = spectrum::great_circle(
+                Direction::new(found.azimuth, 0.),
+                Direction::new(direction.azimuth, 0.),
+            );
+            assert!(
+                error < 2. * mbss.alpha_res,
+                "{direction:?} found at {found:?}, {:.1}° off in azimuth",
+                error.to_degrees()
+            );
+        }
+    }
+
+    #[test]
+    fn replays_files_in_frames() {
+        let audio = Audio::from_channels(
+            RATE.into(),
+            (0..2).map(|channel| (0..10).map(move |sample| F::from(channel * 10 + sample) / 32.)),
+        );
+        let wav = || Wav::parse(&wav::encode(&audio)).unwrap();
+        let first = |replay: &mut FileReplay| replay.record().unwrap().channel(0)[0] * 32.;
+
+        let mut replay = FileReplay::new(wav(), 4, false);
+        assert_eq!(first(&mut replay), 0.);
+        assert_eq!(first(&mut replay), 4.);
+        // the rest of 2 samples is dropped
+        assert_eq!(replay.record().unwrap_err(), ReplayEnd::Finished);
+        assert_eq!(replay.record().unwrap_err(), ReplayEnd::Finished);
+
+        let mut replay = FileReplay::new(wav(), 4, true);
+        let starts: Vec<F> = (0..4).map(|_| first(&mut replay)).collect();
+        assert_eq!(starts, [0., 4., 0., 4.]);
+        let recorded = replay.record().unwrap();
+        assert_eq!((recorded.channels(), recorded.samples()), (2, 4));
+        assert_eq!(recorded.channel(1)[0] * 32., 10.);
+
+        let mut replay = FileReplay::new(wav(), 11, true);
+        assert_eq!(replay.record().unwrap_err(), ReplayEnd::TooShort);
+    }
+}
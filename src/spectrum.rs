@@ -0,0 +1,436 @@
+use std::collections::VecDeque;
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+use ndarray::{Array1, Array2, ArrayView2, Axis};
+use ssloc::{Audio, Direction, MbssConfig, F};
+
+use crate::config::Reduction;
+
+/// STFT window length of [`ssloc::Mbss::analyze_spectrum`] at `sample_rate`,
+/// 64 ms rounded up to a power of two.
+pub fn window_len(sample_rate: F) -> usize {
+    2usize.pow((0.064 * sample_rate).log2().ceil() as u32)
+}
+
+/// Returns whether `audio` is long enough for
+/// [`ssloc::Mbss::analyze_spectrum`], which panics on recordings that do
+/// not fit at least one STFT window (after truncating odd lengths by a window),
+/// of `window` samples or [`window_len`].
+pub fn analyzable(audio: &Audio, window: Option<usize>) -> bool {
+    let window = window.unwrap_or_else(|| window_len(audio.sample_rate()));
+    let samples = audio.samples();
+    samples > audio.channels() && samples >= window * (1 + samples % 2)
+}
+
+/// Running estimate of the noise floor of angular spectra, the exponential
+/// moving average of their medians.
+#[derive(Debug, Default)]
+pub struct NoiseFloor {
+    estimate: Option<F>,
+}
+
+impl NoiseFloor {
+    /// Updates the estimate with `spectrum`, `rate` is the weight of the new
+    /// median, and returns the new estimate.
+    pub fn update(&mut self, spectrum: ArrayView2<F>, rate: F) -> F {
+        let mut values: Vec<_> = spectrum.iter().copied().collect();
+        if values.is_empty() {
+            return self.estimate.unwrap_or_default();
+        }
+        let mid = values.len() / 2;
+        let (_, &mut median, _) = values.select_nth_unstable_by(mid, F::total_cmp);
+        let estimate = match self.estimate {
+            Some(estimate) => estimate + rate * (median - estimate),
+            None => median,
+        };
+        self.estimate = Some(estimate);
+        estimate
+    }
+}
+
+/// Rolling history of the azimuth marginals of the last frames, for the
+/// azimuth over time image on `spectrogram`.
+#[derive(Debug, Default)]
+pub struct Spectrogram {
+    /// Oldest first.
+    columns: VecDeque<Array1<F>>,
+}
+
+impl Spectrogram {
+    /// Appends the `marginal` of the latest frame, keeping `frames` of them,
+    /// and returns the history as azimuths × frames, oldest first. Frames
+    /// not yet recorded are 0, a marginal with a different number of azimuths
+    /// restarts the history.
+    pub fn push(&mut self, marginal: Array1<F>, frames: usize) -> Array2<F> {
+        let azimuths = marginal.len();
+        if self.columns.front().is_some_and(|column| column.len() != azimuths) {
+            self.columns.clear();
+        }
+        self.columns.push_back(marginal);
+        while self.columns.len() > frames.max(1) {
+            self.columns.pop_front();
+        }
+        let padding = frames.max(1) - self.columns.len();
+        let mut history = Array2::zeros((azimuths, frames.max(1)));
+        for (column, marginal) in self.columns.iter().enumerate() {
+            history.column_mut(padding + column).assign(marginal);
+        }
+        history
+    }
+}
+
+/// Linearly interpolates the value at fractional index `i` of `len` samples,
+/// `wrap` connects the last sample to the first.
+fn interpolate(i: F, len: usize, wrap: bool, sample: impl Fn(usize) -> F) -> Option<F> {
+    if i < 0. || (!wrap && i > (len - 1) as F) || i >= len as F {
+        return None;
+    }
+    let a = i.floor() as usize;
+    let b = if a + 1 == len { if wrap { 0 } else { a } } else { a + 1 };
+    let lerp = i - a as F;
+    Some(sample(a) * (1. - lerp) + sample(b) * lerp)
+}
+
+/// Resamples an angular `spectrum` (elevation × azimuth, as produced by
+/// [`ssloc::Mbss::analyze_spectrum`]) onto a fixed grid of `elevations` ×
+/// `azimuths` that covers the whole sphere, independent of the resolution and
+/// ranges in `mbss`.
+///
+/// Cell centers are at `-π + (i + 0.5) * 2π / azimuths` and `-π/2 + (j + 0.5) *
+/// π / elevations`. Directions outside the searched ranges are `0`.
+pub fn resample(
+    spectrum: ArrayView2<F>,
+    mbss: &MbssConfig,
+    elevations: usize,
+    azimuths: usize,
+) -> Array2<F> {
+    let (n_elevations, n_azimuths) = spectrum.dim();
+    let full_circle = n_azimuths as F * mbss.grid_res >= TAU - mbss.grid_res / 2.;
+    Array2::from_shape_fn((elevations, azimuths), |(el, az)| {
+        let elevation = -FRAC_PI_2 + (el as F + 0.5) * PI / elevations as F;
+        let azimuth = -PI + (az as F + 0.5) * TAU / azimuths as F;
+        let el = (elevation - mbss.elevation_range.0) / mbss.grid_res;
+        let az = (azimuth - mbss.azimuth_range.0).rem_euclid(TAU) / mbss.grid_res;
+        interpolate(el, n_elevations, false, |el| {
+            interpolate(az, n_azimuths, full_circle, |az| spectrum[(el, az)]).unwrap_or_default()
+        })
+        .unwrap_or_default()
+    })
+}
+
+/// All local maxima of an angular `spectrum` the peak picker of
+/// [`ssloc::Mbss::find_sources`] considers, strongest first.
+///
+/// Like there, a cell is a maximum if no neighbor is larger, and strengths are
+/// relative to the minimum of the spectrum.
+pub fn local_maxima(spectrum: ArrayView2<F>, mbss: &MbssConfig) -> Vec<(Direction, F)> {
+    let (n_elevations, n_azimuths) = spectrum.dim();
+    let min = spectrum.iter().copied().fold(F::INFINITY, F::min);
+    let mut maxima = Vec::new();
+    for ((el, az), &value) in spectrum.indexed_iter() {
+        let neighbors = spectrum.slice(ndarray::s![
+            el.saturating_sub(1)..(el + 2).min(n_elevations),
+            az.saturating_sub(1)..(az + 2).min(n_azimuths)
+        ]);
+        if neighbors.iter().all(|&neighbor| neighbor <= value) {
+            let direction = Direction::new(
+                mbss.azimuth_range.0 + az as F * mbss.grid_res,
+                mbss.elevation_range.0 + el as F * mbss.grid_res,
+            );
+            maxima.push((direction, value - min));
+        }
+    }
+    maxima.sort_unstable_by(|(_, a), (_, b)| b.total_cmp(a));
+    maxima
+}
+
+/// `spectrum` with the directions `allowed` rejects lowered to its minimum, so
+/// the peak picker of [`ssloc::Mbss::find_sources`] cannot select them over
+/// allowed ones, `None` if all directions are allowed.
+pub fn mask(
+    spectrum: ArrayView2<F>,
+    mbss: &MbssConfig,
+    allowed: impl Fn(Direction) -> bool,
+) -> Option<Array2<F>> {
+    let min = spectrum.iter().copied().fold(F::INFINITY, F::min);
+    let mut masked = None;
+    for (el, az) in ndarray::indices(spectrum.dim()) {
+        let direction = Direction::new(
+            mbss.azimuth_range.0 + az as F * mbss.grid_res,
+            mbss.elevation_range.0 + el as F * mbss.grid_res,
+        );
+        if !allowed(direction) {
+            masked.get_or_insert_with(|| spectrum.to_owned())[(el, az)] = min;
+        }
+    }
+    masked
+}
+
+/// Great-circle distance between two directions, accurate for close
+/// directions and across the azimuth wrap-around.
+pub fn great_circle(a: Direction, b: Direction) -> F {
+    let haversine = ((b.elevation - a.elevation) / 2.).sin().powi(2)
+        + a.elevation.cos() * b.elevation.cos() * ((b.azimuth - a.azimuth) / 2.).sin().powi(2);
+    2. * haversine.sqrt().min(1.).asin()
+}
+
+/// Non-maximum suppression of the `peaks` found in an angular `spectrum`,
+/// merging each peak within `radius` of a stronger kept one into it.
+///
+/// Peaks are only merged if the spectrum stays above half the weaker one on
+/// the great circle between them, so separate lobes of close sources are
+/// kept. Strengths are relative to the minimum of the spectrum like those of
+/// [`ssloc::Mbss::find_sources`].
+pub fn suppress_non_maxima(
+    spectrum: ArrayView2<F>,
+    mbss: &MbssConfig,
+    mut peaks: Vec<(Direction, F)>,
+    radius: F,
+) -> Vec<(Direction, F)> {
+    if radius <= 0. {
+        return peaks;
+    }
+    let min = spectrum.iter().copied().fold(F::INFINITY, F::min);
+    let unit = |direction: Direction| {
+        let (sin_el, cos_el) = direction.elevation.sin_cos();
+        let (sin_az, cos_az) = direction.azimuth.sin_cos();
+        [cos_el * cos_az, cos_el * sin_az, sin_el]
+    };
+    let value = |direction: Direction| {
+        let el = ((direction.elevation - mbss.elevation_range.0) / mbss.grid_res).round();
+        let az = ((direction.azimuth - mbss.azimuth_range.0).rem_euclid(TAU) / mbss.grid_res)
+            .round();
+        if el < 0. {
+            return None;
+        }
+        spectrum.get((el as usize, az as usize)).map(|value| value - min)
+    };
+    // lowest value on the great circle from `a` to `b`, sampled twice per cell
+    let valley = |a: Direction, b: Direction| {
+        let distance = great_circle(a, b);
+        let steps = (distance / (mbss.grid_res / 2.)).ceil() as usize;
+        let (a, b) = (unit(a), unit(b));
+        (1..steps)
+            .filter_map(|step| {
+                let t = step as F / steps as F;
+                // spherical interpolation between the unit vectors
+                let (wa, wb) = (
+                    ((1. - t) * distance).sin() / distance.sin(),
+                    (t * distance).sin() / distance.sin(),
+                );
+                let [x, y, z] = [0, 1, 2].map(|i| wa * a[i] + wb * b[i]);
+                value(Direction::new(y.atan2(x), z.clamp(-1., 1.).asin()))
+            })
+            .fold(F::INFINITY, F::min)
+    };
+    peaks.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    let mut kept: Vec<(Direction, F)> = Vec::with_capacity(peaks.len());
+    for (direction, strength) in peaks {
+        let merged = kept.iter().any(|&(stronger, _)| {
+            great_circle(stronger, direction) < radius
+                && valley(stronger, direction) >= strength / 2.
+        });
+        if !merged {
+            kept.push((direction, strength));
+        }
+    }
+    kept
+}
+
+/// Signal to noise ratio in dB of the peak at `direction` in an angular
+/// `spectrum`, the peak's value over the median of the surrounding
+/// background, i.e. the cells between one and three `min_angle`s away.
+///
+/// Like strengths, values are taken relative to the minimum of the spectrum.
+pub fn snr(spectrum: ArrayView2<F>, mbss: &MbssConfig, direction: Direction) -> F {
+    let min = spectrum.iter().copied().fold(F::INFINITY, F::min);
+    let direction_of = |el: usize, az: usize| {
+        Direction::new(
+            mbss.azimuth_range.0 + az as F * mbss.grid_res,
+            mbss.elevation_range.0 + el as F * mbss.grid_res,
+        )
+    };
+    // distance and value of the cell nearest to `direction`
+    let mut peak = (F::INFINITY, 0.);
+    let mut background = Vec::new();
+    for ((el, az), &value) in spectrum.indexed_iter() {
+        let distance = great_circle(direction, direction_of(el, az));
+        if distance < peak.0 {
+            peak = (distance, value - min);
+        }
+        if (mbss.min_angle..3. * mbss.min_angle).contains(&distance) {
+            background.push(value - min);
+        }
+    }
+    let peak = peak.1;
+    if background.is_empty() {
+        // the searched ranges are too small for a ring, use the whole spectrum
+        background = spectrum.iter().map(|value| value - min).collect();
+    }
+    if background.is_empty() {
+        return 0.;
+    }
+    let mid = background.len() / 2;
+    let background = background
+        .select_nth_unstable_by(mid, F::total_cmp)
+        .1
+        .max(F::EPSILON);
+    10. * (F::max(peak, F::EPSILON) / background).log10()
+}
+
+/// Marginal of an angular `spectrum` (elevation × azimuth), reducing `axis`,
+/// i.e. `Axis(0)` yields one value per azimuth.
+pub fn marginal(spectrum: ArrayView2<F>, axis: Axis, reduction: Reduction) -> Array1<F> {
+    match reduction {
+        Reduction::Max => spectrum.map_axis(axis, |lane| lane.iter().copied().fold(F::MIN, F::max)),
+        Reduction::Sum => spectrum.sum_axis(axis),
+    }
+}
+
+/// Integrated energy of the lobe around the peak at `direction` in an
+/// angular `spectrum`, the sum over the connected cells above `level` times
+/// the peak's value.
+///
+/// Like strengths, values are taken relative to the minimum of the spectrum,
+/// so a lone peak cell integrates to its sampled strength.
+pub fn lobe_energy(
+    spectrum: ArrayView2<F>,
+    mbss: &MbssConfig,
+    direction: Direction,
+    level: F,
+) -> F {
+    let (n_elevations, n_azimuths) = spectrum.dim();
+    let min = spectrum.iter().copied().fold(F::INFINITY, F::min);
+    let el = ((direction.elevation - mbss.elevation_range.0) / mbss.grid_res).round() as usize;
+    let az = ((direction.azimuth - mbss.azimuth_range.0) / mbss.grid_res).round() as usize;
+    let Some(&peak) = spectrum.get((el, az)) else {
+        return 0.;
+    };
+    let level = (peak - min) * level;
+
+    let mut visited = Array2::from_elem(spectrum.dim(), false);
+    let mut stack = vec![(el, az)];
+    let mut energy = 0.;
+    while let Some((el, az)) = stack.pop() {
+        if visited[(el, az)] || spectrum[(el, az)] - min < level {
+            continue;
+        }
+        visited[(el, az)] = true;
+        energy += spectrum[(el, az)] - min;
+        if el > 0 {
+            stack.push((el - 1, az));
+        }
+        if el + 1 < n_elevations {
+            stack.push((el + 1, az));
+        }
+        if az > 0 {
+            stack.push((el, az - 1));
+        }
+        if az + 1 < n_azimuths {
+            stack.push((el, az + 1));
+        }
+    }
+    energy
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use nalgebra::vector;
+    use ssloc::{Audio, Direction, MbssConfig, Position, F};
+
+    use super::{analyzable, window_len};
+    use crate::config::{Fusion, SpectrumMethod, Window};
+    use crate::source::{AudioSource, MockRecorder};
+    use crate::subband::Subbands;
+
+    const RATE: u32 = 16_000;
+
+    fn mics() -> Vec<Position> {
+        vec![vector![0.1, 0., 0.], vector![-0.1, 0., 0.], vector![0., 0.1, 0.05]]
+    }
+
+    fn recording(samples: usize) -> ssloc::Audio {
+        let mut recorder =
+            MockRecorder::tone(&mics(), RATE, 343., Direction::new(0.5, 0.), samples, 1);
+        let Ok(audio) = recorder.record();
+        audio
+    }
+
+    #[test]
+    fn window_size_reconfigured_mid_stream() {
+        let mbss = MbssConfig {
+            grid_res: 10f64.to_radians(),
+            ..Default::default()
+        };
+        assert_eq!(window_len(RATE.into()), 1024);
+        // recordings queued before `mbss/stft/size` grew to 4096
+        for samples in [1024, 2048, 3000] {
+            let audio = recording(samples);
+            assert!(analyzable(&audio, None));
+            assert!(!analyzable(&audio, Some(4096)), "{samples} samples");
+            assert!(!mbss.create(mics()).analyze_spectrum(&audio).is_empty());
+        }
+        // odd lengths are truncated by a window
+        assert!(!analyzable(&recording(1025), None));
+        assert!(analyzable(&recording(2049), None));
+        // recordings after the update
+        let subbands = Subbands::new(
+            mbss,
+            &mics(),
+            SpectrumMethod::GccPhat,
+            None,
+            (Some(4096), Window::Hann),
+            1,
+            Fusion::Sum,
+        );
+        let analyzer = mbss.create(mics());
+        for samples in [4096, 6000, 8193] {
+            let audio = recording(samples);
+            assert!(analyzable(&audio, Some(4096)), "{samples} samples");
+            let spectrum = subbands.analyze_spectrum(&audio);
+            let (found, _) = analyzer.find_sources(spectrum.view(), 1)[0];
+            // compared in azimuth like `source::tests::locates_the_mock_tone`
+            let error =
+                super::great_circle(Direction::new(found.azimuth, 0.), Direction::new(0.5, 0.));
+            assert!(
+                error <= mbss.grid_res.max(2. * mbss.alpha_res),
+                "{samples} samples found at {found:?}, {:.1}° off in azimuth",
+                error.to_degrees()
+            );
+        }
+    }
+
+    #[test]
+    fn spectrum_accumulates_in_f64() {
+        // `ssloc::F` is the only float type of the spectrum, no f32 path exists
+        let _: fn(F) -> f64 = |value| value;
+        // a source 120 dB below full scale on eight mics yields the spectrum
+        // of a loud one within f64 rounding, GCC-PHAT being scale invariant
+        let mics: Vec<Position> = (0..8)
+            .map(|mic| {
+                let angle = F::from(mic) * TAU / 8.;
+                vector![angle.cos(), angle.sin(), F::from(mic % 2)] * 0.1
+            })
+            .collect();
+        let mut recorder =
+            MockRecorder::tone(&mics, RATE, 343., Direction::new(0.5, 0.2), 4096, 1);
+        let Ok(loud) = recorder.record();
+        let weak = Audio::from_interleaved(
+            loud.sample_rate(),
+            loud.channels(),
+            loud.to_interleaved::<F>().map(|sample| sample * 1e-6),
+        );
+        let mbss = MbssConfig {
+            grid_res: 5f64.to_radians(),
+            ..Default::default()
+        }
+        .create(mics);
+        let loud = mbss.analyze_spectrum(&loud);
+        let weak = mbss.analyze_spectrum(&weak);
+        let scale = loud.iter().copied().map(F::abs).fold(0., F::max);
+        let error = (&loud - &weak).iter().copied().map(F::abs).fold(0., F::max);
+        assert!(error <= 1e-9 * scale, "{error} for spectrum values up to {scale}");
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt::Write;
+
+use itertools::Itertools;
+use parking_lot::{const_mutex, Mutex};
+use rosrust::ros_warn;
+use ssloc::{Direction, Position, F};
+
+/// Aliasing check reported last, shared by all analyzer threads so it is only
+/// reported once per change.
+static REPORTED_ALIASING: Mutex<Option<(F, F)>> = const_mutex(None);
+
+/// Describes the far field forward model for a source in `direction`, i.e.
+/// the delays the analyzer expects between the `mics` (channel index and
+/// position).
+///
+/// Delays per mic are relative to the array centroid, positive means the
+/// sound arrives later. The TDOA of a pair `a-b` is `delay(b) - delay(a)`,
+/// matching the TDOA grid searched by GCC-PHAT; the corresponding phase
+/// difference at frequency `f` is `-2π f tdoa`.
+///
+/// Each pair is also reported with its physically feasible TDOA range
+/// `±spacing / speed_of_sound`. `ssloc` derives the TDOA grid of every pair
+/// from the angles between the pair and the searched directions, so GCC-PHAT
+/// never evaluates lags outside of this range.
+pub fn describe(
+    mics: &[(usize, Position)],
+    direction: Direction,
+    speed_of_sound: F,
+    sample_rate: F,
+) -> String {
+    let unit = direction.to_unit_vec();
+    let centroid = mics.iter().map(|(_, pos)| pos).sum::<Position>() / mics.len().max(1) as F;
+    let delays = mics
+        .iter()
+        .map(|(idx, pos)| (idx, -(pos - centroid).dot(&unit) / speed_of_sound))
+        .collect_vec();
+
+    let mut out = format!(
+        "direction: azimuth {:.4} rad, elevation {:.4} rad\nspeed of sound: {speed_of_sound} \
+         m/s\nsample rate: {sample_rate} Hz\n",
+        direction.azimuth, direction.elevation
+    );
+    for ((idx, pos), (_, delay)) in mics.iter().zip(&delays) {
+        writeln!(
+            out,
+            "mic {idx} at ({:.4}, {:.4}, {:.4}): delay {:+.3} µs ({:+.3} samples)",
+            pos.x,
+            pos.y,
+            pos.z,
+            delay * 1e6,
+            delay * sample_rate
+        )
+        .expect("writing to string does not fail");
+    }
+    for (((a, delay_a), (_, pos_a)), ((b, delay_b), (_, pos_b))) in
+        delays.iter().zip(mics).tuple_combinations()
+    {
+        let tdoa = delay_b - delay_a;
+        let feasible = (pos_a - pos_b).magnitude() / speed_of_sound;
+        writeln!(
+            out,
+            "pair {a}-{b}: tdoa {:+.3} µs ({:+.3} samples), feasible ±{:.3} µs ({:.3} \
+             samples)",
+            tdoa * 1e6,
+            tdoa * sample_rate,
+            feasible * 1e6,
+            feasible * sample_rate
+        )
+        .expect("writing to string does not fail");
+    }
+    out
+}
+
+/// Frequency above which the closest pair of `mics` suffers from spatial
+/// aliasing, i.e. half a wavelength fits between them.
+pub fn aliasing_frequency(mics: &[Position], speed_of_sound: F) -> Option<F> {
+    let spacing = mics
+        .iter()
+        .tuple_combinations()
+        .map(|(a, b)| (a - b).magnitude())
+        .filter(|&d| d > 0.)
+        .min_by(F::total_cmp)?;
+    Some(speed_of_sound / (2. * spacing))
+}
+
+/// Warns once if frequencies up to `max_frequency` are analyzed while the
+/// array aliases below that.
+pub fn warn_aliasing(mics: &[Position], speed_of_sound: F, max_frequency: F) {
+    let Some(aliasing) = aliasing_frequency(mics, speed_of_sound) else {
+        return;
+    };
+    let mut reported = REPORTED_ALIASING.lock();
+    if *reported == Some((aliasing, max_frequency)) {
+        return;
+    }
+    *reported = Some((aliasing, max_frequency));
+    if aliasing < max_frequency {
+        ros_warn!(
+            "the mic spacing of {:.1} cm aliases above {aliasing:.0} Hz, but frequencies up to \
+             {max_frequency:.0} Hz are analyzed, expect phantom sources; reduce the spacing or \
+             limit the analyzed band",
+            speed_of_sound / (2. * aliasing) * 100.
+        );
+    }
+}
@@ -0,0 +1,381 @@
+//! Localization in overlapping frequency sub-bands whose angular spectra are
+//! fused, which is more robust against band-limited interferers than pooling
+//! all frequencies at once.
+//!
+//! [`ssloc::Mbss`] only exposes the GCC-PHAT spectrum summed over all
+//! frequencies, and PHAT whitening undoes any band-pass filtering of the
+//! input, so the angular spectrum is computed here, following the same STFT,
+//! TDOA grids and pooling.
+//!
+//! The other local angular spectra of MBSS Locate are not implemented by
+//! `ssloc` either, GCC-NONLIN (`mbss/spectrum_method`) is computed here in a
+//! single band as well, like the angular spectrum limited to a frequency
+//! band (`mbss/freq_min` and `mbss/freq_max`) or of another STFT
+//! (`mbss/stft/{size,window}`).
+//!
+//! With the `rayon` feature the mic pairs can be correlated in parallel, a
+//! single band then replaces [`ssloc::Mbss::analyze_spectrum`] for
+//! `mbss/num_threads` other than 1.
+use std::f64::consts::PI;
+use std::ops::Range;
+
+use itertools::Itertools;
+use nalgebra::vector;
+use ndarray::{s, Array1, Array2, Array3, ArrayView1, Axis};
+use realfft::num_complex::Complex;
+use realfft::RealFftPlanner;
+use ssloc::mbss::Pooling;
+use ssloc::{Audio, MbssConfig, Position, F};
+
+use crate::audio::AudioExt;
+use crate::config::{Fusion, SpectrumMethod, Window};
+use crate::spectrum::window_len;
+
+/// TDOA grid of a mic pair.
+struct Pair {
+    mics: (usize, usize),
+    /// Distance between the mics in meters.
+    distance: F,
+    /// TDOA per angle to the pair's axis, sampled every `alpha_res`.
+    taus: Vec<F>,
+    /// Fractional index into `taus` per direction of the grid.
+    directions: Vec<F>,
+}
+
+pub struct Subbands {
+    pairs: Vec<Pair>,
+    pooling: Pooling,
+    method: SpectrumMethod,
+    speed_of_sound: F,
+    /// Elevations × azimuths, matching [`ssloc::Mbss::analyze_spectrum`].
+    shape: (usize, usize),
+    bands: usize,
+    fusion: Fusion,
+    /// Lowest and highest frequency in Hz accumulated, all if `None`.
+    band: Option<(F, F)>,
+    /// STFT window length, [`window_len`] if `None`, and function.
+    stft: (Option<usize>, Window),
+    /// Correlates the mic pairs in parallel if set.
+    #[cfg(feature = "rayon")]
+    pool: Option<rayon::ThreadPool>,
+}
+
+impl Subbands {
+    pub fn new(
+        mbss: MbssConfig,
+        mics: &[Position],
+        method: SpectrumMethod,
+        band: Option<(F, F)>,
+        stft: (Option<usize>, Window),
+        bands: usize,
+        fusion: Fusion,
+    ) -> Self {
+        let azimuths = grid(mbss.azimuth_range, mbss.grid_res);
+        let elevations = grid(mbss.elevation_range, mbss.grid_res);
+        let units = elevations
+            .iter()
+            .flat_map(|&el| {
+                azimuths
+                    .iter()
+                    .map(move |&az| vector![el.cos() * az.cos(), el.cos() * az.sin(), el.sin()])
+            })
+            .collect_vec();
+        let pairs = (0..mics.len())
+            .tuple_combinations()
+            .map(|(a, b)| {
+                let axis = mics[a] - mics[b];
+                let alphas = units.iter().map(|unit| axis.angle(unit)).collect_vec();
+                let (min, max) = alphas
+                    .iter()
+                    .copied()
+                    .minmax_by(F::total_cmp)
+                    .into_option()
+                    .unwrap_or_default();
+                let min = (min / mbss.alpha_res).floor() * mbss.alpha_res;
+                let steps = ((max - min) / mbss.alpha_res).ceil() as usize;
+                Pair {
+                    mics: (a, b),
+                    distance: axis.magnitude(),
+                    taus: (0..=steps)
+                        .map(|i| {
+                            (min + i as F * mbss.alpha_res).cos() / mbss.speed_of_sound
+                                * axis.magnitude()
+                        })
+                        .collect(),
+                    directions: alphas.iter().map(|alpha| (alpha - min) / mbss.alpha_res).collect(),
+                }
+            })
+            .collect();
+        Self {
+            pairs,
+            pooling: mbss.pooling,
+            method,
+            speed_of_sound: mbss.speed_of_sound,
+            shape: (elevations.len(), azimuths.len()),
+            bands: bands.max(1),
+            fusion,
+            band,
+            stft,
+            #[cfg(feature = "rayon")]
+            pool: None,
+        }
+    }
+
+    /// Correlates the mic pairs on `threads` threads, 0 for one per CPU.
+    #[cfg(feature = "rayon")]
+    pub fn parallelize(&mut self, threads: usize) -> Result<(), rayon::ThreadPoolBuildError> {
+        self.pool = Some(rayon::ThreadPoolBuilder::new().num_threads(threads).build()?);
+        Ok(())
+    }
+
+    /// Fused angular spectrum of `audio`, a drop in replacement for
+    /// [`ssloc::Mbss::analyze_spectrum`].
+    pub fn analyze_spectrum(&self, audio: &Audio) -> Array2<F> {
+        let (window, function) = self.stft;
+        let window = window.unwrap_or_else(|| window_len(audio.sample_rate()));
+        // the DC bin is skipped like in ssloc
+        let frequencies = (1..=window / 2)
+            .map(|bin| bin as F * audio.sample_rate() / window as F)
+            .collect_vec();
+        let bins = match self.band {
+            Some((min, max)) => {
+                let start = frequencies.partition_point(|&frequency| frequency < min);
+                let end = frequencies.partition_point(|&frequency| frequency <= max);
+                // at least the bin closest to a band narrower than the bins
+                start.min(frequencies.len() - 1)..end.max(start + 1).min(frequencies.len())
+            }
+            None => 0..frequencies.len(),
+        };
+        let frequencies = &frequencies[bins.clone()];
+        let stfts = (0..audio.channels())
+            .map(|channel| {
+                stft(&audio.channel(channel), window, function).slice_move(s![bins.clone(), ..])
+            })
+            .collect_vec();
+        let frames = stfts.first().map_or(0, Array2::ncols);
+        let bands = bands(frequencies.len(), self.bands);
+        let directions = self.shape.0 * self.shape.1;
+
+        // band × direction × frame
+        let shape = (bands.len(), directions, frames);
+        let add = |mut local: Array3<F>, pair: &Pair| {
+            let weight = match self.method {
+                SpectrumMethod::GccPhat => Weight::Phat,
+                SpectrumMethod::GccNonlin => Weight::Nonlin {
+                    alpha: 10. * self.speed_of_sound / (pair.distance * audio.sample_rate()),
+                },
+            };
+            correlate(pair, weight, &stfts, frequencies, &bands, &mut local);
+            local
+        };
+        let serial = || self.pairs.iter().fold(Array3::zeros(shape), add);
+        #[cfg(feature = "rayon")]
+        let local = match &self.pool {
+            Some(pool) => pool.install(|| {
+                use rayon::prelude::*;
+                // the pairs are summed in a different order, which only changes
+                // the rounding
+                self.pairs
+                    .par_iter()
+                    .fold(|| Array3::zeros(shape), add)
+                    .reduce(|| Array3::zeros(shape), |a, b| a + b)
+            }),
+            None => serial(),
+        };
+        #[cfg(not(feature = "rayon"))]
+        let local = serial();
+
+        let pooled = local.map_axis(Axis(2), |frames| match self.pooling {
+            Pooling::Max => frames.iter().copied().fold(F::MIN, F::max),
+            Pooling::Sum => frames.sum(),
+        });
+        let fused = match self.fusion {
+            Fusion::Sum => pooled.sum_axis(Axis(0)),
+            Fusion::Product => {
+                // geometric mean of the bands relative to their minimum, scaled
+                // like the sum of equally strong bands
+                let count = pooled.nrows() as F;
+                let mut fused = Array1::<F>::ones(directions);
+                for band in pooled.outer_iter() {
+                    let min = band.iter().copied().fold(F::INFINITY, F::min);
+                    fused.zip_mut_with(&band, |f, v| *f *= (v - min).powf(1. / count));
+                }
+                fused * count
+            }
+        };
+        fused
+            .into_shape(self.shape)
+            .expect("spectrum has one value per direction")
+    }
+}
+
+/// Contribution of a frequency to the local angular spectrum.
+#[derive(Clone, Copy)]
+enum Weight {
+    /// The cosine of the phase difference to the steered TDOA.
+    Phat,
+    /// `1 - tanh(alpha * sqrt(2 - 2 cos))` of it, as in MBSS Locate with
+    /// `alpha = 10 c / (d fs)` for mics `d` apart.
+    Nonlin { alpha: F },
+}
+
+/// Adds the GCC correlation of `pair` per band, direction and frame to
+/// `local`.
+fn correlate(
+    pair: &Pair,
+    weight: Weight,
+    stfts: &[Array2<Complex<F>>],
+    frequencies: &[F],
+    bands: &[Range<usize>],
+    local: &mut Array3<F>,
+) {
+    let (a, b) = pair.mics;
+    let frames = local.dim().2;
+    let phat = Array2::from_shape_fn((frequencies.len(), frames), |index| {
+        let cross = stfts[a][index] * stfts[b][index].conj();
+        let norm = cross.norm();
+        if norm > 0. { cross / norm } else { Complex::default() }
+    });
+    // band × tau × frame
+    let mut sampled = Array3::<F>::zeros((bands.len(), pair.taus.len(), frames));
+    for (i, tau) in pair.taus.iter().enumerate() {
+        let mut correlation = Array2::<F>::zeros((frequencies.len(), frames));
+        for ((mut correlation, phat), frequency) in
+            correlation.outer_iter_mut().zip(phat.outer_iter()).zip(frequencies)
+        {
+            let steering = Complex::from_polar(1., -2. * PI * frequency * tau);
+            correlation.zip_mut_with(&phat, |c, p| {
+                let cos = (p * steering).re;
+                *c = match weight {
+                    Weight::Phat => cos,
+                    Weight::Nonlin { alpha } => {
+                        1. - (alpha * (2. - 2. * cos).max(0.).sqrt()).tanh()
+                    }
+                };
+            });
+        }
+        for (band, bins) in bands.iter().enumerate() {
+            sampled
+                .slice_mut(s![band, i, ..])
+                .assign(&correlation.slice(s![bins.clone(), ..]).sum_axis(Axis(0)));
+        }
+    }
+    for (direction, &index) in pair.directions.iter().enumerate() {
+        let last = pair.taus.len() - 1;
+        let lower = (index.floor() as usize).min(last);
+        let upper = (lower + 1).min(last);
+        let lerp = index - lower as F;
+        for band in 0..bands.len() {
+            let interpolated = &sampled.slice(s![band, lower, ..]) * (1. - lerp)
+                + &sampled.slice(s![band, upper, ..]) * lerp;
+            let mut local = local.slice_mut(s![band, direction, ..]);
+            local += &interpolated;
+        }
+    }
+}
+
+/// Values from `range.0` in `step`s below `range.1`, accumulated like the grid
+/// of [`ssloc::Mbss`] so the shapes match.
+fn grid(range: (F, F), step: F) -> Vec<F> {
+    let mut values = vec![range.0];
+    let mut value = range.0;
+    loop {
+        value += step;
+        if value >= range.1 {
+            return values;
+        }
+        values.push(value);
+    }
+}
+
+/// `count` bands of `bins` overlapping by half their width.
+fn bands(bins: usize, count: usize) -> Vec<Range<usize>> {
+    let width = 2. * bins as F / (count + 1) as F;
+    (0..count)
+        .map(|band| {
+            let start = ((band as F * width / 2.).round() as usize).min(bins.saturating_sub(1));
+            let end = ((band as F * width / 2. + width).round() as usize).clamp(start + 1, bins);
+            start..end
+        })
+        .collect()
+}
+
+impl Window {
+    /// Weight of sample `i` of a window of `len` samples.
+    fn weight(self, i: usize, len: usize) -> F {
+        let phase = i as F / len as F;
+        match self {
+            Window::Sine => ((i as F + 0.5) / len as F * PI).sin(),
+            // periodic, so the half overlapping windows sum to a constant
+            Window::Hann => 0.5 - 0.5 * (2. * PI * phase).cos(),
+            Window::Hamming => 0.54 - 0.46 * (2. * PI * phase).cos(),
+            Window::Rectangular => 1.,
+        }
+    }
+}
+
+/// STFT with `function` as window and half overlap, bins (excluding DC) ×
+/// frames, like the one used by [`ssloc::Mbss::analyze_spectrum`] with the
+/// sine window.
+fn stft(samples: &[F], window: usize, function: Window) -> Array2<Complex<F>> {
+    let len = samples.len() - samples.len() % 2 * window;
+    let frames = (len / window * 2).saturating_sub(1);
+    let fft = RealFftPlanner::<F>::new().plan_fft_forward(window);
+    let mut input = fft.make_input_vec();
+    let mut output = fft.make_output_vec();
+    let weights = (0..window).map(|i| function.weight(i, window)).collect_vec();
+    let mut stft = Array2::default((window / 2, frames));
+    for frame in 0..frames {
+        let start = frame * window / 2;
+        for ((input, sample), weight) in input.iter_mut().zip(&samples[start..]).zip(&weights) {
+            *input = sample * weight;
+        }
+        fft.process(&mut input, &mut output)
+            .expect("buffers are created by the plan");
+        stft.column_mut(frame).assign(&ArrayView1::from(&output[1..]));
+    }
+    stft
+}
+
+#[cfg(all(test, feature = "rayon"))]
+mod tests {
+    use nalgebra::vector;
+    use ssloc::{Direction, MbssConfig, Position, F};
+
+    use super::Subbands;
+    use crate::config::{Fusion, SpectrumMethod, Window};
+    use crate::source::{AudioSource, MockRecorder};
+
+    #[test]
+    fn parallel_matches_serial() {
+        let mbss = MbssConfig {
+            grid_res: 5f64.to_radians(),
+            ..Default::default()
+        };
+        let mics: Vec<Position> = vec![
+            vector![0.1, 0., 0.],
+            vector![-0.1, 0., 0.],
+            vector![0., 0.1, 0.05],
+            vector![0., -0.1, -0.05],
+        ];
+        let direction = Direction::new(1., 0.2);
+        let Ok(audio) = MockRecorder::tone(&mics, 16_000, mbss.speed_of_sound, direction, 4096, 1)
+            .record();
+        for (method, bands, fusion) in [
+            (SpectrumMethod::GccPhat, 1, Fusion::Sum),
+            (SpectrumMethod::GccNonlin, 3, Fusion::Product),
+        ] {
+            let subbands = || {
+                Subbands::new(mbss, &mics, method, None, (None, Window::Sine), bands, fusion)
+            };
+            let serial = subbands().analyze_spectrum(&audio);
+            let mut parallel = subbands();
+            parallel.parallelize(4).unwrap();
+            let parallel = parallel.analyze_spectrum(&audio);
+            let scale = serial.iter().copied().map(F::abs).fold(0., F::max);
+            assert!(scale > 0., "{method:?}: empty spectrum");
+            let error = (&serial - &parallel).iter().copied().map(F::abs).fold(0., F::max);
+            assert!(error <= 1e-9 * scale, "{method:?}: {error} off of {scale}");
+        }
+    }
+}
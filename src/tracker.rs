@@ -0,0 +1,202 @@
+//! Associates the sources detected in consecutive frames to tracks with
+//! stable ids.
+//!
+//! Association is greedy nearest neighbour: all pairs of a detection and a
+//! track closer than `mbss/tracking/gate` (and within
+//! `mbss/max_angular_velocity`) are matched in order of increasing angular
+//! distance, each detection and track at most once. Detections left over start
+//! new tracks (births), tracks left over coast at their last direction until
+//! they missed more than `mbss/tracking/max_missed` frames or are older than
+//! `mbss/tracking_persistence` seconds (deaths). Two sources crossing each
+//! other keep their ids as long as each is closer to its own track than to the
+//! other's.
+//!
+//! The direction of a continued track is smoothed over time with an
+//! exponential moving average of unit vectors, weighting the previous
+//! direction by `mbss/tracking/smoothing`, which averages across the azimuth
+//! wrap-around and the poles. The weight decreases linearly with the distance
+//! of the detection up to the gate, so the jitter of a stationary source is
+//! damped while a moving one is followed closely.
+use std::f64::consts::{PI, TAU};
+
+use itertools::Itertools;
+use rosrust::Time;
+use ssloc::{Direction, F};
+
+use crate::config::Config;
+use crate::spectrum;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Track {
+    /// Direction of the last detection.
+    pub direction: Direction,
+    /// Direction smoothed over the previous detections.
+    pub smoothed: Direction,
+    pub stamp: Time,
+    pub power: F,
+    /// Signal to noise ratio in dB when detected, see [`crate::spectrum::snr`].
+    pub snr: F,
+    pub id: i64,
+    pub channel: Option<usize>,
+    /// Consecutive frames without a matching detection.
+    pub missed: u32,
+}
+
+#[derive(Debug, Default)]
+pub struct Tracker {
+    tracks: Vec<Track>,
+    /// continuiously increases and will wrap at some point
+    next_id: i64,
+}
+
+impl Tracker {
+    /// Whether a frame recorded at `stamp` is older than the tracked ones.
+    pub fn out_of_order(&self, stamp: Time) -> bool {
+        self.tracks.iter().any(|track| track.stamp > stamp)
+    }
+
+    /// Updates the tracks with the `detections` (direction, strength and SNR)
+    /// of the frame recorded at `stamp`, returning the current tracks sorted by
+    /// power.
+    pub fn update(
+        &mut self,
+        config: &Config,
+        stamp: Time,
+        detections: impl IntoIterator<Item = (Direction, F, F)>,
+    ) -> Vec<Track> {
+        let detections = detections.into_iter().collect_vec();
+        let candidates = detections
+            .iter()
+            .enumerate()
+            .cartesian_product(self.tracks.iter().enumerate())
+            .filter_map(|((detection, &(direction, ..)), (track, previous))| {
+                let distance = spectrum::great_circle(previous.direction, direction);
+                let elapsed = (stamp - previous.stamp).seconds();
+                (distance < config.tracking_gate && config.plausible_motion(distance, elapsed))
+                    .then_some((distance, detection, track))
+            })
+            .sorted_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut matched = vec![None; detections.len()];
+        let mut continued = vec![false; self.tracks.len()];
+        for (_, detection, track) in candidates {
+            if matched[detection].is_none() && !continued[track] {
+                matched[detection] = Some(track);
+                continued[track] = true;
+            }
+        }
+
+        let mut tracks = detections
+            .into_iter()
+            .zip(matched)
+            .map(|((direction, power, snr), track)| match track {
+                Some(track) => {
+                    let previous = self.tracks[track];
+                    Track {
+                        direction,
+                        smoothed: smooth(config, previous.smoothed, direction),
+                        stamp,
+                        power: (previous.power * 0.8).max(power),
+                        snr,
+                        missed: 0,
+                        ..previous
+                    }
+                }
+                None => {
+                    let id = self.next_id;
+                    self.next_id = self.next_id.wrapping_add(1);
+                    Track {
+                        direction,
+                        smoothed: direction,
+                        stamp,
+                        power,
+                        snr,
+                        id,
+                        channel: None,
+                        missed: 0,
+                    }
+                }
+            })
+            .collect_vec();
+        tracks.extend(
+            self.tracks
+                .iter()
+                .zip(continued)
+                .filter(|(_, continued)| !continued)
+                .map(|(track, _)| Track {
+                    missed: track.missed + 1,
+                    ..*track
+                })
+                .filter(|track| {
+                    track.missed <= config.tracking_max_missed.into()
+                        && track.stamp.seconds() + config.tracking_persistence >= stamp.seconds()
+                }),
+        );
+        tracks.sort_unstable_by(|a, b| b.power.total_cmp(&a.power));
+        tracks.truncate(config.max_sources.into());
+
+        // assign channels to tracks without channels
+        let mut min = 0;
+        for idx in 0..tracks.len() {
+            if tracks[idx].channel.is_none() {
+                min = (min..)
+                    .find(|&c| !tracks.iter().any(|t| t.channel == Some(c)))
+                    .expect("there should be a usize that is not taken");
+                tracks[idx].channel = Some(min);
+                min += 1;
+            }
+        }
+        self.tracks.clone_from(&tracks);
+        tracks
+    }
+}
+
+/// Moves the `smoothed` direction of a track towards its new `detection`.
+fn smooth(config: &Config, smoothed: Direction, detection: Direction) -> Direction {
+    let distance = spectrum::great_circle(smoothed, detection);
+    let weight = config.tracking_smoothing * (1. - distance / config.tracking_gate).max(0.);
+    let vec = smoothed.to_unit_vec() * weight + detection.to_unit_vec() * (1. - weight);
+    // keeps the azimuth in the range of the detections
+    let azimuth = vec.y.atan2(vec.x) - detection.azimuth;
+    Direction::new(
+        detection.azimuth + (azimuth + PI).rem_euclid(TAU) - PI,
+        vec.z.atan2(vec.xy().norm()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use rosrust::Time;
+    use ssloc::Direction;
+
+    use super::Tracker;
+    use crate::config::Config;
+
+    /// Whether a detection at `azimuth` continues the track of one at 0 100 ms
+    /// before, both at the same elevation.
+    fn continued(config: &Config, azimuth: f64) -> bool {
+        let mut tracker = Tracker::default();
+        let detection = |azimuth| [(Direction::new(azimuth, 0.3), 1., 0.)];
+        let first = tracker.update(config, Time::from_nanos(0), detection(0.));
+        let second = tracker.update(config, Time::from_nanos(100_000_000), detection(azimuth));
+        second[0].id == first[0].id
+    }
+
+    #[test]
+    fn gates_motion_in_azimuth() {
+        let mut config = Config::test();
+        config.tracking_gate = 0.2;
+        assert!(continued(&config, 0.1));
+        assert!(!continued(&config, 0.3));
+        // from the other side of the array
+        assert!(!continued(&config, PI));
+
+        config.tracking_gate = PI;
+        config.max_angular_velocity = 10.;
+        // about 3 and 16 degrees per second at this elevation
+        assert!(continued(&config, 0.3_f64.to_radians()));
+        assert!(!continued(&config, 1.7_f64.to_radians()));
+    }
+}
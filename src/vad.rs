@@ -0,0 +1,114 @@
+//! Voice activity detection restricting localization to speech.
+//!
+//! Combines an energy detector against an adaptive noise floor with the share
+//! of the energy in the speech band (300 Hz to 3.4 kHz), evaluated on 20 ms
+//! frames of the mix of the listened channels.
+use std::f64::consts::PI;
+
+use ssloc::{Audio, F};
+
+use crate::audio::AudioExt;
+
+const FRAME: F = 0.02;
+const SPEECH_BAND: (F, F) = (300., 3400.);
+/// Relative increase of the noise floor per frame, lets it recover from
+/// quiet outliers.
+const NOISE_RISE: F = 0.01;
+
+/// Thresholds per aggressiveness: energy above the noise floor, share of the
+/// energy in the speech band and share of voiced frames in a recording.
+const THRESHOLDS: [(F, F, F); 4] = [
+    (2., 0.3, 0.1),
+    (3., 0.4, 0.2),
+    (4., 0.5, 0.3),
+    (6., 0.6, 0.4),
+];
+
+/// Energy gate skipping the localization of silent recordings.
+///
+/// Closes only after more than `hold` consecutive recordings below the
+/// threshold and opens with the first one above it, so pauses of an
+/// intermittent source do not flap its track.
+#[derive(Debug, Default)]
+pub struct SilenceGate {
+    silent: u16,
+}
+
+impl SilenceGate {
+    /// Returns whether a recording with `rms` should be skipped, a
+    /// `threshold` of 0 disables the gate.
+    pub fn is_silent(&mut self, rms: F, threshold: F, hold: u16) -> bool {
+        if threshold <= 0. || rms >= threshold {
+            self.silent = 0;
+            return false;
+        }
+        self.silent = self.silent.saturating_add(1);
+        self.silent > hold
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Vad {
+    noise_floor: Option<F>,
+}
+
+impl Vad {
+    /// Returns whether the mix of `channels` in `audio` contains speech,
+    /// `aggressiveness` (0 to 3) trades missed speech for fewer false
+    /// detections.
+    pub fn is_speech(
+        &mut self,
+        audio: &Audio,
+        channels: impl FnMut(usize) -> bool,
+        aggressiveness: u16,
+    ) -> bool {
+        let (energy_ratio, band_ratio, voiced_ratio) =
+            THRESHOLDS[usize::from(aggressiveness).min(THRESHOLDS.len() - 1)];
+        let mix = audio.mix(channels);
+        let band = band_pass(&mix, audio.sample_rate());
+        let frame = ((FRAME * audio.sample_rate()) as usize).max(1);
+
+        let mut frames = 0;
+        let mut voiced = 0;
+        for (mix, band) in mix.chunks(frame).zip(band.chunks(frame)) {
+            let energy = mean_square(mix);
+            let noise_floor = self.noise_floor.get_or_insert(energy);
+            let is_voiced = energy > *noise_floor * energy_ratio
+                && mean_square(band) > energy * band_ratio;
+            *noise_floor = if energy < *noise_floor {
+                energy
+            } else {
+                (*noise_floor * (1. + NOISE_RISE)).max(F::EPSILON)
+            };
+            frames += 1;
+            voiced += usize::from(is_voiced);
+        }
+        frames > 0 && voiced as F >= frames as F * voiced_ratio
+    }
+}
+
+fn mean_square(samples: &[F]) -> F {
+    samples.iter().map(|s| s * s).sum::<F>() / samples.len() as F
+}
+
+/// Biquad band pass (constant peak gain) over [`SPEECH_BAND`].
+fn band_pass(samples: &[F], sample_rate: F) -> Vec<F> {
+    let center = (SPEECH_BAND.0 * SPEECH_BAND.1).sqrt();
+    let q = center / (SPEECH_BAND.1 - SPEECH_BAND.0);
+    let w = 2. * PI * center / sample_rate;
+    let alpha = w.sin() / (2. * q);
+    let a0 = 1. + alpha;
+    let (b0, b2) = (alpha / a0, -alpha / a0);
+    let (a1, a2) = (-2. * w.cos() / a0, (1. - alpha) / a0);
+
+    let (mut x1, mut x2, mut y1, mut y2) = (0., 0., 0., 0.);
+    samples
+        .iter()
+        .map(|&x| {
+            let y = b0 * x + b2 * x2 - a1 * y1 - a2 * y2;
+            (x2, x1) = (x1, x);
+            (y2, y1) = (y1, y);
+            y
+        })
+        .collect()
+}
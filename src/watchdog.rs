@@ -0,0 +1,59 @@
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+/// Detects a recorder blocked in `record`, e.g. by a driver that hangs
+/// instead of failing.
+///
+/// A blocked recording cannot be interrupted, so the recorder is replaced by
+/// a new one instead. Each recorder is identified by the generation it was
+/// started in, a replaced recorder exits once its recording returns, so only
+/// one recorder records at a time.
+#[derive(Debug, Default)]
+pub struct Watchdog(Mutex<State>);
+
+#[derive(Debug, Default)]
+struct State {
+    generation: u32,
+    /// Start of the current recorder's pending recording.
+    recording: Option<Instant>,
+}
+
+impl Watchdog {
+    /// Generation of the current recorder.
+    pub fn generation(&self) -> u32 {
+        self.0.lock().generation
+    }
+
+    /// Runs `record` for the recorder of `generation`, `None` if that was
+    /// replaced, before or while recording.
+    pub fn watch<T>(&self, generation: u32, record: impl FnOnce() -> T) -> Option<T> {
+        {
+            let mut state = self.0.lock();
+            if state.generation != generation {
+                return None;
+            }
+            state.recording = Some(Instant::now());
+        }
+        let recorded = record();
+        let mut state = self.0.lock();
+        if state.generation != generation {
+            return None;
+        }
+        state.recording = None;
+        Some(recorded)
+    }
+
+    /// If the current recorder has been recording for longer than `timeout`,
+    /// replaces it by the next generation and returns for how long.
+    pub fn check(&self, timeout: Duration) -> Option<Duration> {
+        let mut state = self.0.lock();
+        let blocked = state.recording?.elapsed();
+        if blocked <= timeout {
+            return None;
+        }
+        state.generation = state.generation.wrapping_add(1);
+        state.recording = None;
+        Some(blocked)
+    }
+}
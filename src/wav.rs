@@ -0,0 +1,294 @@
+//! Minimal reader for PCM and IEEE float WAV files, used to replay
+//! recordings instead of capturing from a device, and a writer persisting
+//! recordings.
+use std::fs::{self, File};
+use std::io::{BufWriter, Error, ErrorKind, Result, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use rosrust::ros_err;
+use ssloc::{Audio, F};
+
+use crate::audio::AudioExt;
+use crate::config::PublishFormat;
+
+const FORMAT_PCM: u16 = 1;
+const FORMAT_FLOAT: u16 = 3;
+const FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+#[derive(Debug)]
+pub struct Wav {
+    pub rate: u32,
+    pub channels: u16,
+    /// Interleaved samples normalized to `[-1, 1]`.
+    samples: Vec<F>,
+}
+
+impl Wav {
+    pub fn read(path: impl AsRef<Path>) -> Result<Self> {
+        Self::parse(&fs::read(path)?)
+    }
+
+    pub fn parse(file: &[u8]) -> Result<Self> {
+        let invalid = |msg: &str| Error::new(ErrorKind::InvalidData, msg.to_owned());
+        if file.len() < 12 || &file[0..4] != b"RIFF" || &file[8..12] != b"WAVE" {
+            return Err(invalid("not a RIFF WAVE file"));
+        }
+        let mut fmt = None;
+        let mut data = None;
+        let mut chunks = &file[12..];
+        while chunks.len() >= 8 {
+            let len = u32::from_le_bytes(chunks[4..8].try_into().expect("4 bytes")) as usize;
+            let body = &chunks[8..];
+            // recordings that were not finalized have a wrong or zero data length
+            let body = &body[..len.min(body.len())];
+            match &chunks[0..4] {
+                b"fmt " => fmt = Some(body),
+                b"data" => data = Some(if len == 0 { &chunks[8..] } else { body }),
+                _ => {}
+            }
+            // chunks are padded to an even length
+            chunks = chunks.get(8 + len + len % 2..).unwrap_or_default();
+        }
+        let fmt = fmt.filter(|fmt| fmt.len() >= 16).ok_or_else(|| invalid("missing fmt chunk"))?;
+        let data = data.ok_or_else(|| invalid("missing data chunk"))?;
+
+        let u16_at = |i: usize| u16::from_le_bytes([fmt[i], fmt[i + 1]]);
+        let mut tag = u16_at(0);
+        let channels = u16_at(2);
+        let rate = u32::from_le_bytes(fmt[4..8].try_into().expect("4 bytes"));
+        let bits = u16_at(14);
+        if tag == FORMAT_EXTENSIBLE && fmt.len() >= 26 {
+            // the sub format GUID starts with the actual format tag
+            tag = u16_at(24);
+        }
+        let decode: fn(&[u8]) -> F = match (tag, bits) {
+            (FORMAT_FLOAT, 32) => |b| f32::from_le_bytes(b.try_into().expect("4 bytes")).into(),
+            (FORMAT_FLOAT, 64) => |b| f64::from_le_bytes(b.try_into().expect("8 bytes")),
+            (FORMAT_PCM, 8) => |b| (F::from(b[0]) - 128.) / 128.,
+            (FORMAT_PCM, 16) => |b| F::from(i16::from_le_bytes([b[0], b[1]])) / 32768.,
+            (FORMAT_PCM, 24) => |b| {
+                F::from(i32::from_le_bytes([0, b[0], b[1], b[2]])) / 2f64.powi(31)
+            },
+            (FORMAT_PCM, 32) => |b| {
+                F::from(i32::from_le_bytes(b.try_into().expect("4 bytes"))) / 2f64.powi(31)
+            },
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    format!("unsupported WAV format {tag} with {bits} bits per sample"),
+                ));
+            }
+        };
+        if channels == 0 {
+            return Err(invalid("WAV file without channels"));
+        }
+        Ok(Self {
+            rate,
+            channels,
+            samples: data.chunks_exact(usize::from(bits / 8)).map(decode).collect(),
+        })
+    }
+
+    /// Number of samples per channel.
+    pub fn samples(&self) -> usize {
+        self.samples.len() / usize::from(self.channels)
+    }
+
+    /// Linearly interpolated to `rate` as a whole, see [`AudioExt::resample`].
+    pub fn resample(self, rate: u32) -> Self {
+        let audio = self.audio(0, self.samples()).resample(rate.into());
+        Self {
+            rate,
+            samples: audio.to_interleaved().collect(),
+            ..self
+        }
+    }
+
+    /// `len` samples per channel starting at `start`, truncated at the end of
+    /// the file.
+    pub fn audio(&self, start: usize, len: usize) -> Audio {
+        let channels = usize::from(self.channels);
+        let end = (start + len).min(self.samples());
+        Audio::from_interleaved(
+            self.rate.into(),
+            channels,
+            self.samples[start * channels..end * channels].iter().copied(),
+        )
+    }
+}
+
+/// Appends audio as 32 bit float samples to a WAV file.
+///
+/// The lengths in the header are patched on every flush, at least once per
+/// [`WavWriter::FLUSH_INTERVAL`], and when dropped, so a killed process only
+/// loses the samples since the last flush.
+pub struct WavWriter {
+    path: PathBuf,
+    rate: u32,
+    channels: u16,
+    file: BufWriter<File>,
+    /// Bytes of sample data written.
+    len: u64,
+    flushed: Instant,
+}
+
+impl WavWriter {
+    pub const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// Creates or truncates the file at `path`.
+    pub fn create(path: impl Into<PathBuf>, rate: u32, channels: u16) -> Result<Self> {
+        let path = path.into();
+        let mut writer = Self {
+            file: BufWriter::new(File::create(&path)?),
+            path,
+            rate,
+            channels,
+            len: 0,
+            flushed: Instant::now(),
+        };
+        writer.flush()?;
+        Ok(writer)
+    }
+
+    /// Whether `audio` can be appended to the file at `path`.
+    pub fn accepts(&self, path: &str, audio: &Audio) -> bool {
+        self.path == Path::new(path)
+            && audio.channels() == usize::from(self.channels)
+            && audio.sample_rate() == F::from(self.rate)
+    }
+
+    pub fn write(&mut self, audio: &Audio) -> Result<()> {
+        // counts the encoded bytes, so the header matches the data however
+        // short a recording is
+        let samples = audio.encode(PublishFormat::F32);
+        self.file.write_all(&samples)?;
+        self.len += samples.len() as u64;
+        if self.flushed.elapsed() >= Self::FLUSH_INTERVAL {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Patches the lengths in the header and flushes to disk.
+    pub fn flush(&mut self) -> Result<()> {
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file.write_all(&header(self.rate, self.channels, self.len))?;
+        self.file.seek(SeekFrom::End(0))?;
+        self.file.flush()?;
+        self.file.get_ref().sync_data()?;
+        self.flushed = Instant::now();
+        Ok(())
+    }
+}
+
+impl Drop for WavWriter {
+    fn drop(&mut self) {
+        if let Err(err) = self.flush() {
+            ros_err!("error finalizing `{}`: {err}", self.path.display());
+        }
+    }
+}
+
+/// `audio` as a WAV file with 32 bit float samples, the lengths in the header
+/// are those of the encoded samples.
+pub fn encode(audio: &Audio) -> Vec<u8> {
+    let samples = audio.encode(PublishFormat::F32);
+    let mut wav = header(
+        audio.sample_rate() as u32,
+        audio.channels() as u16,
+        samples.len() as u64,
+    );
+    wav.extend(samples);
+    wav
+}
+
+/// Header of a 32 bit float WAV file with `len` bytes of samples.
+fn header(rate: u32, channels: u16, len: u64) -> Vec<u8> {
+    const HEADER_LEN: u64 = 44;
+    // lengths saturate for files exceeding the 4 GiB WAV limit
+    let riff = u32::try_from(len + HEADER_LEN - 8).unwrap_or(u32::MAX);
+    let data = u32::try_from(len).unwrap_or(u32::MAX);
+    let block_align = channels * 4;
+    let mut header = Vec::with_capacity(HEADER_LEN as usize);
+    header.extend(b"RIFF");
+    header.extend(riff.to_le_bytes());
+    header.extend(b"WAVEfmt ");
+    header.extend(16u32.to_le_bytes());
+    header.extend(FORMAT_FLOAT.to_le_bytes());
+    header.extend(channels.to_le_bytes());
+    header.extend(rate.to_le_bytes());
+    header.extend((rate * u32::from(block_align)).to_le_bytes());
+    header.extend(block_align.to_le_bytes());
+    header.extend(32u16.to_le_bytes());
+    header.extend(b"data");
+    header.extend(data.to_le_bytes());
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use ssloc::{Audio, F};
+
+    use super::{encode, Wav, WavWriter};
+
+    /// `samples` samples of 3 channels, exactly representable as `f32`.
+    fn audio(samples: usize) -> Audio {
+        Audio::from_channels(
+            16_000.,
+            (0..3).map(|channel| (0..samples).map(move |s| (channel * 100 + s) as F / 1024.)),
+        )
+    }
+
+    fn assert_same(wav: &Wav, audio: &Audio) {
+        assert_eq!(usize::from(wav.channels), audio.channels());
+        assert_eq!(wav.samples(), audio.samples());
+        let parsed = wav.audio(0, wav.samples());
+        assert!(parsed.to_interleaved::<F>().eq(audio.to_interleaved::<F>()), "samples differ");
+    }
+
+    #[test]
+    fn encodes_short_recordings() {
+        for samples in [0, 1, 3, 17] {
+            let audio = audio(samples);
+            assert_same(&Wav::parse(&encode(&audio)).unwrap(), &audio);
+        }
+    }
+
+    #[test]
+    fn writes_recordings_of_varying_length() {
+        let path = env::temp_dir().join(format!("ssloc_wav_test_{}.wav", std::process::id()));
+        let mut writer = WavWriter::create(&path, 16_000, 3).unwrap();
+        let lengths = [5, 1, 17, 0, 3];
+        for samples in lengths {
+            writer.write(&audio(samples)).unwrap();
+        }
+        writer.flush().unwrap();
+        let wav = Wav::read(&path).unwrap();
+        drop(writer);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(wav.rate, 16_000);
+        assert_eq!(wav.samples(), lengths.iter().sum::<usize>());
+        let mut start = 0;
+        for samples in lengths {
+            let recorded = wav.audio(start, samples);
+            assert!(recorded.to_interleaved::<F>().eq(audio(samples).to_interleaved::<F>()));
+            start += samples;
+        }
+    }
+
+    #[test]
+    fn resamples_to_the_configured_rate() {
+        let wav = Wav::parse(&encode(&audio(1600))).unwrap().resample(8000);
+        assert_eq!((wav.rate, wav.channels, wav.samples()), (8000, 3, 800));
+        let resampled = wav.audio(0, wav.samples());
+        assert_eq!(resampled.sample_rate(), 8000.);
+        // every other sample of the linear ramps
+        let expected = audio(1600);
+        let expected: Vec<F> = expected.to_interleaved::<F>().collect();
+        let expected = expected.chunks(6).flat_map(|samples| &samples[..3]);
+        assert!(resampled.to_interleaved::<F>().eq(expected.copied()), "samples differ");
+    }
+}